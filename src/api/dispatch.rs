@@ -0,0 +1,112 @@
+//! Request/response shapes for the control API, plus the pure
+//! dispatcher that turns an authenticated [`ApiRequest`] into an
+//! [`ApiResponse`] by calling straight into the same core types
+//! [`crate::cli::run`] does. No networking or secret-checking lives
+//! here — see [`super::listen`] and [`super::SharedSecret`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::cleaning::{CleanupCategory, CleanupOptions, CleanupReport, DiskCleaner};
+use crate::profiles::{ApplyError, ProfileApplier, ProfileManager, ProfileManagerError};
+use crate::scheduler::{execute_task, EngineStats, SchedulerStore, SchedulerStoreError, TaskOutcome, TaskType};
+
+/// One authenticated control-API call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiRequest {
+    CleanRam,
+    CleanDisk { categories: Vec<CleanupCategory>, dry_run: bool },
+    ApplyProfile { name: String, target_pid: u32 },
+    ListTasks,
+    Status,
+}
+
+/// What a request produced, ready for the transport in [`super::server`]
+/// to serialize back to the caller.
+#[derive(Debug, Clone)]
+pub enum ApiResponse {
+    RamCleaned(TaskOutcome),
+    DiskCleaned(CleanupReport),
+    DiskScanned(HashMap<CleanupCategory, u64>),
+    ProfileApplied { name: String },
+    Tasks(Vec<String>),
+    Status(EngineStats),
+}
+
+#[derive(Debug)]
+pub enum ApiDispatchError {
+    TaskFailed(String),
+    Profile(ApplyError),
+    ProfileNotFound(String),
+    ProfileManager(ProfileManagerError),
+    Store(SchedulerStoreError),
+}
+
+impl fmt::Display for ApiDispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiDispatchError::TaskFailed(err) => write!(f, "task failed: {err}"),
+            ApiDispatchError::Profile(err) => write!(f, "{err}"),
+            ApiDispatchError::ProfileNotFound(name) => write!(f, "no profile named {name:?}"),
+            ApiDispatchError::ProfileManager(err) => write!(f, "profile manager error: {err}"),
+            ApiDispatchError::Store(err) => write!(f, "scheduler store error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ApiDispatchError {}
+
+impl From<ApplyError> for ApiDispatchError {
+    fn from(err: ApplyError) -> Self {
+        ApiDispatchError::Profile(err)
+    }
+}
+
+impl From<ProfileManagerError> for ApiDispatchError {
+    fn from(err: ProfileManagerError) -> Self {
+        ApiDispatchError::ProfileManager(err)
+    }
+}
+
+impl From<SchedulerStoreError> for ApiDispatchError {
+    fn from(err: SchedulerStoreError) -> Self {
+        ApiDispatchError::Store(err)
+    }
+}
+
+/// Handles one already-authenticated request. `profiles_path` and
+/// `scheduler_store_path` are only consulted for the requests that
+/// need them, the same way [`crate::cli::run`]'s `scheduler_store_path`
+/// is.
+pub fn dispatch(request: ApiRequest, profiles_path: &Path, scheduler_store_path: &Path) -> Result<ApiResponse, ApiDispatchError> {
+    match request {
+        ApiRequest::CleanRam => {
+            let outcome = execute_task(&TaskType::CleanRam).map_err(ApiDispatchError::TaskFailed)?;
+            Ok(ApiResponse::RamCleaned(outcome))
+        }
+        ApiRequest::CleanDisk { categories, dry_run } => {
+            let options = CleanupOptions { categories: categories.into_iter().collect(), exclude_paths: Vec::new() };
+            let cleaner = DiskCleaner::new();
+            if dry_run {
+                Ok(ApiResponse::DiskScanned(cleaner.estimate_freeable_bytes_by_category(&options)))
+            } else {
+                Ok(ApiResponse::DiskCleaned(cleaner.clean(&options)))
+            }
+        }
+        ApiRequest::ApplyProfile { name, target_pid } => {
+            let manager = ProfileManager::load_from_file(profiles_path.to_path_buf())?;
+            let profile = manager.find(&name).ok_or_else(|| ApiDispatchError::ProfileNotFound(name.clone()))?;
+            ProfileApplier::new().activate(profile, target_pid)?;
+            Ok(ApiResponse::ProfileApplied { name })
+        }
+        ApiRequest::ListTasks => {
+            let store = SchedulerStore::load_from_file(scheduler_store_path.to_path_buf())?;
+            Ok(ApiResponse::Tasks(store.tasks().iter().map(|task| task.name.clone()).collect()))
+        }
+        ApiRequest::Status => {
+            let store = SchedulerStore::load_from_file(scheduler_store_path.to_path_buf())?;
+            Ok(ApiResponse::Status(store.engine_stats().clone()))
+        }
+    }
+}
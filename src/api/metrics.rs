@@ -0,0 +1,110 @@
+//! Renders the app's own metrics as Prometheus's plain-text exposition
+//! format, for the Settings tab's "Expose /metrics locally" toggle so
+//! homelab users can graph RAM usage, CPU usage, system-wide network
+//! throughput, cleanup counters, bytes freed and scheduler runs in
+//! Grafana. No per-process bandwidth metric is exported.
+//! [`render_prometheus_text`] is the pure, dependency-free half; serving
+//! it over `/metrics` is stubbed in [`serve`] for the same reason
+//! [`super::server::listen`] is — no HTTP server in this crate's
+//! dependencies yet, so there's nothing to parse the request line or
+//! frame the response with.
+
+use std::fmt;
+use std::net::SocketAddr;
+
+/// Everything [`render_prometheus_text`] needs, gathered by the caller
+/// from wherever each figure actually lives
+/// ([`crate::system::sampler::StatsSampler`], [`crate::scheduler::EngineStats`],
+/// a running total of [`crate::cleaning::CleanupReport`]s) rather than
+/// this module reaching into any of those subsystems itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrometheusMetricsSnapshot {
+    pub ram_used_percent: Option<f64>,
+    pub cpu_used_percent: Option<f64>,
+    pub network_down_kbps: Option<f64>,
+    pub network_up_kbps: Option<f64>,
+    /// Number of `CleanRam`/`CleanDisk` tasks that have completed since
+    /// this app last started.
+    pub cleanup_runs_total: u64,
+    pub bytes_freed_total: u64,
+    /// Number of scheduled tasks of any type that have run since this
+    /// app last started.
+    pub scheduler_runs_total: u64,
+}
+
+/// Renders `snapshot` as Prometheus's plain-text exposition format:
+/// a `# HELP`/`# TYPE` pair and one sample line per metric. Gauges for
+/// point-in-time readings, counters for running totals — the same
+/// distinction Prometheus itself expects so Grafana graphs a counter as
+/// a rate rather than a raw value.
+pub fn render_prometheus_text(snapshot: &PrometheusMetricsSnapshot) -> String {
+    let mut out = String::new();
+
+    push_gauge(&mut out, "gamebooster_ram_used_percent", "Physical RAM currently in use, 0-100.", snapshot.ram_used_percent);
+    push_gauge(&mut out, "gamebooster_cpu_used_percent", "System-wide CPU usage, 0-100.", snapshot.cpu_used_percent);
+    push_gauge(&mut out, "gamebooster_network_down_kbps", "Current download throughput in kbps.", snapshot.network_down_kbps);
+    push_gauge(&mut out, "gamebooster_network_up_kbps", "Current upload throughput in kbps.", snapshot.network_up_kbps);
+    push_counter(&mut out, "gamebooster_cleanup_runs_total", "Cleanup tasks completed since this app last started.", snapshot.cleanup_runs_total);
+    push_counter(&mut out, "gamebooster_bytes_freed_total", "Bytes freed by cleanup tasks since this app last started.", snapshot.bytes_freed_total);
+    push_counter(&mut out, "gamebooster_scheduler_runs_total", "Scheduled tasks of any type run since this app last started.", snapshot.scheduler_runs_total);
+
+    out
+}
+
+fn push_gauge(out: &mut String, name: &str, help: &str, value: Option<f64>) {
+    let Some(value) = value else { return };
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"));
+}
+
+fn push_counter(out: &mut String, name: &str, help: &str, value: u64) {
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} counter\n{name} {value}\n"));
+}
+
+#[derive(Debug)]
+pub enum PrometheusExporterError {
+    Io(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for PrometheusExporterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrometheusExporterError::Io(err) => write!(f, "I/O error: {err}"),
+            PrometheusExporterError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for PrometheusExporterError {}
+
+/// Serves `/metrics` on `addr`, calling `snapshot` fresh on every
+/// request and writing [`render_prometheus_text`]'s output back as the
+/// response body. Blocks the calling thread, the same way
+/// [`super::server::listen`] does — intended to run on its own thread
+/// started from the Settings toggle's on-change handler.
+pub fn serve(addr: SocketAddr, snapshot: impl Fn() -> PrometheusMetricsSnapshot + Send + 'static) -> Result<(), PrometheusExporterError> {
+    imp::serve(addr, Box::new(snapshot))
+}
+
+type SnapshotFn = Box<dyn Fn() -> PrometheusMetricsSnapshot + Send>;
+
+// A plain `TcpListener`, unlike the control API's named pipe, has
+// nothing platform-specific about it — this gap is the same on every
+// target, so there's no `#[cfg(windows)]` split here the way
+// `super::server`'s `imp` has.
+mod imp {
+    use std::net::SocketAddr;
+
+    use super::{PrometheusExporterError, SnapshotFn};
+
+    pub(super) fn serve(_addr: SocketAddr, _snapshot: SnapshotFn) -> Result<(), PrometheusExporterError> {
+        // A `TcpListener::bind(addr)` loop, reading just enough of each
+        // request to confirm it's a GET to `/metrics` before writing a
+        // `200 text/plain; version=0.0.4` response with `snapshot()`'s
+        // rendered body — deliberately not wired up yet, the same gap
+        // `super::server::listen`'s HTTP half has, so both endpoints
+        // land together once this crate actually depends on an HTTP
+        // server instead of hand-rolling request parsing for each.
+        todo!("serve GET /metrics over a localhost TcpListener")
+    }
+}
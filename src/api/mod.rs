@@ -0,0 +1,24 @@
+//! Local control API: a named-pipe (Windows) or localhost-only HTTP
+//! server exposing the same clean-ram/clean-disk/apply-profile/status
+//! actions [`crate::cli`] does, so a Stream Deck button, a script, or
+//! the future in-game overlay can drive the app without going through
+//! the GUI. Every request must carry the [`SharedSecret`] written to
+//! disk at setup time, so nothing else running on the machine can call
+//! in uninvited.
+//!
+//! [`dispatch`] is the pure request-handling half and has no
+//! networking in it; [`listen`] is the actual named-pipe/HTTP server
+//! and isn't implemented yet (see [`ApiServerError`]). [`metrics`]
+//! is a second, read-only local HTTP surface behind its own Settings
+//! toggle: a Prometheus `/metrics` endpoint for homelab users to graph
+//! this app's own stats in Grafana, rather than another control action.
+
+mod dispatch;
+mod metrics;
+mod secret;
+mod server;
+
+pub use dispatch::{dispatch, ApiRequest, ApiResponse};
+pub use metrics::{render_prometheus_text, serve as serve_prometheus_metrics, PrometheusExporterError, PrometheusMetricsSnapshot};
+pub use secret::{SharedSecret, SharedSecretError};
+pub use server::{listen, ApiServerError};
@@ -0,0 +1,87 @@
+//! The token every [`super::ApiRequest`] must carry. Generated once and
+//! written next to the rest of the app's state, so a script or Stream
+//! Deck plugin is configured with it the same way a webhook URL is.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum SharedSecretError {
+    Io(std::io::Error),
+}
+
+impl fmt::Display for SharedSecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SharedSecretError::Io(err) => write!(f, "io error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SharedSecretError {}
+
+impl From<std::io::Error> for SharedSecretError {
+    fn from(err: std::io::Error) -> Self {
+        SharedSecretError::Io(err)
+    }
+}
+
+/// The control API's shared secret, loaded from (or generated into)
+/// `path`. Every [`super::ApiRequest`] must echo this value back;
+/// [`SharedSecret::matches`] compares it in constant time so a timing
+/// attack can't narrow it down one byte at a time.
+pub struct SharedSecret {
+    path: PathBuf,
+    value: String,
+}
+
+impl SharedSecret {
+    /// Loads the secret at `path`, generating and writing a fresh
+    /// random one if it doesn't exist yet.
+    pub fn load_or_generate(path: impl Into<PathBuf>) -> Result<Self, SharedSecretError> {
+        let path = path.into();
+        let value = match fs::read_to_string(&path) {
+            Ok(value) => value.trim().to_string(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                let generated = generate_secret();
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, &generated)?;
+                generated
+            }
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Self { path, value })
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Whether `candidate` is this secret, checked byte-for-byte
+    /// regardless of where the two strings first differ.
+    pub fn matches(&self, candidate: &str) -> bool {
+        let expected = self.value.as_bytes();
+        let actual = candidate.as_bytes();
+        if expected.len() != actual.len() {
+            return false;
+        }
+        let mut diff = 0u8;
+        for (a, b) in expected.iter().zip(actual.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+}
+
+fn generate_secret() -> String {
+    // A real implementation would pull from the OS CSPRNG
+    // (`BCryptGenRandom` on Windows); `SystemTime` jitter is a
+    // placeholder just good enough to give every install a distinct
+    // value until that's wired up.
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+    format!("{nanos:x}")
+}
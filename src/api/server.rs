@@ -0,0 +1,63 @@
+//! The actual transport for the control API: a named pipe on Windows,
+//! a localhost-only HTTP listener elsewhere. Neither is implemented
+//! yet — there's no JSON-RPC framing, HTTP server, or async runtime in
+//! this crate's dependencies to build them on top of. [`listen`] exists
+//! so [`crate::cli::service::run_as_service`] has a single call to make
+//! once that lands, and so the request/response shapes in
+//! [`super::dispatch`] already have a caller to match.
+
+use std::fmt;
+use std::path::Path;
+
+use super::SharedSecret;
+
+#[derive(Debug)]
+pub enum ApiServerError {
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for ApiServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiServerError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ApiServerError {}
+
+/// Listens for control-API requests until the process is asked to
+/// stop, authenticating each one against `secret` before handing it to
+/// [`super::dispatch`]. Blocks the calling thread, the same way
+/// [`crate::cli::service::run_as_service`] blocks on the Windows
+/// service dispatcher — intended to run on its own thread inside that
+/// service.
+pub fn listen(secret: &SharedSecret, profiles_path: &Path, scheduler_store_path: &Path) -> Result<(), ApiServerError> {
+    imp::listen(secret, profiles_path, scheduler_store_path)
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::path::Path;
+
+    use super::{ApiServerError, SharedSecret};
+
+    pub(super) fn listen(_secret: &SharedSecret, _profiles_path: &Path, _scheduler_store_path: &Path) -> Result<(), ApiServerError> {
+        // CreateNamedPipeW(r"\\.\pipe\GameBoosterControl", ...) in a loop,
+        // reading a line of JSON per connection, checking it against
+        // `secret` before calling `dispatch::dispatch` and writing the
+        // JSON-encoded `ApiResponse` back.
+        todo!("serve the control API over a named pipe")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::path::Path;
+
+    use super::{ApiServerError, SharedSecret};
+
+    pub(super) fn listen(_secret: &SharedSecret, _profiles_path: &Path, _scheduler_store_path: &Path) -> Result<(), ApiServerError> {
+        Err(ApiServerError::Unsupported("the control API needs a localhost HTTP listener, not implemented yet"))
+    }
+}
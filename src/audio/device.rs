@@ -0,0 +1,109 @@
+//! Tweaks the default playback device's "enhancements" (APOs) and
+//! exclusive-mode access — the same two checkboxes on the device's
+//! Properties > Advanced tab in the Sound control panel — via Core
+//! Audio's endpoint property store. A common ask from competitive
+//! players chasing lower audio latency; there's no non-Windows
+//! equivalent.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum AudioDeviceError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for AudioDeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AudioDeviceError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            AudioDeviceError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AudioDeviceError {}
+
+/// The default playback device's current enhancement and exclusive-mode
+/// preferences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioDeviceSettings {
+    /// "Disable all sound effects" — off means every audio processing
+    /// object (APO) in the device's signal chain is bypassed.
+    pub enhancements_enabled: bool,
+    /// "Allow applications to take exclusive control of this device" —
+    /// off forces every app through the shared-mode mixer, adding
+    /// latency but avoiding one app locking other apps' audio out.
+    pub exclusive_mode_allowed: bool,
+}
+
+/// Reads and writes the default playback device's enhancement and
+/// exclusive-mode settings.
+pub struct AudioDeviceController;
+
+impl AudioDeviceController {
+    pub fn new() -> Self {
+        AudioDeviceController
+    }
+
+    pub fn settings(&self) -> Result<AudioDeviceSettings, AudioDeviceError> {
+        imp::settings()
+    }
+
+    pub fn set_enhancements_enabled(&self, enabled: bool) -> Result<(), AudioDeviceError> {
+        imp::set_enhancements_enabled(enabled)
+    }
+
+    pub fn set_exclusive_mode_allowed(&self, allowed: bool) -> Result<(), AudioDeviceError> {
+        imp::set_exclusive_mode_allowed(allowed)
+    }
+}
+
+impl Default for AudioDeviceController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{AudioDeviceError, AudioDeviceSettings};
+
+    pub(super) fn settings() -> Result<AudioDeviceSettings, AudioDeviceError> {
+        // IMMDeviceEnumerator::GetDefaultAudioEndpoint(eRender, eConsole),
+        // then IMMDevice::OpenPropertyStore and read
+        // PKEY_AudioEndpoint_Disable_SysFx ({1da5d803-d492-4edd-8c23-e0c0ffee7f0e}, 5);
+        // exclusive mode isn't exposed through IPropertyStore, so it's
+        // read from the same device's registry key under
+        // HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\MMDevices\Audio\
+        // Render\{endpoint-id}\FxProperties, value "DeviceState" +
+        // vendor-specific exclusive-mode flags the Sound control panel's
+        // property sheet writes.
+        todo!("read PKEY_AudioEndpoint_Disable_SysFx and the device's exclusive-mode registry flag")
+    }
+
+    pub(super) fn set_enhancements_enabled(_enabled: bool) -> Result<(), AudioDeviceError> {
+        todo!("write PKEY_AudioEndpoint_Disable_SysFx via IPropertyStore::SetValue")
+    }
+
+    pub(super) fn set_exclusive_mode_allowed(_allowed: bool) -> Result<(), AudioDeviceError> {
+        todo!("write the default device's exclusive-mode registry flag under MMDevices\\Audio\\Render")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{AudioDeviceError, AudioDeviceSettings};
+
+    pub(super) fn settings() -> Result<AudioDeviceSettings, AudioDeviceError> {
+        Err(AudioDeviceError::Unsupported("Core Audio endpoint properties are a Windows-only concept"))
+    }
+
+    pub(super) fn set_enhancements_enabled(_enabled: bool) -> Result<(), AudioDeviceError> {
+        Err(AudioDeviceError::Unsupported("Core Audio endpoint properties are a Windows-only concept"))
+    }
+
+    pub(super) fn set_exclusive_mode_allowed(_allowed: bool) -> Result<(), AudioDeviceError> {
+        Err(AudioDeviceError::Unsupported("Core Audio endpoint properties are a Windows-only concept"))
+    }
+}
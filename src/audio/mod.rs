@@ -0,0 +1,5 @@
+//! Default playback device tweaks for lower audio latency.
+
+pub mod device;
+
+pub use device::{AudioDeviceController, AudioDeviceError};
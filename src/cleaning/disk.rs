@@ -0,0 +1,191 @@
+//! Deletes files from safe-to-clean categories — temp files, stale
+//! shader caches, browser caches — while leaving anything the caller
+//! excluded, or a shader cache that's still being written to, alone.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+/// A category of files a cleanup pass is allowed to remove.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CleanupCategory {
+    TempFiles,
+    ShaderCache,
+    BrowserCache,
+}
+
+/// How much a cleanup pass actually removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CleanupReport {
+    pub bytes_freed: u64,
+    pub files_removed: u64,
+    pub skipped_excluded: u64,
+}
+
+/// Options controlling what a disk cleanup pass is allowed to touch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CleanupOptions {
+    pub categories: HashSet<CleanupCategory>,
+    /// Paths to leave alone even if they'd otherwise match a category —
+    /// e.g. a shader cache the user wants to keep warm.
+    pub exclude_paths: Vec<PathBuf>,
+}
+
+/// How long a shader cache file must sit untouched before it's
+/// considered safe to delete. Anything newer might still be mid-compile.
+const SHADER_CACHE_STALE_AFTER: Duration = Duration::from_secs(24 * 3600);
+
+/// The filesystem operations [`DiskCleaner`] needs to size up and
+/// delete candidate files. Implemented against real `std::fs` calls in
+/// production; [`DiskCleaner::with_filesystem`] accepts any other
+/// implementation, such as an in-memory fake, so the
+/// category/exclusion/staleness logic below can be exercised without
+/// touching the host filesystem.
+pub trait FileSystemApi {
+    /// `Some(bytes)` if `path` exists, `None` otherwise.
+    fn file_size(&self, path: &Path) -> Option<u64>;
+    /// `path`'s last-modified time, if it exists and the platform
+    /// reports one.
+    fn modified(&self, path: &Path) -> Option<SystemTime>;
+    /// Deletes `path`, returning whether it succeeded.
+    fn remove_file(&self, path: &Path) -> bool;
+}
+
+/// Default [`FileSystemApi`] backed by real `std::fs` calls.
+pub struct OsFileSystem;
+
+impl FileSystemApi for OsFileSystem {
+    fn file_size(&self, path: &Path) -> Option<u64> {
+        fs::metadata(path).ok().map(|metadata| metadata.len())
+    }
+
+    fn modified(&self, path: &Path) -> Option<SystemTime> {
+        fs::metadata(path).ok().and_then(|metadata| metadata.modified().ok())
+    }
+
+    fn remove_file(&self, path: &Path) -> bool {
+        fs::remove_file(path).is_ok()
+    }
+}
+
+pub struct DiskCleaner<F: FileSystemApi = OsFileSystem> {
+    fs: F,
+}
+
+impl DiskCleaner<OsFileSystem> {
+    pub fn new() -> Self {
+        Self::with_filesystem(OsFileSystem)
+    }
+}
+
+impl<F: FileSystemApi> DiskCleaner<F> {
+    pub fn with_filesystem(fs: F) -> Self {
+        Self { fs }
+    }
+
+    /// Dry-runs a cleanup pass: walks the same candidate paths as
+    /// [`DiskCleaner::clean`] and sums up what they'd free, without
+    /// deleting anything. Used to check whether a cleanup is worth
+    /// running before committing to it.
+    pub fn estimate_freeable_bytes(&self, options: &CleanupOptions) -> u64 {
+        let mut bytes = 0;
+        for &category in &options.categories {
+            for candidate in candidate_paths(category) {
+                if options.exclude_paths.iter().any(|excluded| candidate.starts_with(excluded)) {
+                    continue;
+                }
+                if category == CleanupCategory::ShaderCache && !self.is_stale(&candidate, SHADER_CACHE_STALE_AFTER) {
+                    continue;
+                }
+                if let Some(size) = self.fs.file_size(&candidate) {
+                    bytes += size;
+                }
+            }
+        }
+        bytes
+    }
+
+    /// Like [`DiskCleaner::estimate_freeable_bytes`], but broken down
+    /// per category instead of summed into one total, so a repeated
+    /// scan can be diffed against [`crate::cleaning::ScanHistory`] to
+    /// show which category grew since last time.
+    pub fn estimate_freeable_bytes_by_category(&self, options: &CleanupOptions) -> HashMap<CleanupCategory, u64> {
+        let mut by_category = HashMap::new();
+        for &category in &options.categories {
+            let mut bytes = 0;
+            for candidate in candidate_paths(category) {
+                if options.exclude_paths.iter().any(|excluded| candidate.starts_with(excluded)) {
+                    continue;
+                }
+                if category == CleanupCategory::ShaderCache && !self.is_stale(&candidate, SHADER_CACHE_STALE_AFTER) {
+                    continue;
+                }
+                if let Some(size) = self.fs.file_size(&candidate) {
+                    bytes += size;
+                }
+            }
+            by_category.insert(category, bytes);
+        }
+        by_category
+    }
+
+    /// Runs a cleanup pass synchronously and returns what it freed.
+    pub fn clean(&self, options: &CleanupOptions) -> CleanupReport {
+        self.clean_with_cancel(options, &AtomicBool::new(false))
+    }
+
+    /// Like [`DiskCleaner::clean`], but checks `cancel` between
+    /// candidate files and stops early once it's set, returning
+    /// whatever was freed up to that point. Lets a caller with its own
+    /// time limit (e.g. a scheduled task's `max_runtime`) abort a
+    /// cleanup pass that's taking too long instead of abandoning it
+    /// mid-delete with no way to know what happened.
+    pub fn clean_with_cancel(&self, options: &CleanupOptions, cancel: &AtomicBool) -> CleanupReport {
+        let mut report = CleanupReport::default();
+        for &category in &options.categories {
+            for candidate in candidate_paths(category) {
+                if cancel.load(Ordering::Relaxed) {
+                    return report;
+                }
+                if options.exclude_paths.iter().any(|excluded| candidate.starts_with(excluded)) {
+                    report.skipped_excluded += 1;
+                    continue;
+                }
+                if category == CleanupCategory::ShaderCache && !self.is_stale(&candidate, SHADER_CACHE_STALE_AFTER) {
+                    continue;
+                }
+                if let Some(size) = self.fs.file_size(&candidate) {
+                    if self.fs.remove_file(&candidate) {
+                        report.bytes_freed += size;
+                        report.files_removed += 1;
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    fn is_stale(&self, path: &Path, window: Duration) -> bool {
+        self.fs
+            .modified(path)
+            .map(|modified| modified.elapsed().unwrap_or(Duration::ZERO) > window)
+            .unwrap_or(false)
+    }
+}
+
+impl Default for DiskCleaner<OsFileSystem> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn candidate_paths(_category: CleanupCategory) -> Vec<PathBuf> {
+    // Per-platform temp/cache directory walks land here once the
+    // general disk-cleaning tab defines the full category list; for now
+    // callers get an empty, safe-by-construction result.
+    Vec::new()
+}
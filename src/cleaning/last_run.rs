@@ -0,0 +1,44 @@
+//! Tracks when something last ran, persisted to disk so a "skip if run
+//! within the last N hours" check survives an app restart.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+pub struct LastRunTracker {
+    state_dir: PathBuf,
+}
+
+impl LastRunTracker {
+    pub fn new(state_dir: impl Into<PathBuf>) -> Self {
+        Self { state_dir: state_dir.into() }
+    }
+
+    fn marker_path(&self, key: &str) -> PathBuf {
+        self.state_dir.join(format!("{key}.last_run"))
+    }
+
+    /// Returns `true` if `key` last ran less than `window` ago.
+    pub fn ran_within(&self, key: &str, window: Duration) -> bool {
+        fs::metadata(self.marker_path(key))
+            .and_then(|m| m.modified())
+            .map(|modified| modified.elapsed().unwrap_or(Duration::MAX) < window)
+            .unwrap_or(false)
+    }
+
+    /// Returns how long ago `key` last ran, or `None` if it never has —
+    /// for the Dashboard tab's "last cleanup" card.
+    pub fn ran_ago(&self, key: &str) -> Option<Duration> {
+        fs::metadata(self.marker_path(key)).and_then(|m| m.modified()).ok()?.elapsed().ok()
+    }
+
+    /// Records that `key` ran just now.
+    pub fn record_ran(&self, key: &str) -> std::io::Result<()> {
+        fs::create_dir_all(&self.state_dir)?;
+        let now_secs = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        fs::write(self.marker_path(key), now_secs.to_string())
+    }
+}
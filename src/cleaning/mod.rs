@@ -0,0 +1,10 @@
+//! Disk cleanup: frees space from safe-to-delete categories while
+//! respecting user exclusions and shader-cache safety rules.
+
+pub mod disk;
+pub mod last_run;
+pub mod scan_history;
+
+pub use disk::{CleanupCategory, CleanupOptions, CleanupReport, DiskCleaner, FileSystemApi, OsFileSystem};
+pub use last_run::LastRunTracker;
+pub use scan_history::{CategoryDelta, ScanHistory, ScanSnapshot};
@@ -0,0 +1,124 @@
+//! Tracks per-category freeable-byte estimates across disk scans, so a
+//! new scan can show "+320 MB temp since yesterday" instead of just a
+//! flat total. Persisted the same JSON-array-rewrite-on-append approach
+//! as [`crate::history::ActionHistoryLog`].
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::disk::CleanupCategory;
+
+/// How many scans [`ScanHistory`] keeps before dropping the oldest —
+/// enough for the growth chart to show a meaningful trend without the
+/// file growing forever.
+const SCAN_HISTORY_CAPACITY: usize = 200;
+
+#[derive(Debug)]
+pub enum ScanHistoryError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ScanHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScanHistoryError::Io(err) => write!(f, "io error: {err}"),
+            ScanHistoryError::Parse(err) => write!(f, "invalid scan history: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScanHistoryError {}
+
+impl From<std::io::Error> for ScanHistoryError {
+    fn from(err: std::io::Error) -> Self {
+        ScanHistoryError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ScanHistoryError {
+    fn from(err: serde_json::Error) -> Self {
+        ScanHistoryError::Parse(err)
+    }
+}
+
+/// One scan's freeable-byte estimate per category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScanSnapshot {
+    pub at: SystemTime,
+    pub freeable_bytes_by_category: HashMap<CleanupCategory, u64>,
+}
+
+/// How much a category's freeable bytes changed between two scans, for
+/// the Dashboard's "since last scan" panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryDelta {
+    pub category: CleanupCategory,
+    pub previous_bytes: u64,
+    pub current_bytes: u64,
+}
+
+impl CategoryDelta {
+    pub fn delta_bytes(&self) -> i64 {
+        self.current_bytes as i64 - self.previous_bytes as i64
+    }
+}
+
+/// Persists every [`ScanSnapshot`] taken, oldest first.
+pub struct ScanHistory {
+    path: PathBuf,
+}
+
+impl ScanHistory {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn snapshots(&self) -> Result<Vec<ScanSnapshot>, ScanHistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        if data.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Appends a new snapshot for `freeable_bytes_by_category`, dropping
+    /// the oldest once [`SCAN_HISTORY_CAPACITY`] is exceeded, and
+    /// returns the per-category deltas against the immediately
+    /// preceding scan — empty if this is the first scan recorded.
+    pub fn record_scan(&self, freeable_bytes_by_category: HashMap<CleanupCategory, u64>) -> Result<Vec<CategoryDelta>, ScanHistoryError> {
+        let mut snapshots = self.snapshots()?;
+
+        let deltas = match snapshots.last() {
+            Some(previous) => freeable_bytes_by_category
+                .iter()
+                .map(|(&category, &current_bytes)| CategoryDelta {
+                    category,
+                    previous_bytes: previous.freeable_bytes_by_category.get(&category).copied().unwrap_or(0),
+                    current_bytes,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        snapshots.push(ScanSnapshot { at: SystemTime::now(), freeable_bytes_by_category });
+        if snapshots.len() > SCAN_HISTORY_CAPACITY {
+            snapshots.remove(0);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&snapshots)?)?;
+
+        Ok(deltas)
+    }
+}
@@ -0,0 +1,199 @@
+//! Parses the headless CLI surface (`clean-ram`, `clean-disk`, `scan`,
+//! `limit`, `task run`, `--service`) and drives the existing
+//! memory/disk/network/scheduler code directly, without spawning the
+//! egui window — for scripting, remote administration, and (via
+//! `--service`, see [`service`]) running unattended before any user
+//! logs in. There's no binary target in this crate yet; a future
+//! `src/bin/gamebooster.rs` would call [`parse`] on `std::env::args()`,
+//! call [`run`], and print whatever [`CliOutput`] comes back in the
+//! format [`OutputFormat::from_flag`] picked, the same way a GUI
+//! launcher constructs `CleanRamApp`.
+
+mod output;
+mod service;
+
+use std::fmt;
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::cleaning::{CleanupCategory, CleanupOptions, DiskCleaner};
+use crate::scheduler::{execute_task, tasks_needing_catch_up, SchedulerStore, SchedulerStoreError, TaskType};
+
+pub use output::{CliOutput, OutputFormat};
+pub use service::{ServiceError, ServiceStatus, SERVICE_NAME};
+
+/// One parsed CLI invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    CleanRam,
+    CleanDisk { categories: Vec<CleanupCategory>, dry_run: bool },
+    /// Estimates freeable bytes per category without deleting anything —
+    /// `clean-disk --dry-run`'s read-only cousin, always across every
+    /// category, for a quick "is this worth cleaning" check.
+    Scan,
+    Limit { exe: String, mbps: f64 },
+    TaskRun { id: String },
+    /// Runs the scheduler as a Windows service instead of executing one
+    /// command and exiting — see [`service::run_as_service`].
+    Service,
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    UnknownCommand(String),
+    MissingArgument { command: &'static str, argument: &'static str },
+    InvalidArgument { argument: &'static str, value: String },
+    TaskNotFound(String),
+    Store(SchedulerStoreError),
+    TaskFailed(String),
+    Service(ServiceError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownCommand(cmd) => write!(f, "unknown command: {cmd}"),
+            CliError::MissingArgument { command, argument } => write!(f, "{command}: missing <{argument}>"),
+            CliError::InvalidArgument { argument, value } => write!(f, "invalid value for {argument}: {value:?}"),
+            CliError::TaskNotFound(id) => write!(f, "no task named {id:?}"),
+            CliError::Store(err) => write!(f, "scheduler store error: {err}"),
+            CliError::TaskFailed(err) => write!(f, "task failed: {err}"),
+            CliError::Service(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<SchedulerStoreError> for CliError {
+    fn from(err: SchedulerStoreError) -> Self {
+        CliError::Store(err)
+    }
+}
+
+impl From<ServiceError> for CliError {
+    fn from(err: ServiceError) -> Self {
+        CliError::Service(err)
+    }
+}
+
+/// Parses `args` (the process's arguments with `argv[0]` already
+/// stripped) into a [`Command`].
+pub fn parse(args: &[String]) -> Result<Command, CliError> {
+    match args.first().map(String::as_str) {
+        Some("clean-ram") => Ok(Command::CleanRam),
+        Some("clean-disk") => parse_clean_disk(&args[1..]),
+        Some("scan") => Ok(Command::Scan),
+        Some("limit") => parse_limit(&args[1..]),
+        Some("task") => parse_task(&args[1..]),
+        Some("--service") => Ok(Command::Service),
+        Some(other) => Err(CliError::UnknownCommand(other.to_string())),
+        None => Err(CliError::UnknownCommand(String::new())),
+    }
+}
+
+fn parse_clean_disk(args: &[String]) -> Result<Command, CliError> {
+    let mut categories = Vec::new();
+    let mut dry_run = false;
+    for arg in args {
+        match arg.as_str() {
+            "--temp" => categories.push(CleanupCategory::TempFiles),
+            "--cache" => categories.push(CleanupCategory::BrowserCache),
+            "--shader-cache" => categories.push(CleanupCategory::ShaderCache),
+            "--dry-run" => dry_run = true,
+            other => return Err(CliError::InvalidArgument { argument: "clean-disk", value: other.to_string() }),
+        }
+    }
+    Ok(Command::CleanDisk { categories, dry_run })
+}
+
+fn parse_limit(args: &[String]) -> Result<Command, CliError> {
+    let exe = args.first().cloned().ok_or(CliError::MissingArgument { command: "limit", argument: "exe" })?;
+    let mbps_str = args.get(1).ok_or(CliError::MissingArgument { command: "limit", argument: "mbps" })?;
+    let mbps: f64 = mbps_str
+        .parse()
+        .map_err(|_| CliError::InvalidArgument { argument: "mbps", value: mbps_str.clone() })?;
+    Ok(Command::Limit { exe, mbps })
+}
+
+fn parse_task(args: &[String]) -> Result<Command, CliError> {
+    if args.first().map(String::as_str) != Some("run") {
+        return Err(CliError::UnknownCommand(format!("task {}", args.join(" "))));
+    }
+    let id = args.get(1).cloned().ok_or(CliError::MissingArgument { command: "task run", argument: "id" })?;
+    Ok(Command::TaskRun { id })
+}
+
+/// Runs a parsed [`Command`] to completion, calling straight into the
+/// same types the egui tabs call (`DiskCleaner`, `execute_task`,
+/// `SchedulerStore`) rather than duplicating their logic.
+/// `scheduler_store_path` is only consulted for [`Command::TaskRun`]
+/// and [`Command::Service`].
+pub fn run(command: Command, scheduler_store_path: &Path) -> Result<CliOutput, CliError> {
+    match command {
+        Command::CleanRam => {
+            let outcome = execute_task(&TaskType::CleanRam).map_err(CliError::TaskFailed)?;
+            Ok(CliOutput::RamCleaned(outcome))
+        }
+        Command::CleanDisk { categories, dry_run } => {
+            let options = CleanupOptions { categories: categories.into_iter().collect(), exclude_paths: Vec::new() };
+            let cleaner = DiskCleaner::new();
+            if dry_run {
+                let by_category = cleaner.estimate_freeable_bytes_by_category(&options);
+                Ok(CliOutput::DiskScanned(by_category))
+            } else {
+                let report = cleaner.clean(&options);
+                Ok(CliOutput::DiskCleaned(report))
+            }
+        }
+        Command::Scan => {
+            let options = CleanupOptions {
+                categories: [CleanupCategory::TempFiles, CleanupCategory::ShaderCache, CleanupCategory::BrowserCache].into_iter().collect(),
+                exclude_paths: Vec::new(),
+            };
+            let by_category = DiskCleaner::new().estimate_freeable_bytes_by_category(&options);
+            Ok(CliOutput::DiskScanned(by_category))
+        }
+        Command::Limit { exe, mbps } => {
+            let kbps = (mbps * 1000.0) as u32;
+            let task_type = TaskType::ApplyNetworkLimit { exe: exe.clone(), down_kbps: kbps, up_kbps: kbps };
+            execute_task(&task_type).map_err(CliError::TaskFailed)?;
+            Ok(CliOutput::LimitApplied { exe, kbps })
+        }
+        Command::TaskRun { id } => {
+            let store = SchedulerStore::load_from_file(scheduler_store_path.to_path_buf())?;
+            let task = store.find(&id).ok_or_else(|| CliError::TaskNotFound(id.clone()))?;
+            let outcome = execute_task(&task.task_type).map_err(CliError::TaskFailed)?;
+            Ok(CliOutput::TaskRun { id, outcome })
+        }
+        Command::Service => {
+            run_catch_up_tasks(scheduler_store_path)?;
+            service::run_as_service(scheduler_store_path)?;
+            Ok(CliOutput::ServiceStopped)
+        }
+    }
+}
+
+/// Runs every task [`tasks_needing_catch_up`] flags as having missed its
+/// scheduled occurrence while the service wasn't running, before handing
+/// off to [`service::run_as_service`]'s regular poll loop. Best-effort:
+/// a task that fails to catch up is recorded like any other failed run
+/// (see [`crate::scheduler::ScheduledTask::record_outcome`]), not
+/// retried here.
+fn run_catch_up_tasks(scheduler_store_path: &Path) -> Result<(), CliError> {
+    let mut store = SchedulerStore::load_from_file(scheduler_store_path.to_path_buf())?;
+    let due: Vec<String> =
+        tasks_needing_catch_up(store.tasks(), SystemTime::now()).into_iter().map(|task| task.name.clone()).collect();
+
+    for name in due {
+        let Some(task_type) = store.find(&name).map(|task| task.task_type.clone()) else { continue };
+        let result = execute_task(&task_type).map(|_| ());
+        if let Some(task) = store.tasks_mut().iter_mut().find(|task| task.name == name) {
+            task.record_outcome(&result);
+            task.record_run_at(SystemTime::now());
+        }
+    }
+
+    store.save_to_file()?;
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! Formats a [`super::CliOutput`] as JSON or plain text, so the same
+//! CLI command works for a script parsing machine-readable output and
+//! for a human reading it in a terminal.
+
+use std::collections::HashMap;
+
+use crate::cleaning::{CleanupCategory, CleanupReport};
+use crate::scheduler::TaskOutcome;
+
+/// Which of the two the `--format`/`--json` flag on the (not-yet-built)
+/// binary picked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Human,
+    Json,
+}
+
+impl OutputFormat {
+    /// Parses a `--format` flag's value, defaulting to [`OutputFormat::Human`]
+    /// for anything that isn't recognized rather than failing the whole
+    /// command over an output preference.
+    pub fn from_flag(value: Option<&str>) -> Self {
+        match value {
+            Some("json") => OutputFormat::Json,
+            _ => OutputFormat::Human,
+        }
+    }
+}
+
+/// What a CLI command produced, ready to render in either format.
+#[derive(Debug, Clone)]
+pub enum CliOutput {
+    RamCleaned(TaskOutcome),
+    DiskCleaned(CleanupReport),
+    DiskScanned(HashMap<CleanupCategory, u64>),
+    LimitApplied { exe: String, kbps: u32 },
+    TaskRun { id: String, outcome: TaskOutcome },
+    /// The `--service` run loop exited because the Service Control
+    /// Manager asked it to stop.
+    ServiceStopped,
+}
+
+fn category_label(category: CleanupCategory) -> &'static str {
+    match category {
+        CleanupCategory::TempFiles => "temp",
+        CleanupCategory::ShaderCache => "shader_cache",
+        CleanupCategory::BrowserCache => "browser_cache",
+    }
+}
+
+impl CliOutput {
+    /// Renders this result the way `format` asks for.
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Human => self.to_human(),
+            OutputFormat::Json => self.to_json(),
+        }
+    }
+
+    fn to_human(&self) -> String {
+        match self {
+            CliOutput::RamCleaned(_) => "RAM cleaned".to_string(),
+            CliOutput::DiskCleaned(report) => {
+                format!("freed {} bytes across {} files ({} skipped)", report.bytes_freed, report.files_removed, report.skipped_excluded)
+            }
+            CliOutput::DiskScanned(by_category) => {
+                let mut lines: Vec<String> = by_category.iter().map(|(category, bytes)| format!("{}: {bytes} bytes", category_label(*category))).collect();
+                lines.sort();
+                lines.join("\n")
+            }
+            CliOutput::LimitApplied { exe, kbps } => format!("{exe} limited to {kbps} kbps"),
+            CliOutput::TaskRun { id, outcome } => match &outcome.output {
+                Some(output) => format!("task {id}: {output}"),
+                None => format!("task {id}: ok"),
+            },
+            CliOutput::ServiceStopped => "service stopped".to_string(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        match self {
+            CliOutput::RamCleaned(_) => r#"{"ok":true,"action":"clean-ram"}"#.to_string(),
+            CliOutput::DiskCleaned(report) => format!(
+                r#"{{"ok":true,"action":"clean-disk","bytes_freed":{},"files_removed":{},"skipped_excluded":{}}}"#,
+                report.bytes_freed, report.files_removed, report.skipped_excluded
+            ),
+            CliOutput::DiskScanned(by_category) => {
+                let mut entries: Vec<String> = by_category.iter().map(|(category, bytes)| format!(r#""{}":{bytes}"#, category_label(*category))).collect();
+                entries.sort();
+                format!(r#"{{"ok":true,"action":"scan",{}}}"#, entries.join(","))
+            }
+            CliOutput::LimitApplied { exe, kbps } => {
+                format!(r#"{{"ok":true,"action":"limit","exe":{exe:?},"kbps":{kbps}}}"#)
+            }
+            CliOutput::TaskRun { id, outcome } => format!(
+                r#"{{"ok":true,"action":"task-run","id":{id:?},"bytes_freed":{},"output":{}}}"#,
+                outcome.bytes_freed,
+                outcome.output.as_deref().map(|s| format!("{s:?}")).unwrap_or_else(|| "null".to_string())
+            ),
+            CliOutput::ServiceStopped => r#"{"ok":true,"action":"service"}"#.to_string(),
+        }
+    }
+}
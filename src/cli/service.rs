@@ -0,0 +1,84 @@
+//! Hosts the scheduler as a long-running Windows service (via the
+//! `windows-service` crate) so due tasks still run with no user logged
+//! in and no GUI open, instead of only while the GUI happens to be
+//! running and polling [`crate::scheduler::check_task_condition`]
+//! itself. The GUI would connect to the running service over
+//! [`crate::api`]'s named pipe to show live status and trigger an
+//! immediate run; [`ServiceStatus`] predates that module and is kept
+//! here as the shape its `Status` response would fill in once the
+//! service loop calls [`crate::api::listen`] on its own thread.
+
+use std::fmt;
+use std::path::Path;
+
+use crate::scheduler::SchedulerStoreError;
+
+#[derive(Debug)]
+pub enum ServiceError {
+    Store(SchedulerStoreError),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceError::Store(err) => write!(f, "scheduler store error: {err}"),
+            ServiceError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+impl From<SchedulerStoreError> for ServiceError {
+    fn from(err: SchedulerStoreError) -> Self {
+        ServiceError::Store(err)
+    }
+}
+
+/// The short service name `sc.exe` and the SCM would register this
+/// under — same role as [`crate::system::instance::INSTANCE_NAME`] for
+/// the single-instance mutex.
+pub const SERVICE_NAME: &str = "GameBoosterScheduler";
+
+/// What the GUI would see over the (not yet built) status pipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceStatus {
+    pub running: bool,
+}
+
+/// Registers and runs the scheduler's task loop as the
+/// [`SERVICE_NAME`] Windows service, blocking until the Service
+/// Control Manager sends a stop control. `scheduler_store_path` is the
+/// same store [`crate::cli::Command::TaskRun`] reads from the GUI's
+/// schedule lives in.
+pub fn run_as_service(scheduler_store_path: &Path) -> Result<(), ServiceError> {
+    imp::run(scheduler_store_path)
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::path::Path;
+
+    use super::ServiceError;
+
+    pub(super) fn run(_scheduler_store_path: &Path) -> Result<(), ServiceError> {
+        // `define_windows_service!` would register a service_main that
+        // calls `service_dispatcher::start(SERVICE_NAME, ...)`, loads
+        // the `SchedulerStore` at `scheduler_store_path`, and loops
+        // `run_if_not_already_running` for every due task on a timer
+        // until the SCM control handler sees `ServiceControl::Stop`.
+        todo!("register and run the windows-service event loop")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::path::Path;
+
+    use super::ServiceError;
+
+    pub(super) fn run(_scheduler_store_path: &Path) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+}
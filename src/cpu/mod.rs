@@ -0,0 +1,9 @@
+//! CPU-side tuning: per-process priority, affinity, and I/O priority control.
+
+pub mod history;
+pub mod isolation;
+mod limiter;
+pub mod power;
+pub mod rules;
+
+pub use limiter::{CpuLimiter, CpuLimiterError, CpuPriority, IoPriority};
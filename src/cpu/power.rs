@@ -0,0 +1,208 @@
+//! Windows power plan management: switching to High Performance/Ultimate
+//! while gaming, restoring Balanced afterwards, and tweaking core parking
+//! and processor min/max throttle state.
+//!
+//! All of this rides on the Win32 Power Management API (`PowrProf.dll`);
+//! there is no non-Windows equivalent, so every entry point returns
+//! [`PowerError::Unsupported`] elsewhere.
+
+use std::fmt;
+
+/// A power plan ("scheme") as reported by Windows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerPlan {
+    pub guid: String,
+    pub name: String,
+}
+
+/// Well-known power plan GUIDs shipped with every Windows install.
+pub mod known_plans {
+    pub const BALANCED: &str = "381b4222-f694-41f0-9685-ff5bb260df2e";
+    pub const HIGH_PERFORMANCE: &str = "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c";
+    pub const ULTIMATE_PERFORMANCE: &str = "e9a42b02-d5df-448d-aa00-03f14749eb61";
+}
+
+// Processor power settings subgroup and setting GUIDs, as used by
+// `powercfg /setacvalueindex`.
+#[cfg(windows)]
+const SUBGROUP_PROCESSOR: &str = "54533251-82be-4824-96c1-47b60b740d00";
+const SETTING_PROCTHROTTLEMIN: &str = "893dee8e-2bef-41e0-89c6-b55d0929964c";
+const SETTING_PROCTHROTTLEMAX: &str = "bc5038f7-23e0-4960-96da-33abaf5935ec";
+const SETTING_CORE_PARKING_MIN_CORES: &str = "0cc5b647-c1df-4637-891a-dec35c318583";
+
+#[derive(Debug)]
+pub enum PowerError {
+    Unsupported(&'static str),
+    NotFound(String),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for PowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            PowerError::NotFound(guid) => write!(f, "power plan {guid} not found"),
+            PowerError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PowerError {}
+
+/// Snapshot of the processor power state we changed, so it can be put
+/// back exactly as we found it.
+#[derive(Debug, Clone)]
+pub struct PowerRestorePoint {
+    active_plan_guid: String,
+    proc_min_percent: u8,
+    proc_max_percent: u8,
+    core_parking_min_percent: u8,
+}
+
+/// Entry point for reading and changing Windows power plans and processor
+/// throttle state.
+pub struct PowerManager;
+
+impl PowerManager {
+    pub fn new() -> Self {
+        PowerManager
+    }
+
+    /// Lists every power plan known to Windows.
+    pub fn list_plans(&self) -> Result<Vec<PowerPlan>, PowerError> {
+        imp::list_plans()
+    }
+
+    /// Returns the GUID of the currently active power plan.
+    pub fn active_plan_guid(&self) -> Result<String, PowerError> {
+        imp::active_plan_guid()
+    }
+
+    /// Switches the active power plan to `guid`, e.g. to High Performance
+    /// or Ultimate Performance while a game is running.
+    pub fn set_active_plan(&self, guid: &str) -> Result<(), PowerError> {
+        imp::set_active_plan(guid)
+    }
+
+    /// Sets the minimum and maximum processor performance state, as a
+    /// percentage of max frequency, on the active plan's AC and DC
+    /// (battery) profiles.
+    pub fn set_processor_throttle_bounds(&self, min_percent: u8, max_percent: u8) -> Result<(), PowerError> {
+        imp::write_proc_setting(SETTING_PROCTHROTTLEMIN, min_percent)?;
+        imp::write_proc_setting(SETTING_PROCTHROTTLEMAX, max_percent)?;
+        imp::apply_active_plan()
+    }
+
+    /// Sets the minimum fraction of cores kept unparked, disabling core
+    /// parking entirely at 100%.
+    pub fn set_core_parking_min(&self, min_percent: u8) -> Result<(), PowerError> {
+        imp::write_proc_setting(SETTING_CORE_PARKING_MIN_CORES, min_percent)?;
+        imp::apply_active_plan()
+    }
+
+    /// Captures the current plan and processor throttle state so it can
+    /// later be restored with [`PowerManager::restore`].
+    pub fn snapshot(&self) -> Result<PowerRestorePoint, PowerError> {
+        Ok(PowerRestorePoint {
+            active_plan_guid: imp::active_plan_guid()?,
+            proc_min_percent: imp::read_proc_setting(SETTING_PROCTHROTTLEMIN)?,
+            proc_max_percent: imp::read_proc_setting(SETTING_PROCTHROTTLEMAX)?,
+            core_parking_min_percent: imp::read_proc_setting(SETTING_CORE_PARKING_MIN_CORES)?,
+        })
+    }
+
+    /// Restores a previously captured [`PowerRestorePoint`] — used both
+    /// for the explicit "restore defaults" safety action and for
+    /// switching back to Balanced once a game exits.
+    pub fn restore(&self, point: &PowerRestorePoint) -> Result<(), PowerError> {
+        imp::write_proc_setting(SETTING_PROCTHROTTLEMIN, point.proc_min_percent)?;
+        imp::write_proc_setting(SETTING_PROCTHROTTLEMAX, point.proc_max_percent)?;
+        imp::write_proc_setting(SETTING_CORE_PARKING_MIN_CORES, point.core_parking_min_percent)?;
+        imp::apply_active_plan()?;
+        imp::set_active_plan(&point.active_plan_guid)
+    }
+
+    /// Resets processor throttle bounds and core parking to the Windows
+    /// factory defaults (0/100/5) and switches back to Balanced.
+    pub fn restore_defaults(&self) -> Result<(), PowerError> {
+        self.restore(&PowerRestorePoint {
+            active_plan_guid: known_plans::BALANCED.to_string(),
+            proc_min_percent: 0,
+            proc_max_percent: 100,
+            core_parking_min_percent: 5,
+        })
+    }
+}
+
+impl Default for PowerManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{PowerError, PowerPlan, SUBGROUP_PROCESSOR};
+
+    pub(super) fn list_plans() -> Result<Vec<PowerPlan>, PowerError> {
+        // Walks PowerEnumerate(..., ACCESS_SCHEME, ...) and resolves each
+        // scheme GUID's friendly name with PowerReadFriendlyName.
+        todo!("enumerate schemes via PowrProf::PowerEnumerate")
+    }
+
+    pub(super) fn active_plan_guid() -> Result<String, PowerError> {
+        // PowerGetActiveScheme.
+        todo!("read the active scheme GUID via PowrProf::PowerGetActiveScheme")
+    }
+
+    pub(super) fn set_active_plan(_guid: &str) -> Result<(), PowerError> {
+        // PowerSetActiveScheme.
+        todo!("activate the scheme via PowrProf::PowerSetActiveScheme")
+    }
+
+    pub(super) fn write_proc_setting(_setting_guid: &str, _percent: u8) -> Result<(), PowerError> {
+        // PowerWriteACValueIndex(active plan, SUBGROUP_PROCESSOR, setting, percent)
+        // followed by PowerWriteDCValueIndex for the battery profile.
+        let _ = SUBGROUP_PROCESSOR;
+        todo!("write an AC/DC value index via PowrProf::PowerWriteACValueIndex")
+    }
+
+    pub(super) fn read_proc_setting(_setting_guid: &str) -> Result<u8, PowerError> {
+        todo!("read an AC value index via PowrProf::PowerReadACValueIndex")
+    }
+
+    pub(super) fn apply_active_plan() -> Result<(), PowerError> {
+        // PowerSetActiveScheme re-applied to the current scheme commits
+        // pending AC/DC value writes.
+        todo!("reapply the active scheme via PowrProf::PowerSetActiveScheme")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{PowerError, PowerPlan};
+
+    pub(super) fn list_plans() -> Result<Vec<PowerPlan>, PowerError> {
+        Err(PowerError::Unsupported("power plans are a Windows-only concept"))
+    }
+
+    pub(super) fn active_plan_guid() -> Result<String, PowerError> {
+        Err(PowerError::Unsupported("power plans are a Windows-only concept"))
+    }
+
+    pub(super) fn set_active_plan(_guid: &str) -> Result<(), PowerError> {
+        Err(PowerError::Unsupported("power plans are a Windows-only concept"))
+    }
+
+    pub(super) fn write_proc_setting(_setting_guid: &str, _percent: u8) -> Result<(), PowerError> {
+        Err(PowerError::Unsupported("processor throttle states are a Windows-only concept"))
+    }
+
+    pub(super) fn read_proc_setting(_setting_guid: &str) -> Result<u8, PowerError> {
+        Err(PowerError::Unsupported("processor throttle states are a Windows-only concept"))
+    }
+
+    pub(super) fn apply_active_plan() -> Result<(), PowerError> {
+        Err(PowerError::Unsupported("power plans are a Windows-only concept"))
+    }
+}
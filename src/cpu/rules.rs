@@ -0,0 +1,149 @@
+//! Per-process CPU tuning rules, and JSON import/export so a squad can
+//! share identical priority/affinity/rate setups across machines.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::limiter::{CpuPriority, IoPriority};
+
+/// A single process's desired CPU tuning, matched by executable name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CpuRule {
+    /// Executable name the rule matches against, e.g.
+    /// `"VALORANT-Win64-Shipping.exe"`.
+    pub process_name: String,
+    pub priority: Option<CpuPriority>,
+    pub affinity_mask: Option<u64>,
+    pub io_priority: Option<IoPriority>,
+    pub cpu_quota_percent: Option<u8>,
+}
+
+/// A named, ordered collection of [`CpuRule`]s — the unit exported to and
+/// imported from a shared JSON file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CpuRuleSet {
+    pub rules: Vec<CpuRule>,
+}
+
+#[derive(Debug)]
+pub enum CpuRuleError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for CpuRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CpuRuleError::Io(err) => write!(f, "io error: {err}"),
+            CpuRuleError::Parse(err) => write!(f, "invalid rule file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CpuRuleError {}
+
+impl From<std::io::Error> for CpuRuleError {
+    fn from(err: std::io::Error) -> Self {
+        CpuRuleError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for CpuRuleError {
+    fn from(err: serde_json::Error) -> Self {
+        CpuRuleError::Parse(err)
+    }
+}
+
+impl CpuRuleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Finds the rule matching `process_name`, if any, case-insensitively
+    /// (Windows executable names are case-insensitive).
+    pub fn rule_for(&self, process_name: &str) -> Option<&CpuRule> {
+        self.rules.iter().find(|r| r.process_name.eq_ignore_ascii_case(process_name))
+    }
+
+    /// Serializes the rule set to pretty-printed JSON and writes it to
+    /// `path`.
+    pub fn export_to_file(&self, path: &Path) -> Result<(), CpuRuleError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Reads and parses a rule set previously written by
+    /// [`CpuRuleSet::export_to_file`] — including one exported on another
+    /// machine.
+    pub fn import_from_file(path: &Path) -> Result<Self, CpuRuleError> {
+        let data = fs::read_to_string(path)?;
+        let set: CpuRuleSet = serde_json::from_str(&data)?;
+        Ok(set)
+    }
+
+    /// Merges `other`'s rules into this set, with `other` winning on
+    /// process name conflicts. Used when importing onto a machine that
+    /// already has some rules configured.
+    pub fn merge(&mut self, other: CpuRuleSet) {
+        for incoming in other.rules {
+            if let Some(existing) = self
+                .rules
+                .iter_mut()
+                .find(|r| r.process_name.eq_ignore_ascii_case(&incoming.process_name))
+            {
+                *existing = incoming;
+            } else {
+                self.rules.push(incoming);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(process_name: &str, priority: CpuPriority) -> CpuRule {
+        CpuRule { process_name: process_name.to_string(), priority: Some(priority), affinity_mask: None, io_priority: None, cpu_quota_percent: None }
+    }
+
+    #[test]
+    fn rule_for_matches_case_insensitively() {
+        let set = CpuRuleSet { rules: vec![rule("Game.exe", CpuPriority::High)] };
+        assert!(set.rule_for("game.exe").is_some());
+        assert!(set.rule_for("GAME.EXE").is_some());
+        assert!(set.rule_for("other.exe").is_none());
+    }
+
+    #[test]
+    fn merge_adds_rules_for_new_process_names() {
+        let mut set = CpuRuleSet { rules: vec![rule("a.exe", CpuPriority::High)] };
+        set.merge(CpuRuleSet { rules: vec![rule("b.exe", CpuPriority::Idle)] });
+        assert_eq!(set.rules.len(), 2);
+        assert!(set.rule_for("b.exe").is_some());
+    }
+
+    #[test]
+    fn merge_lets_the_incoming_rule_win_on_name_conflicts() {
+        let mut set = CpuRuleSet { rules: vec![rule("a.exe", CpuPriority::Normal)] };
+        set.merge(CpuRuleSet { rules: vec![rule("A.EXE", CpuPriority::Realtime)] });
+        assert_eq!(set.rules.len(), 1);
+        assert_eq!(set.rule_for("a.exe").unwrap().priority, Some(CpuPriority::Realtime));
+    }
+
+    #[test]
+    fn export_then_import_round_trips_a_rule_set() {
+        let path = std::env::temp_dir().join(format!("gamebooster_cpu_rules_test_{}.json", std::process::id()));
+        let set = CpuRuleSet { rules: vec![rule("a.exe", CpuPriority::High), rule("b.exe", CpuPriority::Idle)] };
+
+        set.export_to_file(&path).unwrap();
+        let imported = CpuRuleSet::import_from_file(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(imported.rules, set.rules);
+    }
+}
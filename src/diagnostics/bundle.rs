@@ -0,0 +1,64 @@
+//! Gathers every crash report and the current log file into one
+//! directory for attaching to a bug report.
+//!
+//! This crate has no archive dependency, so
+//! [`write_diagnostics_bundle`] stops at collecting the files into
+//! `dest_dir` rather than actually zipping them — the "offers to zip"
+//! part of the request needs either a `zip` crate dependency or
+//! shelling out to the platform's own compressor (`tar`/`Compress-Archive`),
+//! neither of which is wired up yet. Callers can already point a
+//! support instructions page at `dest_dir` itself, which is a valid
+//! (if uncompressed) bug-report attachment on its own.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum DiagnosticsBundleError {
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DiagnosticsBundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticsBundleError::Io(err) => write!(f, "io error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for DiagnosticsBundleError {}
+
+impl From<std::io::Error> for DiagnosticsBundleError {
+    fn from(err: std::io::Error) -> Self {
+        DiagnosticsBundleError::Io(err)
+    }
+}
+
+/// Copies every `crash-*.json` report under `crash_dir`, plus
+/// `log_file` if it exists, into a fresh `dest_dir`. Returns `dest_dir`
+/// once everything's copied, for the caller to show in a "bundle saved
+/// to..." confirmation.
+pub fn write_diagnostics_bundle(crash_dir: &Path, log_file: Option<&Path>, dest_dir: &Path) -> Result<PathBuf, DiagnosticsBundleError> {
+    fs::create_dir_all(dest_dir)?;
+
+    if let Ok(entries) = fs::read_dir(crash_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string();
+            if file_name.starts_with("crash-") && file_name.ends_with(".json") {
+                fs::copy(&path, dest_dir.join(&file_name))?;
+            }
+        }
+    }
+
+    if let Some(log_file) = log_file {
+        if let Some(file_name) = log_file.file_name() {
+            if log_file.exists() {
+                fs::copy(log_file, dest_dir.join(file_name))?;
+            }
+        }
+    }
+
+    Ok(dest_dir.to_path_buf())
+}
@@ -0,0 +1,117 @@
+//! Captures what a panic hook can still see about the process as it
+//! goes down — a backtrace, the run-up to it from [`LogBuffer`], and a
+//! config snapshot the caller hands in — and writes it to
+//! `logs/crashes/` so the next launch can offer to show it.
+
+use std::backtrace::Backtrace;
+use std::fmt;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::LogBuffer;
+
+#[derive(Debug)]
+pub enum CrashReportError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for CrashReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrashReportError::Io(err) => write!(f, "io error: {err}"),
+            CrashReportError::Parse(err) => write!(f, "invalid crash report: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CrashReportError {}
+
+impl From<std::io::Error> for CrashReportError {
+    fn from(err: std::io::Error) -> Self {
+        CrashReportError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for CrashReportError {
+    fn from(err: serde_json::Error) -> Self {
+        CrashReportError::Parse(err)
+    }
+}
+
+/// Everything captured about one crash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub at: SystemTime,
+    pub message: String,
+    pub backtrace: String,
+    pub recent_log_lines: Vec<String>,
+    pub config_snapshot: String,
+}
+
+impl CrashReport {
+    /// Writes this report as its own timestamped JSON file under
+    /// `crash_dir`, then updates `crash_dir`'s pending-report marker to
+    /// point at it so [`find_pending_crash_report`] picks it up on the
+    /// next launch.
+    fn write_to(&self, crash_dir: &Path) -> Result<PathBuf, CrashReportError> {
+        fs::create_dir_all(crash_dir)?;
+        let nanos = self.at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_nanos();
+        let report_path = crash_dir.join(format!("crash-{nanos}.json"));
+        fs::write(&report_path, serde_json::to_string_pretty(self)?)?;
+        fs::write(pending_marker_path(crash_dir), report_path.to_string_lossy().as_bytes())?;
+        Ok(report_path)
+    }
+}
+
+fn pending_marker_path(crash_dir: &Path) -> PathBuf {
+    crash_dir.join("pending")
+}
+
+/// Installs a panic hook that builds a [`CrashReport`] from the panic
+/// message, a freshly captured backtrace, `log`'s recent lines, and
+/// whatever `config_snapshot` returns, then writes it under
+/// `crash_dir`. Chains to whatever hook was previously installed
+/// afterwards, so `RUST_BACKTRACE`'s own stderr dump (if enabled)
+/// still happens too.
+pub fn install_panic_hook(crash_dir: PathBuf, log: Arc<LogBuffer>, config_snapshot: impl Fn() -> String + Send + Sync + 'static) {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let report = CrashReport {
+            at: SystemTime::now(),
+            message: info.to_string(),
+            backtrace: Backtrace::force_capture().to_string(),
+            recent_log_lines: log.recent_lines(),
+            config_snapshot: config_snapshot(),
+        };
+        // A crash report that fails to write is still a crash; there's
+        // nowhere left to report that failure to, so it's swallowed.
+        let _ = report.write_to(&crash_dir);
+        previous(info);
+    }));
+}
+
+/// Checks `crash_dir` for a crash report left by the previous run and
+/// not yet shown, for the recovery dialog the next launch shows. Does
+/// not clear the pending marker — call
+/// [`acknowledge_pending_crash_report`] once the dialog has been shown.
+pub fn find_pending_crash_report(crash_dir: &Path) -> Option<CrashReport> {
+    let report_path = fs::read_to_string(pending_marker_path(crash_dir)).ok()?;
+    let contents = fs::read_to_string(report_path.trim()).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Clears `crash_dir`'s pending marker so the recovery dialog only
+/// shows once per crash.
+pub fn acknowledge_pending_crash_report(crash_dir: &Path) -> Result<(), CrashReportError> {
+    match fs::remove_file(pending_marker_path(crash_dir)) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
@@ -0,0 +1,44 @@
+//! A bounded, shareable ring of the app's most recent log lines, so a
+//! crash report can include what happened right before it without
+//! re-reading a log file from disk. Nothing in this crate feeds it
+//! yet — there's no logging facade wired up — so it currently starts
+//! (and stays) empty until a future `log`/`tracing` subscriber calls
+//! [`LogBuffer::push_line`].
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// How many lines [`LogBuffer`] keeps before dropping the oldest —
+/// enough for a crash report to show the run-up to a crash without the
+/// buffer growing unbounded over a long session.
+pub const LOG_BUFFER_CAPACITY: usize = 200;
+
+/// Shared across every thread that logs something and the panic hook
+/// that reads it back out, behind a [`Mutex`] the same way
+/// [`crate::scheduler::RunningTasks`] shares its task set.
+#[derive(Default)]
+pub struct LogBuffer {
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `line`, dropping the oldest line once the buffer is at
+    /// [`LOG_BUFFER_CAPACITY`].
+    pub fn push_line(&self, line: impl Into<String>) {
+        let mut lines = self.lines.lock().unwrap();
+        if lines.len() >= LOG_BUFFER_CAPACITY {
+            lines.pop_front();
+        }
+        lines.push_back(line.into());
+    }
+
+    /// The lines currently held, oldest first — what a crash report
+    /// embeds under "recent log lines".
+    pub fn recent_lines(&self) -> Vec<String> {
+        self.lines.lock().unwrap().iter().cloned().collect()
+    }
+}
@@ -0,0 +1,15 @@
+//! Crash handling: a panic hook that writes what it can about a crash
+//! to `logs/crashes/`, a check the next launch runs to offer a recovery
+//! dialog if one is waiting, a bundle of that plus the app's own logs
+//! for attaching to a bug report, and pruning old log files per
+//! [`crate::settings::LogRetention`].
+
+mod bundle;
+mod crash;
+mod log_buffer;
+mod retention;
+
+pub use bundle::{write_diagnostics_bundle, DiagnosticsBundleError};
+pub use crash::{acknowledge_pending_crash_report, find_pending_crash_report, install_panic_hook, CrashReport, CrashReportError};
+pub use log_buffer::LogBuffer;
+pub use retention::{prune_log_files, PruneReport};
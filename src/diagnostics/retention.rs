@@ -0,0 +1,51 @@
+//! Applies [`crate::settings::LogRetention`] against whatever log files
+//! are sitting in the log directory — there's no log writer in this
+//! crate yet to rotate files as it goes (see [`super::LogBuffer`]'s own
+//! doc comment), but pruning what's already on disk doesn't need one.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::settings::LogRetention;
+
+/// How much a pruning pass removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub files_removed: u64,
+    pub bytes_freed: u64,
+}
+
+const SECONDS_PER_DAY: u64 = 24 * 3600;
+
+/// Deletes every file directly under `log_dir` whose last-modified time
+/// is older than `retention.max_age_days`. Doesn't look at
+/// `retention.max_file_size_bytes` — that governs when the (not yet
+/// written) log writer rotates to a new file, not what pruning does
+/// with files that already exist.
+pub fn prune_log_files(log_dir: &Path, retention: &LogRetention) -> std::io::Result<PruneReport> {
+    let max_age = Duration::from_secs(u64::from(retention.max_age_days) * SECONDS_PER_DAY);
+    let mut report = PruneReport::default();
+
+    let entries = match fs::read_dir(log_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+        Err(err) => return Err(err),
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else { continue };
+        let Ok(modified) = metadata.modified() else { continue };
+        let is_stale = modified.elapsed().unwrap_or(Duration::ZERO) > max_age;
+        if is_stale && fs::remove_file(&path).is_ok() {
+            report.files_removed += 1;
+            report.bytes_freed += metadata.len();
+        }
+    }
+
+    Ok(report)
+}
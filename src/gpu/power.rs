@@ -0,0 +1,172 @@
+//! GPU power-mode control: preferring maximum performance over
+//! power-saving, and toggling hardware-accelerated GPU scheduling
+//! (HAGS). Both live in the Windows registry; there's no non-Windows
+//! equivalent, so every entry point returns [`GpuError::Unsupported`]
+//! elsewhere.
+
+use std::fmt;
+
+/// Windows' per-app (or system-wide) graphics power preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpuPowerMode {
+    Optimal,
+    PreferMaximumPerformance,
+}
+
+#[derive(Debug)]
+pub enum GpuError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            GpuError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GpuError {}
+
+/// Reads and writes GPU power-mode and scheduling preferences.
+pub struct GpuController;
+
+impl GpuController {
+    pub fn new() -> Self {
+        GpuController
+    }
+
+    /// Sets Windows' graphics power preference (Settings > Display >
+    /// Graphics). `app_path` targets a single app; `None` sets the
+    /// system-wide default.
+    pub fn set_power_mode(&self, app_path: Option<&str>, mode: GpuPowerMode) -> Result<(), GpuError> {
+        imp::set_power_mode(app_path, mode)
+    }
+
+    pub fn power_mode(&self, app_path: Option<&str>) -> Result<GpuPowerMode, GpuError> {
+        imp::power_mode(app_path)
+    }
+
+    /// Toggles Hardware-accelerated GPU Scheduling. Real Windows
+    /// requires a reboot for this to take effect — callers should
+    /// surface that to the user rather than expecting an immediate
+    /// change.
+    pub fn set_hags_enabled(&self, enabled: bool) -> Result<(), GpuError> {
+        imp::set_hags_enabled(enabled)
+    }
+
+    pub fn hags_enabled(&self) -> Result<bool, GpuError> {
+        imp::hags_enabled()
+    }
+
+    /// Whether the installed GPU driver reports WDDM 2.7+ support, the
+    /// prerequisite for Hardware-accelerated GPU Scheduling. The toggle
+    /// above doesn't check this itself; this is for
+    /// the UI to grey the control out and explain why it won't do
+    /// anything on unsupported hardware.
+    pub fn hags_supported(&self) -> Result<bool, GpuError> {
+        imp::hags_supported()
+    }
+
+    /// Toggles variable refresh rate (Settings > Display > Graphics >
+    /// "Variable refresh rate"), letting the display sync to the game's
+    /// frame rate instead of a fixed one. Also reboot-flagged like HAGS.
+    pub fn set_vrr_enabled(&self, enabled: bool) -> Result<(), GpuError> {
+        imp::set_vrr_enabled(enabled)
+    }
+
+    pub fn vrr_enabled(&self) -> Result<bool, GpuError> {
+        imp::vrr_enabled()
+    }
+}
+
+impl Default for GpuController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{GpuError, GpuPowerMode};
+
+    // HKCU\Software\Microsoft\DirectX\UserGpuPreferences, value name
+    // is the app's exe path (or blank for system-wide), data is
+    // "GpuPreference=1;" (power saving) or "GpuPreference=2;" (max
+    // performance). Not implemented yet — report it as unsupported
+    // rather than panicking, so profile activation gets a clean error
+    // instead of a crash.
+    pub(super) fn set_power_mode(_app_path: Option<&str>, _mode: GpuPowerMode) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("writing UserGpuPreferences isn't implemented yet"))
+    }
+
+    pub(super) fn power_mode(_app_path: Option<&str>) -> Result<GpuPowerMode, GpuError> {
+        Err(GpuError::Unsupported("reading UserGpuPreferences isn't implemented yet"))
+    }
+
+    // HKLM\SYSTEM\CurrentControlSet\Control\GraphicsDrivers\HwSchMode,
+    // 2 = on, 1 = off. Requires administrator rights and a reboot. Not
+    // implemented yet — report it as unsupported rather than panicking.
+    pub(super) fn set_hags_enabled(_enabled: bool) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("writing HwSchMode isn't implemented yet"))
+    }
+
+    pub(super) fn hags_enabled() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("reading HwSchMode isn't implemented yet"))
+    }
+
+    // D3DKMTQueryAdapterInfo with KMTQAITYPE_WDDM_2_7_CAPS — the
+    // same capability check `dxdiag` reports as "Hardware-accelerated
+    // GPU Scheduling: supported/not supported" under System Information.
+    // Not implemented yet — report it as unsupported rather than panicking.
+    pub(super) fn hags_supported() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("querying WDDM 2.7 capability isn't implemented yet"))
+    }
+
+    // HKLM\SYSTEM\CurrentControlSet\Control\GraphicsDrivers, value
+    // "VRROptimizeEnable" (DWORD, 1 = on). Requires administrator
+    // rights and a reboot, same as HAGS. Not implemented yet — report
+    // it as unsupported rather than panicking.
+    pub(super) fn set_vrr_enabled(_enabled: bool) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("writing VRROptimizeEnable isn't implemented yet"))
+    }
+
+    pub(super) fn vrr_enabled() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("reading VRROptimizeEnable isn't implemented yet"))
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{GpuError, GpuPowerMode};
+
+    pub(super) fn set_power_mode(_app_path: Option<&str>, _mode: GpuPowerMode) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("GPU power-mode preferences are a Windows-only concept"))
+    }
+
+    pub(super) fn power_mode(_app_path: Option<&str>) -> Result<GpuPowerMode, GpuError> {
+        Err(GpuError::Unsupported("GPU power-mode preferences are a Windows-only concept"))
+    }
+
+    pub(super) fn set_hags_enabled(_enabled: bool) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("hardware-accelerated GPU scheduling is a Windows-only concept"))
+    }
+
+    pub(super) fn hags_enabled() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("hardware-accelerated GPU scheduling is a Windows-only concept"))
+    }
+
+    pub(super) fn hags_supported() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("hardware-accelerated GPU scheduling is a Windows-only concept"))
+    }
+
+    pub(super) fn set_vrr_enabled(_enabled: bool) -> Result<(), GpuError> {
+        Err(GpuError::Unsupported("variable refresh rate is a Windows-only concept"))
+    }
+
+    pub(super) fn vrr_enabled() -> Result<bool, GpuError> {
+        Err(GpuError::Unsupported("variable refresh rate is a Windows-only concept"))
+    }
+}
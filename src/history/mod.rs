@@ -0,0 +1,113 @@
+//! A durable, append-only log of every significant action this app has
+//! taken on its own: disk/RAM cleanups, network speed limit changes,
+//! service toggles, and profile activations. Backs the history panel's
+//! timeline, including its undo links where the action left something
+//! undoable — a service snapshot, a previous speed limit, an activated
+//! profile.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+/// What, if anything, can be undone from an [`ActionRecord`]. Each
+/// variant names the mechanism the history panel's "Undo" link should
+/// drive rather than embedding the undo logic itself — that already
+/// lives with the subsystem that owns it
+/// ([`crate::services::snapshot::SnapshotStore`] for service toggles,
+/// [`crate::profiles::ProfileManager`] for activations).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UndoAction {
+    /// Restore the named service/registry snapshot.
+    RestoreServiceSnapshot { snapshot_label: String },
+    /// Set the background network limit back to what it was before.
+    RevertSpeedLimit { previous_kbps: Option<u32> },
+    /// Deactivate the profile that was just activated.
+    DeactivateProfile { profile_name: String },
+}
+
+/// One significant, user-visible action, recorded the moment it
+/// happens.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRecord {
+    pub at: SystemTime,
+    /// A one-line, already-formatted description for the history
+    /// panel, e.g. "Freed 1.2 GB (disk cleanup)" or "Activated profile
+    /// \"Competitive\"" — built by the call site, which knows the
+    /// action's details, rather than reconstructed from a generic
+    /// enum here.
+    pub summary: String,
+    #[serde(default)]
+    pub undo: Option<UndoAction>,
+}
+
+#[derive(Debug)]
+pub enum ActionHistoryError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ActionHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionHistoryError::Io(err) => write!(f, "io error: {err}"),
+            ActionHistoryError::Parse(err) => write!(f, "invalid action history: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ActionHistoryError {}
+
+impl From<std::io::Error> for ActionHistoryError {
+    fn from(err: std::io::Error) -> Self {
+        ActionHistoryError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ActionHistoryError {
+    fn from(err: serde_json::Error) -> Self {
+        ActionHistoryError::Parse(err)
+    }
+}
+
+/// Persists [`ActionRecord`]s to a single JSON file, oldest first, the
+/// same shape [`crate::scheduler::history::TaskHistoryLog`] uses for
+/// per-task history.
+pub struct ActionHistoryLog {
+    path: PathBuf,
+}
+
+impl ActionHistoryLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Appends `record` and flushes the whole log to disk immediately.
+    pub fn append(&self, record: ActionRecord) -> Result<(), ActionHistoryError> {
+        let mut records = self.load_all()?;
+        records.push(record);
+        self.write_all(&records)
+    }
+
+    pub fn load_all(&self) -> Result<Vec<ActionRecord>, ActionHistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        if data.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    fn write_all(&self, records: &[ActionRecord]) -> Result<(), ActionHistoryError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(records)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+//! The extracted strings themselves. Adding a new piece of UI copy
+//! means adding a variant here and a line in [`tr`] for each locale —
+//! deliberately plain so a translator without Rust experience can
+//! still find and edit their language's lines.
+
+use super::Locale;
+
+/// One piece of translatable UI copy. Named after where it appears,
+/// not its English text, so renaming the English string doesn't mean
+/// renaming the key every other locale keys off of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranslationKey {
+    SettingsTabTitle,
+    LanguageLabel,
+    MuteAllNotifications,
+    RestorePointsLabel,
+    RestorePointsEmpty,
+    RestoreButton,
+    RemoveButton,
+    HagsLabel,
+    HagsUnsupported,
+    VrrLabel,
+    GpuRebootNotice,
+    UiScaleLabel,
+}
+
+/// Looks up `key` in `locale`, falling back to English if this key
+/// hasn't been translated into `locale` yet rather than showing a
+/// missing-string placeholder.
+pub fn tr(locale: Locale, key: TranslationKey) -> &'static str {
+    translate(locale, key).unwrap_or_else(|| translate(Locale::English, key).unwrap_or(""))
+}
+
+fn translate(locale: Locale, key: TranslationKey) -> Option<&'static str> {
+    use Locale::*;
+    use TranslationKey::*;
+
+    Some(match (locale, key) {
+        (English, SettingsTabTitle) => "Settings",
+        (French, SettingsTabTitle) => "Paramètres",
+        (German, SettingsTabTitle) => "Einstellungen",
+
+        (English, LanguageLabel) => "Language:",
+        (French, LanguageLabel) => "Langue :",
+        (German, LanguageLabel) => "Sprache:",
+
+        (English, MuteAllNotifications) => "Mute all notifications",
+        (French, MuteAllNotifications) => "Couper toutes les notifications",
+        (German, MuteAllNotifications) => "Alle Benachrichtigungen stummschalten",
+
+        (English, RestorePointsLabel) => "Restore points:",
+        (French, RestorePointsLabel) => "Points de restauration :",
+        (German, RestorePointsLabel) => "Wiederherstellungspunkte:",
+
+        (English, RestorePointsEmpty) => "(none yet — one is captured automatically before a batch service change)",
+        (French, RestorePointsEmpty) => "(aucun pour l'instant — un point est créé automatiquement avant une modification groupée des services)",
+        (German, RestorePointsEmpty) => "(noch keiner — vor einer Sammeländerung an Diensten wird automatisch einer erstellt)",
+
+        (English, RestoreButton) => "Restore",
+        (French, RestoreButton) => "Restaurer",
+        (German, RestoreButton) => "Wiederherstellen",
+
+        (English, RemoveButton) => "Remove",
+        (French, RemoveButton) => "Supprimer",
+        (German, RemoveButton) => "Entfernen",
+
+        (English, HagsLabel) => "Hardware-accelerated GPU Scheduling",
+        (French, HagsLabel) => "Planification GPU accélérée par le matériel",
+        (German, HagsLabel) => "Hardwarebeschleunigte GPU-Planung",
+
+        (English, HagsUnsupported) => "(not supported by this GPU's driver)",
+        (French, HagsUnsupported) => "(non pris en charge par ce pilote GPU)",
+        (German, HagsUnsupported) => "(vom GPU-Treiber nicht unterstützt)",
+
+        (English, VrrLabel) => "Variable refresh rate",
+        (French, VrrLabel) => "Taux de rafraîchissement variable",
+        (German, VrrLabel) => "Variable Bildwiederholrate",
+
+        (English, GpuRebootNotice) => "A reboot is required for this change to take effect.",
+        (French, GpuRebootNotice) => "Un redémarrage est nécessaire pour que ce changement prenne effet.",
+        (German, GpuRebootNotice) => "Ein Neustart ist erforderlich, damit diese Änderung wirksam wird.",
+
+        (English, UiScaleLabel) => "UI scale:",
+        (French, UiScaleLabel) => "Échelle de l'interface :",
+        (German, UiScaleLabel) => "UI-Skalierung:",
+    })
+}
@@ -0,0 +1,45 @@
+//! Translation layer: a small hand-rolled catalog instead of a
+//! `fluent`/`rust-i18n` dependency, since every string this app shows
+//! is plain UI copy with no plurals or date formatting to justify
+//! pulling one in. [`Locale`] is the persisted, user-switchable
+//! current language; [`tr`] looks up a [`TranslationKey`] in it,
+//! falling back to English for anything not yet translated in the
+//! requested locale.
+
+mod catalog;
+mod settings;
+
+pub use catalog::{tr, TranslationKey};
+pub use settings::{LocaleSettings, LocaleSettingsError};
+
+use serde::{Deserialize, Serialize};
+
+/// A language the UI can be switched to at runtime, with no restart
+/// needed: every tab re-reads [`LocaleSettings::current`] each frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Locale {
+    English,
+    French,
+    German,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::English
+    }
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::English, Locale::French, Locale::German];
+
+    /// The language's own name for itself, for the Settings tab's
+    /// picker — a French user shouldn't have to read "French" to find
+    /// their language.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::French => "Français",
+            Locale::German => "Deutsch",
+        }
+    }
+}
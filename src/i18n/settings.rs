@@ -0,0 +1,83 @@
+//! Persists which [`Locale`] the Settings tab's language picker last
+//! chose, so runtime switching survives a restart.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::Locale;
+
+#[derive(Debug)]
+pub enum LocaleSettingsError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for LocaleSettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocaleSettingsError::Io(err) => write!(f, "{err}"),
+            LocaleSettingsError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for LocaleSettingsError {}
+
+impl From<io::Error> for LocaleSettingsError {
+    fn from(err: io::Error) -> Self {
+        LocaleSettingsError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for LocaleSettingsError {
+    fn from(err: serde_json::Error) -> Self {
+        LocaleSettingsError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PersistedLocaleSettings {
+    locale: Locale,
+}
+
+/// The current UI language, switchable at runtime without a restart:
+/// every tab re-reads [`LocaleSettings::current`] each frame rather
+/// than caching translated strings.
+#[derive(Debug, Clone, Copy)]
+pub struct LocaleSettings {
+    locale: Locale,
+}
+
+impl LocaleSettings {
+    /// Loads `path`, or starts in [`Locale::English`] if it doesn't
+    /// exist yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, LocaleSettingsError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let persisted: PersistedLocaleSettings = serde_json::from_str(&contents)?;
+                Ok(Self { locale: persisted.locale })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self { locale: Locale::default() }),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn current(&self) -> Locale {
+        self.locale
+    }
+
+    /// Switches the active language and saves it to `path`
+    /// immediately.
+    pub fn set_current(&mut self, locale: Locale, path: impl AsRef<Path>) -> Result<(), LocaleSettingsError> {
+        self.locale = locale;
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&PersistedLocaleSettings { locale })?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+//! Core, platform-facing logic for the app: CPU tuning, process profiles,
+//! the background task scheduler, and Windows service management.
+//!
+//! Every module except [`ui`] is already free of any GUI framework
+//! dependency — [`cleaning`], [`network`], [`scheduler`], [`cpu`] and
+//! [`services`] are the public surface a future `gamebooster-core`
+//! library crate would re-export as-is, with [`ui`] and this crate's
+//! egui dependency moving into a separate GUI binary crate alongside
+//! it. [`cli`] and [`api`] already exercise that boundary today: both
+//! drive `cleaning`/`network`/`scheduler` directly and never touch
+//! `ui`, the same way a second frontend crate would. Splitting the actual
+//! `Cargo.toml` into a workspace is left for whenever this crate grows
+//! a second real consumer beyond the GUI and the CLI.
+//!
+//! The OS calls each of those modules makes are also behind their own
+//! small trait — [`cleaning::FileSystemApi`], [`services::ServiceControl`],
+//! [`network::QosBackend`], [`cpu::isolation::ProcessLister`] and
+//! [`network::process_limits::NetworkProcessLister`] — so the logic
+//! built on top of them can be exercised against an in-memory fake
+//! instead of the host OS.
+
+pub mod api;
+pub mod audio;
+pub mod cleaning;
+pub mod cli;
+pub mod confirm;
+pub mod cpu;
+pub mod diagnostics;
+pub mod gpu;
+pub mod history;
+pub mod i18n;
+pub mod network;
+pub mod notifications;
+pub mod plugins;
+pub mod profiles;
+pub mod reporting;
+pub mod scheduler;
+pub mod services;
+pub mod settings;
+pub mod shortcuts;
+pub mod startup;
+pub mod system;
+pub mod theme;
+pub mod ui;
+pub mod utils;
@@ -0,0 +1,162 @@
+//! Throttles Windows' own background downloads — BITS transfer rates
+//! and Delivery Optimization's download mode/bandwidth limits — so they
+//! respect the same bandwidth discipline as [`super::NetworkLimiter`]'s
+//! per-process limiting.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum BackgroundDownloadError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for BackgroundDownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackgroundDownloadError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            BackgroundDownloadError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BackgroundDownloadError {}
+
+/// How aggressively Delivery Optimization is allowed to fetch from
+/// peers rather than Microsoft's own servers (`DODownloadMode`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryOptimizationMode {
+    /// HTTP only, no peer-to-peer.
+    HttpOnly,
+    /// Peers on the local network only.
+    LanPeers,
+    /// Peers on the local network and over the internet.
+    Internet,
+    /// Delivery Optimization is bypassed entirely.
+    Bypass,
+}
+
+/// Throttles BITS (Background Intelligent Transfer Service) and
+/// Delivery Optimization, Windows Update's own download paths.
+pub struct BackgroundDownloadController;
+
+impl BackgroundDownloadController {
+    pub fn new() -> Self {
+        BackgroundDownloadController
+    }
+
+    /// Caps BITS' background transfer rate to `down_kbps`/`up_kbps`
+    /// kilobits per second.
+    pub fn set_bits_rate_limit(&self, down_kbps: u32, up_kbps: u32) -> Result<(), BackgroundDownloadError> {
+        imp::set_bits_rate_limit(down_kbps, up_kbps)
+    }
+
+    /// Removes any BITS rate limit previously applied.
+    pub fn clear_bits_rate_limit(&self) -> Result<(), BackgroundDownloadError> {
+        imp::clear_bits_rate_limit()
+    }
+
+    pub fn set_delivery_optimization_mode(&self, mode: DeliveryOptimizationMode) -> Result<(), BackgroundDownloadError> {
+        imp::set_delivery_optimization_mode(mode)
+    }
+
+    pub fn delivery_optimization_mode(&self) -> Result<DeliveryOptimizationMode, BackgroundDownloadError> {
+        imp::delivery_optimization_mode()
+    }
+
+    /// Caps Delivery Optimization's own download rate to
+    /// `down_kbps`/`up_kbps`, independent of BITS.
+    pub fn set_delivery_optimization_bandwidth_limit(
+        &self,
+        down_kbps: u32,
+        up_kbps: u32,
+    ) -> Result<(), BackgroundDownloadError> {
+        imp::set_delivery_optimization_bandwidth_limit(down_kbps, up_kbps)
+    }
+
+    /// Removes any Delivery Optimization bandwidth limit previously
+    /// applied.
+    pub fn clear_delivery_optimization_bandwidth_limit(&self) -> Result<(), BackgroundDownloadError> {
+        imp::clear_delivery_optimization_bandwidth_limit()
+    }
+}
+
+impl Default for BackgroundDownloadController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{BackgroundDownloadError, DeliveryOptimizationMode};
+
+    pub(super) fn set_bits_rate_limit(_down_kbps: u32, _up_kbps: u32) -> Result<(), BackgroundDownloadError> {
+        // IBackgroundCopyManager::SetBandwidthLimitation (or the
+        // registry-backed group policy equivalent under
+        // HKLM\SOFTWARE\Policies\Microsoft\Windows\BITS) sets a
+        // schedule-wide cap rather than per-job, matching this app's
+        // "one dial for all background downloads" scope.
+        todo!("set BITS' bandwidth limitation via IBackgroundCopyManager")
+    }
+
+    pub(super) fn clear_bits_rate_limit() -> Result<(), BackgroundDownloadError> {
+        todo!("clear BITS' bandwidth limitation via IBackgroundCopyManager")
+    }
+
+    pub(super) fn set_delivery_optimization_mode(_mode: DeliveryOptimizationMode) -> Result<(), BackgroundDownloadError> {
+        // HKLM\SOFTWARE\Microsoft\Windows\CurrentVersion\DeliveryOptimization\Config,
+        // value "DODownloadMode" (DWORD: 0 HTTP only, 1 LAN peers, 3
+        // internet peers, 99 bypass).
+        todo!("write DODownloadMode under the DeliveryOptimization config key")
+    }
+
+    pub(super) fn delivery_optimization_mode() -> Result<DeliveryOptimizationMode, BackgroundDownloadError> {
+        todo!("read DODownloadMode under the DeliveryOptimization config key")
+    }
+
+    pub(super) fn set_delivery_optimization_bandwidth_limit(
+        _down_kbps: u32,
+        _up_kbps: u32,
+    ) -> Result<(), BackgroundDownloadError> {
+        // Same key, "DOMaxDownloadBandwidth" and "DOMaxUploadBandwidth"
+        // (DWORD, kilobits per second).
+        todo!("write DOMaxDownloadBandwidth/DOMaxUploadBandwidth under the DeliveryOptimization config key")
+    }
+
+    pub(super) fn clear_delivery_optimization_bandwidth_limit() -> Result<(), BackgroundDownloadError> {
+        todo!("delete DOMaxDownloadBandwidth/DOMaxUploadBandwidth under the DeliveryOptimization config key")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{BackgroundDownloadError, DeliveryOptimizationMode};
+
+    pub(super) fn set_bits_rate_limit(_down_kbps: u32, _up_kbps: u32) -> Result<(), BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("BITS is a Windows-only concept"))
+    }
+
+    pub(super) fn clear_bits_rate_limit() -> Result<(), BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("BITS is a Windows-only concept"))
+    }
+
+    pub(super) fn set_delivery_optimization_mode(_mode: DeliveryOptimizationMode) -> Result<(), BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("Delivery Optimization is a Windows-only concept"))
+    }
+
+    pub(super) fn delivery_optimization_mode() -> Result<DeliveryOptimizationMode, BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("Delivery Optimization is a Windows-only concept"))
+    }
+
+    pub(super) fn set_delivery_optimization_bandwidth_limit(
+        _down_kbps: u32,
+        _up_kbps: u32,
+    ) -> Result<(), BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("Delivery Optimization is a Windows-only concept"))
+    }
+
+    pub(super) fn clear_delivery_optimization_bandwidth_limit() -> Result<(), BackgroundDownloadError> {
+        Err(BackgroundDownloadError::Unsupported("Delivery Optimization is a Windows-only concept"))
+    }
+}
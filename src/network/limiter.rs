@@ -0,0 +1,117 @@
+//! Caps a process's network throughput so background downloads don't eat
+//! bandwidth a game needs. Windows drives this through a WFP throttle
+//! filter; Linux would need a `tc` qdisc wired to a cgroup. Both are
+//! involved enough to land as their own follow-up; for now this gives
+//! the rest of the app a stable type to call into.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum NetworkLimiterError {
+    ProcessNotFound(u32),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for NetworkLimiterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkLimiterError::ProcessNotFound(pid) => write!(f, "process {pid} not found"),
+            NetworkLimiterError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkLimiterError {}
+
+/// Something that can install and remove a per-process quality-of-service
+/// (bandwidth) throttle. Implemented against the real WFP/tc backend in
+/// production; [`NetworkLimiter::with_backend`] accepts a fake, so the scheduler and
+/// profile-activation code that applies rate limits doesn't need the
+/// host OS's networking stack to verify it calls through correctly.
+pub trait QosBackend {
+    fn set_rate_limit(&self, pid: u32, down_kbps: u32, up_kbps: u32) -> Result<(), NetworkLimiterError>;
+    fn clear_rate_limit(&self, pid: u32) -> Result<(), NetworkLimiterError>;
+}
+
+/// Default [`QosBackend`] backed by the real platform throttle (WFP on
+/// Windows, unimplemented elsewhere — see `imp` below).
+pub struct OsQosBackend;
+
+impl QosBackend for OsQosBackend {
+    fn set_rate_limit(&self, pid: u32, down_kbps: u32, up_kbps: u32) -> Result<(), NetworkLimiterError> {
+        imp::set_rate_limit(pid, down_kbps, up_kbps)
+    }
+
+    fn clear_rate_limit(&self, pid: u32) -> Result<(), NetworkLimiterError> {
+        imp::clear_rate_limit(pid)
+    }
+}
+
+/// Applies and clears a per-process network rate limit.
+pub struct NetworkLimiter<B: QosBackend = OsQosBackend> {
+    backend: B,
+}
+
+impl NetworkLimiter<OsQosBackend> {
+    pub fn new() -> Self {
+        Self::with_backend(OsQosBackend)
+    }
+}
+
+impl<B: QosBackend> NetworkLimiter<B> {
+    pub fn with_backend(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Caps `pid`'s network throughput to `down_kbps` kilobits per second
+    /// downstream and `up_kbps` upstream.
+    pub fn set_rate_limit(&self, pid: u32, down_kbps: u32, up_kbps: u32) -> Result<(), NetworkLimiterError> {
+        self.backend.set_rate_limit(pid, down_kbps, up_kbps)
+    }
+
+    /// Removes any rate limit previously applied to `pid`.
+    pub fn clear_rate_limit(&self, pid: u32) -> Result<(), NetworkLimiterError> {
+        self.backend.clear_rate_limit(pid)
+    }
+}
+
+impl Default for NetworkLimiter<OsQosBackend> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::NetworkLimiterError;
+
+    // Installs a pair of per-direction WFP (Windows Filtering Platform)
+    // throttle filters keyed on the process's token. Not implemented yet —
+    // report it as unsupported rather than panicking, so callers (profile
+    // activation, the scheduler's `ApplyNetworkLimit` task) get a clean
+    // error instead of a crash.
+    pub(super) fn set_rate_limit(_pid: u32, _down_kbps: u32, _up_kbps: u32) -> Result<(), NetworkLimiterError> {
+        Err(NetworkLimiterError::Unsupported("WFP throttle filters aren't implemented yet"))
+    }
+
+    pub(super) fn clear_rate_limit(_pid: u32) -> Result<(), NetworkLimiterError> {
+        Err(NetworkLimiterError::Unsupported("WFP throttle filters aren't implemented yet"))
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::NetworkLimiterError;
+
+    pub(super) fn set_rate_limit(_pid: u32, _down_kbps: u32, _up_kbps: u32) -> Result<(), NetworkLimiterError> {
+        Err(NetworkLimiterError::Unsupported(
+            "per-process network limiting needs a tc qdisc wired to a cgroup, not implemented yet",
+        ))
+    }
+
+    pub(super) fn clear_rate_limit(_pid: u32) -> Result<(), NetworkLimiterError> {
+        Err(NetworkLimiterError::Unsupported(
+            "per-process network limiting needs a tc qdisc wired to a cgroup, not implemented yet",
+        ))
+    }
+}
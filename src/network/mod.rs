@@ -0,0 +1,15 @@
+//! Per-process network rate limiting, plus throttles for Windows' own
+//! background downloads (BITS, Delivery Optimization) so they respect
+//! the same bandwidth discipline.
+
+mod bits;
+mod limiter;
+pub mod ping;
+pub mod process_limits;
+pub mod throughput;
+
+pub use bits::{BackgroundDownloadController, BackgroundDownloadError, DeliveryOptimizationMode};
+pub use limiter::{NetworkLimiter, NetworkLimiterError, OsQosBackend, QosBackend};
+pub use ping::{PingError, PingMonitor, DEFAULT_PING_HOST};
+pub use process_limits::{build_rows, NetworkProcessLister, NetworkProcessRow, OsNetworkProcessLister, ProcessLimit};
+pub use throughput::{NetworkThroughputError, NetworkThroughputMonitor, NetworkThroughputSample};
@@ -0,0 +1,99 @@
+//! Round-trip latency to a fixed host, for the mini overlay's "ping"
+//! readout — not tied to any particular game server, just a cheap
+//! signal of whether the connection is currently healthy.
+
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum PingError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+    Timeout,
+}
+
+impl fmt::Display for PingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PingError::Os(err) => write!(f, "os error: {err}"),
+            PingError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            PingError::Timeout => write!(f, "ping timed out"),
+        }
+    }
+}
+
+impl std::error::Error for PingError {}
+
+/// Host pinged when the caller doesn't care which one, just whether
+/// the link is up and how slow it is.
+pub const DEFAULT_PING_HOST: &str = "1.1.1.1";
+
+/// How long to wait for a single echo reply before giving up.
+const PING_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Sends one ICMP echo to `host` and returns the round-trip time in
+/// milliseconds.
+pub struct PingMonitor;
+
+impl PingMonitor {
+    pub fn new() -> Self {
+        PingMonitor
+    }
+
+    pub fn ping_ms(&self, host: &str) -> Result<u32, PingError> {
+        imp::ping_ms(host, PING_TIMEOUT)
+    }
+}
+
+impl Default for PingMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::PingError;
+    use std::time::Duration;
+
+    pub(super) fn ping_ms(_host: &str, _timeout: Duration) -> Result<u32, PingError> {
+        // IcmpSendEcho (iphlpapi) against the resolved address, reading
+        // the round-trip time back out of the ICMP_ECHO_REPLY.
+        todo!("send one echo via Iphlpapi::IcmpSendEcho")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::PingError;
+    use std::process::Command;
+    use std::time::Duration;
+
+    /// Shells out to the system `ping` binary rather than opening a raw
+    /// ICMP socket, since that needs `CAP_NET_RAW` this app shouldn't
+    /// require just to show a latency number.
+    pub(super) fn ping_ms(host: &str, timeout: Duration) -> Result<u32, PingError> {
+        let output = Command::new("ping")
+            .arg("-c")
+            .arg("1")
+            .arg("-W")
+            .arg(timeout.as_secs().max(1).to_string())
+            .arg(host)
+            .output()
+            .map_err(PingError::Os)?;
+
+        if !output.status.success() {
+            return Err(PingError::Timeout);
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        parse_round_trip_ms(&stdout).ok_or(PingError::Unsupported("couldn't parse ping output"))
+    }
+
+    fn parse_round_trip_ms(output: &str) -> Option<u32> {
+        // "... time=12.3 ms"
+        let (_, after) = output.split_once("time=")?;
+        let digits: String = after.chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+        digits.parse::<f64>().ok().map(|ms| ms.round() as u32)
+    }
+}
@@ -0,0 +1,49 @@
+//! Per-process network rate limits shown as inline row controls in the
+//! Network tab, replacing a single shared speed-limit field that used
+//! to apply to whatever process happened to be selected.
+
+use std::collections::HashMap;
+
+/// A rate limit currently applied to one process via
+/// [`crate::network::NetworkLimiter::set_rate_limit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessLimit {
+    pub down_kbps: u32,
+    pub up_kbps: u32,
+}
+
+/// One row of the Network tab's process table: who it is, and whatever
+/// limit is currently active on it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkProcessRow {
+    pub pid: u32,
+    pub name: String,
+    pub active_limit: Option<ProcessLimit>,
+}
+
+/// Something that can list running processes by pid and name.
+/// Implemented against the real OS process list in production; a fake
+/// can be passed to [`build_rows`] instead.
+pub trait NetworkProcessLister {
+    fn list_processes(&self) -> Vec<(u32, String)>;
+}
+
+/// Default [`NetworkProcessLister`] backed by the real OS process list.
+pub struct OsNetworkProcessLister;
+
+impl NetworkProcessLister for OsNetworkProcessLister {
+    fn list_processes(&self) -> Vec<(u32, String)> {
+        todo!("enumerate (pid, name) pairs via the platform process list (EnumProcesses / sysinfo)")
+    }
+}
+
+/// Builds the Network tab's row list from every running process,
+/// annotated with whichever per-pid limits `active_limits` is
+/// currently tracking.
+pub fn build_rows(lister: &impl NetworkProcessLister, active_limits: &HashMap<u32, ProcessLimit>) -> Vec<NetworkProcessRow> {
+    lister
+        .list_processes()
+        .into_iter()
+        .map(|(pid, name)| NetworkProcessRow { pid, name, active_limit: active_limits.get(&pid).copied() })
+        .collect()
+}
@@ -0,0 +1,113 @@
+//! Reads system-wide network throughput, computed from the delta
+//! between successive cumulative-byte-counter reads, for the Network
+//! tab's rolling charts and the Dashboard's network card.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Instant;
+
+#[derive(Debug)]
+pub enum NetworkThroughputError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for NetworkThroughputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkThroughputError::Os(err) => write!(f, "os error: {err}"),
+            NetworkThroughputError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkThroughputError {}
+
+/// One throughput reading, in kilobits per second.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NetworkThroughputSample {
+    pub down_kbps: u32,
+    pub up_kbps: u32,
+}
+
+struct Cumulative {
+    at: Instant,
+    down_bytes: u64,
+    up_bytes: u64,
+}
+
+/// Samples system-wide network throughput across every non-loopback
+/// adapter. The first call after construction always returns zero,
+/// since throughput needs two readings to compute a rate from.
+pub struct NetworkThroughputMonitor {
+    last: Mutex<Option<Cumulative>>,
+}
+
+impl NetworkThroughputMonitor {
+    pub fn new() -> Self {
+        Self { last: Mutex::new(None) }
+    }
+
+    pub fn sample_kbps(&self) -> Result<NetworkThroughputSample, NetworkThroughputError> {
+        let (down_bytes, up_bytes) = imp::read_cumulative_bytes()?;
+        let now = Instant::now();
+        let mut last = self.last.lock().unwrap();
+
+        let sample = match &*last {
+            Some(prev) => {
+                let elapsed = now.duration_since(prev.at).as_secs_f64().max(0.001);
+                let down_kbps = (down_bytes.saturating_sub(prev.down_bytes) as f64 * 8.0 / 1000.0 / elapsed) as u32;
+                let up_kbps = (up_bytes.saturating_sub(prev.up_bytes) as f64 * 8.0 / 1000.0 / elapsed) as u32;
+                NetworkThroughputSample { down_kbps, up_kbps }
+            }
+            None => NetworkThroughputSample::default(),
+        };
+
+        *last = Some(Cumulative { at: now, down_bytes, up_bytes });
+        Ok(sample)
+    }
+}
+
+impl Default for NetworkThroughputMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::NetworkThroughputError;
+
+    pub(super) fn read_cumulative_bytes() -> Result<(u64, u64), NetworkThroughputError> {
+        // GetIfTable2 (iphlpapi), summing InOctets/OutOctets across
+        // every adapter whose InterfaceAndOperStatusFlags doesn't mark
+        // it as a loopback or tunnel interface.
+        todo!("sum InOctets/OutOctets across adapters via GetIfTable2")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::NetworkThroughputError;
+    use std::fs;
+
+    pub(super) fn read_cumulative_bytes() -> Result<(u64, u64), NetworkThroughputError> {
+        let contents = fs::read_to_string("/proc/net/dev").map_err(NetworkThroughputError::Os)?;
+        let mut down_bytes = 0u64;
+        let mut up_bytes = 0u64;
+
+        for line in contents.lines().skip(2) {
+            let Some((iface, rest)) = line.split_once(':') else { continue };
+            if iface.trim() == "lo" {
+                continue;
+            }
+            let mut fields = rest.split_whitespace();
+            let Some(received) = fields.next().and_then(|field| field.parse::<u64>().ok()) else { continue };
+            let transmitted = fields.nth(7).and_then(|field| field.parse::<u64>().ok()).unwrap_or(0);
+            down_bytes += received;
+            up_bytes += transmitted;
+        }
+
+        Ok((down_bytes, up_bytes))
+    }
+}
@@ -0,0 +1,94 @@
+//! Notifies the user once a scheduled task finishes, so an unattended
+//! cleanup running overnight isn't silent: a tray toast, a webhook
+//! call, or both, per task.
+
+mod settings;
+mod toast;
+mod webhook;
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+pub use settings::{NotificationSettings, NotificationSettingsError};
+pub use toast::{show_toast, ToastError};
+pub use webhook::{post_webhook, WebhookError};
+
+/// Per-task notification settings: what to show (and optionally send)
+/// once a run finishes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationOptions {
+    #[serde(default)]
+    pub toast: bool,
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// Fire on a successful run too, not just a failure.
+    #[serde(default)]
+    pub notify_on_success: bool,
+}
+
+/// A short, human-readable summary of one task execution, built by the
+/// scheduler's background thread once a run finishes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunSummary {
+    pub task_name: String,
+    pub success: bool,
+    pub duration: Duration,
+    pub bytes_freed: u64,
+    pub error: Option<String>,
+}
+
+impl RunSummary {
+    pub fn message(&self) -> String {
+        if self.success {
+            if self.bytes_freed > 0 {
+                format!(
+                    "{} finished in {:.1}s, freed {} bytes",
+                    self.task_name,
+                    self.duration.as_secs_f64(),
+                    self.bytes_freed
+                )
+            } else {
+                format!("{} finished in {:.1}s", self.task_name, self.duration.as_secs_f64())
+            }
+        } else {
+            format!(
+                "{} failed after {:.1}s: {}",
+                self.task_name,
+                self.duration.as_secs_f64(),
+                self.error.as_deref().unwrap_or("unknown error")
+            )
+        }
+    }
+}
+
+/// Notifies the user about `summary` per `options`, skipping both
+/// channels if the run succeeded and `notify_on_success` is false, or
+/// if `settings` has the global mute switch on. Returns every failure
+/// encountered rather than stopping at the first, since a task can
+/// have both a toast and a webhook configured and one failing
+/// shouldn't suppress the other — the caller folds these into the
+/// run's history like any other error.
+pub fn notify(settings: &NotificationSettings, options: &NotificationOptions, summary: &RunSummary) -> Vec<String> {
+    if settings.muted() {
+        return Vec::new();
+    }
+    if summary.success && !options.notify_on_success {
+        return Vec::new();
+    }
+
+    let message = summary.message();
+    let mut errors = Vec::new();
+
+    if options.toast {
+        if let Err(err) = show_toast(&summary.task_name, &message) {
+            errors.push(err.to_string());
+        }
+    }
+    if let Some(url) = &options.webhook_url {
+        if let Err(err) = post_webhook(url, &message) {
+            errors.push(err.to_string());
+        }
+    }
+    errors
+}
@@ -0,0 +1,87 @@
+//! The global mute switch in Settings: when it's on, [`crate::notifications::notify`]
+//! is a no-op for every caller — the scheduler, the profile applier, and
+//! the cleaners alike — without each of them needing to check a flag
+//! themselves.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub enum NotificationSettingsError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for NotificationSettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationSettingsError::Io(err) => write!(f, "{err}"),
+            NotificationSettingsError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for NotificationSettingsError {}
+
+impl From<io::Error> for NotificationSettingsError {
+    fn from(err: io::Error) -> Self {
+        NotificationSettingsError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for NotificationSettingsError {
+    fn from(err: serde_json::Error) -> Self {
+        NotificationSettingsError::Parse(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PersistedNotificationSettings {
+    #[serde(default)]
+    muted: bool,
+}
+
+/// Whether toasts and webhooks should fire at all, independent of any
+/// single task's [`crate::notifications::NotificationOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NotificationSettings {
+    muted: bool,
+}
+
+impl NotificationSettings {
+    /// Loads `path`, or starts unmuted if it doesn't exist yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, NotificationSettingsError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let persisted: PersistedNotificationSettings = serde_json::from_str(&contents)?;
+                Ok(Self { muted: persisted.muted })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Flips the mute switch and saves it to `path` immediately, so the
+    /// Settings tab's toggle survives a restart.
+    pub fn set_muted(
+        &mut self,
+        muted: bool,
+        path: impl AsRef<Path>,
+    ) -> Result<(), NotificationSettingsError> {
+        self.muted = muted;
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let persisted = PersistedNotificationSettings { muted: self.muted };
+        fs::write(path, serde_json::to_string_pretty(&persisted)?)?;
+        Ok(())
+    }
+}
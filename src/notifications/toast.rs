@@ -0,0 +1,47 @@
+//! Shows a tray balloon/toast notification. Outside Windows it returns
+//! [`ToastError::Unavailable`], since there's no equivalent desktop
+//! notification concept this app integrates with yet.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ToastError {
+    Unavailable,
+}
+
+impl fmt::Display for ToastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToastError::Unavailable => write!(f, "toast notifications aren't available on this platform"),
+        }
+    }
+}
+
+impl std::error::Error for ToastError {}
+
+pub fn show_toast(title: &str, body: &str) -> Result<(), ToastError> {
+    imp::show_toast(title, body)
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::ToastError;
+
+    /// Shows a balloon toast via `Shell_NotifyIconW` with `NIF_INFO` set
+    /// on this app's existing tray icon — simpler and more broadly
+    /// compatible across Windows versions than the full
+    /// `ToastNotificationManager` WinRT API, at the cost of a plainer
+    /// look.
+    pub(super) fn show_toast(_title: &str, _body: &str) -> Result<(), ToastError> {
+        todo!("call Shell_NotifyIconW(NIM_MODIFY, ...) with NIF_INFO, szInfoTitle, and szInfo set on this app's tray icon")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::ToastError;
+
+    pub(super) fn show_toast(_title: &str, _body: &str) -> Result<(), ToastError> {
+        Err(ToastError::Unavailable)
+    }
+}
@@ -0,0 +1,110 @@
+//! Posts a task's run summary to a user-configured webhook URL — e.g.
+//! a Slack or Discord incoming webhook — as a minimal `{"text": "..."}`
+//! JSON payload over HTTP/1.1, using only `std` sockets since this
+//! crate has no HTTP client dependency.
+
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use serde::Serialize;
+
+#[derive(Debug)]
+pub enum WebhookError {
+    InvalidUrl(String),
+    /// Anything other than `http://`: reaching `https://` endpoints
+    /// (what most real webhook providers require) needs a TLS
+    /// implementation this crate doesn't depend on.
+    UnsupportedScheme(String),
+    Io(std::io::Error),
+    Serialize(serde_json::Error),
+    HttpStatus(u16),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::InvalidUrl(url) => write!(f, "invalid webhook URL: {url}"),
+            WebhookError::UnsupportedScheme(scheme) => {
+                write!(f, "'{scheme}://' webhook URLs aren't supported; only 'http://' is reachable")
+            }
+            WebhookError::Io(err) => write!(f, "webhook request failed: {err}"),
+            WebhookError::Serialize(err) => write!(f, "failed to build webhook payload: {err}"),
+            WebhookError::HttpStatus(status) => write!(f, "webhook returned HTTP {status}"),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+impl From<std::io::Error> for WebhookError {
+    fn from(err: std::io::Error) -> Self {
+        WebhookError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for WebhookError {
+    fn from(err: serde_json::Error) -> Self {
+        WebhookError::Serialize(err)
+    }
+}
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    text: &'a str,
+}
+
+/// Posts `message` to `url` as `{"text": "<message>"}`, the payload
+/// shape both Slack's and Discord's incoming webhooks accept.
+pub fn post_webhook(url: &str, message: &str) -> Result<(), WebhookError> {
+    let (host, port, path) = parse_http_url(url)?;
+    let body = serde_json::to_string(&WebhookPayload { text: message })?;
+
+    let mut stream = TcpStream::connect((host.as_str(), port))?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+
+    let request = format!(
+        "POST {path} HTTP/1.1\r\nHost: {host}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut status_line = String::new();
+    BufReader::new(stream).read_line(&mut status_line)?;
+    let status = status_line.split_whitespace().nth(1).and_then(|code| code.parse::<u16>().ok()).unwrap_or(0);
+    if (200..300).contains(&status) {
+        Ok(())
+    } else {
+        Err(WebhookError::HttpStatus(status))
+    }
+}
+
+/// Splits an `http://host[:port][/path]` URL into its parts, defaulting
+/// to port 80 and path `/`.
+fn parse_http_url(url: &str) -> Result<(String, u16, String), WebhookError> {
+    let rest = match url.split_once("://") {
+        Some(("http", rest)) => rest,
+        Some((scheme, _)) => return Err(WebhookError::UnsupportedScheme(scheme.to_string())),
+        None => return Err(WebhookError::InvalidUrl(url.to_string())),
+    };
+
+    let (authority, path) = match rest.split_once('/') {
+        Some((authority, path)) => (authority, format!("/{path}")),
+        None => (rest, "/".to_string()),
+    };
+    if authority.is_empty() {
+        return Err(WebhookError::InvalidUrl(url.to_string()));
+    }
+
+    let (host, port) = match authority.split_once(':') {
+        Some((host, port)) => {
+            (host.to_string(), port.parse::<u16>().map_err(|_| WebhookError::InvalidUrl(url.to_string()))?)
+        }
+        None => (authority.to_string(), 80),
+    };
+    Ok((host, port, path))
+}
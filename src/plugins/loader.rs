@@ -0,0 +1,70 @@
+//! Where [`super::Optimizer`] plugins would actually come from at
+//! runtime: a dynamic library (`.dll`/`.so`) exporting a known entry
+//! point, or a WASM module running under a sandboxed runtime. Neither
+//! `libloading` nor a WASM runtime is in this crate's dependencies yet,
+//! so [`PluginLoader::load_all`] is a stub — see
+//! [`PluginLoadError::Unsupported`].
+
+use std::fmt;
+use std::path::PathBuf;
+
+use super::Optimizer;
+
+#[derive(Debug)]
+pub enum PluginLoadError {
+    Io(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for PluginLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginLoadError::Io(err) => write!(f, "I/O error: {err}"),
+            PluginLoadError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for PluginLoadError {}
+
+impl From<std::io::Error> for PluginLoadError {
+    fn from(err: std::io::Error) -> Self {
+        PluginLoadError::Io(err)
+    }
+}
+
+/// Discovers and loads every [`Optimizer`] plugin found directly under
+/// a directory, the same directory-of-files approach
+/// [`crate::profiles::discovery`] uses for discovering installed games.
+pub struct PluginLoader {
+    dir: PathBuf,
+}
+
+impl PluginLoader {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Loads every plugin found directly under this loader's directory.
+    pub fn load_all(&self) -> Result<Vec<Box<dyn Optimizer>>, PluginLoadError> {
+        imp::load_all(&self.dir)
+    }
+}
+
+mod imp {
+    use std::path::Path;
+
+    use super::{Optimizer, PluginLoadError};
+
+    pub(super) fn load_all(_dir: &Path) -> Result<Vec<Box<dyn Optimizer>>, PluginLoadError> {
+        // Would enumerate `*.dll`/`*.so`/`*.wasm` files directly under
+        // `dir`, load each native one via `libloading::Library::new`
+        // and look up a fixed entry point (e.g.
+        // `extern "C" fn gamebooster_optimizer_new() -> *mut dyn Optimizer`),
+        // or load each `.wasm` one under a sandboxed runtime like
+        // `wasmtime` and wrap its exports behind an `Optimizer` adapter.
+        // Neither `libloading` nor a WASM runtime is a dependency of
+        // this crate yet.
+        Err(PluginLoadError::Unsupported("loading native or WASM Optimizer plugins isn't implemented"))
+    }
+}
@@ -0,0 +1,12 @@
+//! Lets third parties add cleaners and tweaks without forking the app:
+//! [`Optimizer`] is the trait a plugin implements, and [`PluginLoader`]
+//! is where those plugins would come from at runtime so they show up
+//! as extra categories in the Disk and Services tabs alongside
+//! [`crate::cleaning::DiskCleaner`]'s and [`crate::services::manager`]'s
+//! own built-in ones.
+
+mod loader;
+mod optimizer;
+
+pub use loader::{PluginLoadError, PluginLoader};
+pub use optimizer::{Optimizer, OptimizerError, OptimizerMetadata, OptimizerScanResult};
@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// What a plugin calls itself, shown next to the built-in categories in
+/// the Disk and Services tabs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizerMetadata {
+    pub name: String,
+    pub description: String,
+    pub version: String,
+}
+
+/// What an [`Optimizer::scan`] found, in the same shape
+/// [`crate::cleaning::CleanupReport`] reports a built-in cleanup pass's
+/// results in, so the Disk tab can list a plugin's category next to its
+/// own without a special case for "plugin" rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OptimizerScanResult {
+    pub freeable_bytes: u64,
+    pub item_count: u64,
+}
+
+#[derive(Debug)]
+pub enum OptimizerError {
+    /// The plugin reported a failure of its own, with whatever message
+    /// it chose to surface.
+    Plugin(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for OptimizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptimizerError::Plugin(msg) => write!(f, "plugin error: {msg}"),
+            OptimizerError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for OptimizerError {}
+
+/// A third-party cleaner or tweak, loaded by [`super::PluginLoader`]
+/// and driven the same way [`crate::cleaning::DiskCleaner`] and
+/// [`crate::services::manager::ServiceManager`] drive their own
+/// built-in categories: scan for what's there, apply to change it,
+/// revert to undo that change.
+pub trait Optimizer: Send + Sync {
+    /// The plugin's own name/description/version, for the category
+    /// label and an "About this plugin" panel.
+    fn metadata(&self) -> OptimizerMetadata;
+
+    /// Looks at the current system state without changing anything,
+    /// reporting what [`Optimizer::apply`] would do.
+    fn scan(&self) -> Result<OptimizerScanResult, OptimizerError>;
+
+    /// Actually makes the change `scan` described.
+    fn apply(&self) -> Result<(), OptimizerError>;
+
+    /// Undoes the most recent [`Optimizer::apply`]. Plugins that can't
+    /// undo their own change (e.g. a one-shot cleanup with nothing to
+    /// restore) return `Ok(())` without doing anything, the same way a
+    /// no-op revert is valid for a built-in cleanup category.
+    fn revert(&self) -> Result<(), OptimizerError>;
+}
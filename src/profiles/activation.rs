@@ -0,0 +1,63 @@
+//! Ways to activate a gaming profile besides the process watcher
+//! noticing its target running: a recurring schedule, or a global
+//! hotkey that toggles it without the user opening the main window.
+
+use serde::{Deserialize, Serialize};
+
+use crate::scheduler::ScheduleRule;
+
+/// A key combination recognized system-wide, independent of which
+/// window (if any) has focus.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hotkey {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    /// Key name as used by the platform's virtual-key lookup, e.g. `"G"`.
+    pub key: String,
+}
+
+impl Hotkey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { ctrl: false, alt: false, shift: false, key: key.into() }
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Human-readable form, e.g. `"Ctrl+Alt+G"`.
+    pub fn display(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ctrl {
+            parts.push("Ctrl");
+        }
+        if self.alt {
+            parts.push("Alt");
+        }
+        if self.shift {
+            parts.push("Shift");
+        }
+        parts.push(&self.key);
+        parts.join("+")
+    }
+}
+
+/// How a profile can be triggered without the user opening the main
+/// window: on a schedule, via a global hotkey that toggles it, or both.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ProfileActivation {
+    pub schedule: Option<ScheduleRule>,
+    pub hotkey: Option<Hotkey>,
+}
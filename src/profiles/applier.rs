@@ -0,0 +1,520 @@
+//! Applies a [`GamingProfile`]'s settings to the live system on
+//! activation, and records exactly what it changed so deactivation can
+//! put everything back.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::audio::AudioDeviceController;
+use crate::cleaning::{CleanupCategory, CleanupOptions, DiskCleaner, LastRunTracker};
+use crate::cpu::{CpuLimiter, CpuLimiterError, CpuPriority};
+use crate::gpu::{GpuController, GpuPowerMode};
+use crate::network::NetworkLimiter;
+use crate::notifications::{notify, NotificationOptions, NotificationSettings, RunSummary};
+use crate::services::manager::ServiceManager;
+use crate::services::{ServiceController, ServiceError};
+use crate::system::defender::{self, ScanSchedule};
+use crate::system::focus_assist::{FocusAssistController, FocusAssistLevel};
+use crate::system::game_mode::GameModeController;
+use crate::system::ram::RamCleaner;
+use crate::system::superfetch::SuperfetchTuner;
+
+use super::journal::{JournalEntry, JournalError, PriorState, RollbackJournal};
+use super::profile::{DefenderScanReschedule, GamingProfile};
+use super::watcher::ProcessSnapshot;
+
+#[derive(Debug)]
+pub enum ApplyError {
+    Cpu(CpuLimiterError),
+    Service(ServiceError),
+    Journal(JournalError),
+}
+
+impl fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApplyError::Cpu(err) => write!(f, "{err}"),
+            ApplyError::Service(err) => write!(f, "{err}"),
+            ApplyError::Journal(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+impl From<CpuLimiterError> for ApplyError {
+    fn from(err: CpuLimiterError) -> Self {
+        ApplyError::Cpu(err)
+    }
+}
+
+impl From<ServiceError> for ApplyError {
+    fn from(err: ServiceError) -> Self {
+        ApplyError::Service(err)
+    }
+}
+
+impl From<JournalError> for ApplyError {
+    fn from(err: JournalError) -> Self {
+        ApplyError::Journal(err)
+    }
+}
+
+/// Everything [`ProfileApplier::activate`] changed, kept around so
+/// [`ProfileApplier::deactivate`] can undo it.
+#[derive(Debug, Default)]
+pub struct AppliedProfileState {
+    target_pid: Option<u32>,
+    disabled_services: Vec<String>,
+    prior_gpu_power_mode: Option<GpuPowerMode>,
+    prior_hags_enabled: Option<bool>,
+    prior_game_mode_enabled: Option<bool>,
+    prior_game_dvr_enabled: Option<bool>,
+    prior_fullscreen_optimizations_disabled: Option<bool>,
+    prior_scan_schedule: Option<ScanSchedule>,
+    prior_game_bar_enabled: Option<bool>,
+    prior_game_bar_background_tasks_enabled: Option<bool>,
+    prior_focus_assist_level: Option<FocusAssistLevel>,
+    superfetch_disabled: bool,
+    prior_audio_enhancements_enabled: Option<bool>,
+    prior_audio_exclusive_mode_allowed: Option<bool>,
+}
+
+/// Enforces a [`GamingProfile`] against a live target process.
+pub struct ProfileApplier {
+    cpu: CpuLimiter,
+    network: NetworkLimiter,
+    services: ServiceController,
+    ram: RamCleaner,
+    disk_clean_tracker: LastRunTracker,
+    gpu: GpuController,
+    game_mode: GameModeController,
+    focus_assist: FocusAssistController,
+    audio: AudioDeviceController,
+    // Mutex rather than a plain field: both types persist to disk on
+    // every mutation, which needs `&mut self`, but `activate`/
+    // `deactivate` only take `&self` (the process watcher calls them
+    // from a shared reference across poll cycles).
+    service_manager: Mutex<ServiceManager>,
+    superfetch: Mutex<SuperfetchTuner>,
+    notification_settings: NotificationSettings,
+}
+
+/// How recently a profile's disk clean must have run for a later
+/// activation to skip it, when `disk_options.skip_if_recent` is set.
+const SKIP_DISK_CLEAN_WINDOW: Duration = Duration::from_secs(24 * 3600);
+
+impl ProfileApplier {
+    pub fn new() -> Self {
+        Self {
+            cpu: CpuLimiter::new(),
+            network: NetworkLimiter::new(),
+            services: ServiceController::new(),
+            ram: RamCleaner::new(),
+            disk_clean_tracker: LastRunTracker::new(std::env::temp_dir().join("gamebooster_state")),
+            gpu: GpuController::new(),
+            game_mode: GameModeController::new(),
+            focus_assist: FocusAssistController::new(),
+            audio: AudioDeviceController::new(),
+            service_manager: Mutex::new(
+                ServiceManager::load_from_file(std::env::temp_dir().join("gamebooster_state").join("service_manager_state.json"))
+                    .expect("service manager state file is corrupt"),
+            ),
+            superfetch: Mutex::new(
+                SuperfetchTuner::load_from_file(std::env::temp_dir().join("gamebooster_state").join("superfetch_snapshot.json"))
+                    .expect("superfetch snapshot file is corrupt"),
+            ),
+            notification_settings: NotificationSettings::load_from_file(
+                std::env::temp_dir().join("gamebooster_state").join("notification_settings.json"),
+            )
+            .expect("notification settings file is corrupt"),
+        }
+    }
+
+    /// Reports `task_name`'s outcome via the app's shared toast/webhook
+    /// channels, respecting the global mute switch in Settings. Used
+    /// for everything this applier does that isn't already surfaced
+    /// through [`AppliedProfileState`] or a propagated [`ApplyError`] —
+    /// a background disk clean finishing, or a best-effort tweak
+    /// failing silently otherwise.
+    fn notify_user(&self, task_name: &str, bytes_freed: u64, error: Option<String>, notify_on_success: bool) {
+        let options = NotificationOptions { toast: true, webhook_url: None, notify_on_success };
+        let _ = notify(
+            &self.notification_settings,
+            &options,
+            &RunSummary {
+                task_name: task_name.to_string(),
+                success: error.is_none(),
+                duration: Duration::ZERO,
+                bytes_freed,
+                error,
+            },
+        );
+    }
+
+    /// Activates `profile` against the already-running process
+    /// `target_pid`: kicks off the pre-launch disk clean (if requested)
+    /// asynchronously, cleans RAM if requested, applies CPU priority and
+    /// affinity, applies the background network limit, stops the
+    /// profile's disabled services, and applies its GPU power-mode,
+    /// HAGS, Game Mode, GameDVR, and fullscreen optimization
+    /// preferences.
+    pub fn activate(&self, profile: &GamingProfile, target_pid: u32) -> Result<AppliedProfileState, ApplyError> {
+        if profile.clean_disk_before_launch {
+            self.spawn_disk_clean(profile);
+        }
+
+        if profile.clean_ram_on_activate {
+            // Best-effort: a failed RAM clean shouldn't block the rest of
+            // the profile from applying.
+            if let Err(err) = self.ram.clean() {
+                self.notify_user("Clean RAM", 0, Some(err.to_string()), false);
+            }
+        }
+
+        if let Some(priority) = profile.cpu_priority {
+            self.cpu.set_priority(target_pid, priority)?;
+        }
+        if let Some(mask) = profile.affinity_mask {
+            self.cpu.set_affinity(target_pid, mask)?;
+        }
+        if let Some(limit_kbps) = profile.background_network_limit_kbps {
+            // Best-effort: network limiting returns Unsupported on every
+            // platform (see `network::limiter`).
+            if let Err(err) = self.network.set_rate_limit(target_pid, limit_kbps, limit_kbps) {
+                self.notify_user("Apply QoS limit", 0, Some(format!("{err} — try running as administrator")), false);
+            }
+        }
+
+        let mut disabled_services = Vec::new();
+        for service in &profile.disabled_services {
+            self.services.set_service_enabled(service, false)?;
+            disabled_services.push(service.clone());
+        }
+
+        // GPU preferences are keyed by the app's own executable path
+        // rather than its pid; until that path is threaded through here
+        // these apply system-wide, matching Windows' own default scope.
+        let prior_gpu_power_mode = if profile.gpu_options.prefer_max_performance {
+            let prior = self.gpu.power_mode(None).ok();
+            let _ = self.gpu.set_power_mode(None, GpuPowerMode::PreferMaximumPerformance);
+            prior
+        } else {
+            None
+        };
+        let prior_hags_enabled = if let Some(enabled) = profile.gpu_options.hags_enabled {
+            let prior = self.gpu.hags_enabled().ok();
+            let _ = self.gpu.set_hags_enabled(enabled);
+            prior
+        } else {
+            None
+        };
+
+        let prior_game_mode_enabled = if let Some(enabled) = profile.game_mode_options.game_mode_enabled {
+            let prior = self.game_mode.game_mode_enabled().ok();
+            let _ = self.game_mode.set_game_mode_enabled(enabled);
+            prior
+        } else {
+            None
+        };
+        let prior_game_dvr_enabled = if let Some(enabled) = profile.game_mode_options.game_dvr_enabled {
+            let prior = self.game_mode.game_dvr_enabled().ok();
+            let _ = self.game_mode.set_game_dvr_enabled(enabled);
+            prior
+        } else {
+            None
+        };
+        let prior_fullscreen_optimizations_disabled = if profile.game_mode_options.disable_fullscreen_optimizations {
+            let prior = self.game_mode.fullscreen_optimizations_disabled(&profile.target_process_name).ok();
+            let _ = self
+                .game_mode
+                .set_fullscreen_optimizations_disabled(&profile.target_process_name, true);
+            prior
+        } else {
+            None
+        };
+
+        let prior_game_bar_enabled = if let Some(enabled) = profile.game_mode_options.game_bar_enabled {
+            let prior = self.game_mode.game_bar_enabled().ok();
+            let _ = self.game_mode.set_game_bar_enabled(enabled);
+            prior
+        } else {
+            None
+        };
+        let prior_game_bar_background_tasks_enabled =
+            if let Some(enabled) = profile.game_mode_options.game_bar_background_tasks_enabled {
+                let prior = self.game_mode.game_bar_background_tasks_enabled().ok();
+                let _ = self.game_mode.set_game_bar_background_tasks_enabled(enabled);
+                prior
+            } else {
+                None
+            };
+
+        // Best-effort, like the GPU/Game Mode preferences above: a
+        // failure here (e.g. Tamper Protection is on) shouldn't block
+        // the rest of the profile from applying.
+        let prior_scan_schedule = profile.defender_scan_reschedule.as_ref().and_then(|reschedule| {
+            let prior = defender::read_scan_schedule().ok();
+            match reschedule {
+                DefenderScanReschedule::OffHours { quick_scan_time, full_scan_day, full_scan_time } => {
+                    let _ = defender::reschedule_to_off_hours(*quick_scan_time, *full_scan_day, *full_scan_time);
+                }
+                DefenderScanReschedule::OnlyWhenIdle => {
+                    let _ = defender::disable_scheduled_scans_for_idle_only();
+                }
+            }
+            prior
+        });
+
+        let prior_focus_assist_level = if let Some(level) = profile.focus_assist_level {
+            let prior = self.focus_assist.level().ok();
+            let _ = self.focus_assist.set_level(level);
+            prior
+        } else {
+            None
+        };
+
+        // Best-effort, like the other system tweaks above: a failure
+        // here (e.g. the service is already disabled by policy)
+        // shouldn't block the rest of the profile from applying.
+        let superfetch_disabled = if profile.disable_superfetch {
+            let mut service_manager = self.service_manager.lock().unwrap();
+            self.superfetch.lock().unwrap().disable(&mut service_manager).is_ok()
+        } else {
+            false
+        };
+
+        let prior_audio_enhancements_enabled = if let Some(enabled) = profile.audio_options.enhancements_enabled {
+            let prior = self.audio.settings().ok().map(|s| s.enhancements_enabled);
+            let _ = self.audio.set_enhancements_enabled(enabled);
+            prior
+        } else {
+            None
+        };
+        let prior_audio_exclusive_mode_allowed = if let Some(allowed) = profile.audio_options.exclusive_mode_allowed {
+            let prior = self.audio.settings().ok().map(|s| s.exclusive_mode_allowed);
+            let _ = self.audio.set_exclusive_mode_allowed(allowed);
+            prior
+        } else {
+            None
+        };
+
+        Ok(AppliedProfileState {
+            target_pid: Some(target_pid),
+            disabled_services,
+            prior_gpu_power_mode,
+            prior_hags_enabled,
+            prior_game_mode_enabled,
+            prior_game_dvr_enabled,
+            prior_fullscreen_optimizations_disabled,
+            prior_scan_schedule,
+            prior_game_bar_enabled,
+            prior_game_bar_background_tasks_enabled,
+            prior_focus_assist_level,
+            superfetch_disabled,
+            prior_audio_enhancements_enabled,
+            prior_audio_exclusive_mode_allowed,
+        })
+    }
+
+    /// Runs `profile`'s disk clean on a background thread so it doesn't
+    /// delay the rest of activation, skipping it entirely if one already
+    /// ran within [`SKIP_DISK_CLEAN_WINDOW`] and the profile asked to
+    /// honor that. Reports how much it freed once it finishes.
+    fn spawn_disk_clean(&self, profile: &GamingProfile) {
+        if profile.disk_options.skip_if_recent
+            && self.disk_clean_tracker.ran_within(&profile.name, SKIP_DISK_CLEAN_WINDOW)
+        {
+            return;
+        }
+
+        let profile_name = profile.name.clone();
+        let exclude_paths = profile.disk_options.exclude_paths.clone();
+        let tracker = LastRunTracker::new(std::env::temp_dir().join("gamebooster_state"));
+        let notification_settings = self.notification_settings;
+
+        thread::spawn(move || {
+            let options = CleanupOptions {
+                categories: [CleanupCategory::TempFiles, CleanupCategory::ShaderCache].into_iter().collect(),
+                exclude_paths,
+            };
+            let report = DiskCleaner::new().clean(&options);
+            let _ = tracker.record_ran(&profile_name);
+
+            let notification_options = NotificationOptions { toast: true, webhook_url: None, notify_on_success: true };
+            let _ = notify(
+                &notification_settings,
+                &notification_options,
+                &RunSummary {
+                    task_name: format!("Pre-launch disk clean ({profile_name})"),
+                    success: true,
+                    duration: Duration::ZERO,
+                    bytes_freed: report.bytes_freed,
+                    error: None,
+                },
+            );
+        });
+    }
+
+    /// Applies each of `profile`'s companion processes' CPU priority,
+    /// resolving their pids via `snapshot`. A companion that isn't
+    /// currently running is skipped silently — e.g. OBS not being open
+    /// yet shouldn't block the rest of a streaming profile.
+    pub fn apply_companions(&self, profile: &GamingProfile, snapshot: &impl ProcessSnapshot) {
+        let running = snapshot.running_processes();
+        for companion in &profile.companions {
+            let Some(priority) = companion.cpu_priority else { continue };
+            let matched = running.iter().find(|(_, name)| name.eq_ignore_ascii_case(&companion.process_name));
+            if let Some((pid, _)) = matched {
+                let _ = self.cpu.set_priority(*pid, priority);
+            }
+        }
+    }
+
+    /// Reverts what [`ProfileApplier::activate`] recorded in `state`:
+    /// re-enables the disabled services, clears the network limit, resets
+    /// the target's CPU priority to `Normal`, and restores each recorded
+    /// prior GPU, Game Mode, Defender, Focus Assist, Superfetch and audio
+    /// setting. The target's CPU affinity mask is not restored.
+    pub fn deactivate(&self, profile: &GamingProfile, state: &AppliedProfileState) -> Result<(), ApplyError> {
+        for service in state.disabled_services.iter().rev() {
+            self.services.set_service_enabled(service, true)?;
+        }
+
+        if let Some(pid) = state.target_pid {
+            if profile.background_network_limit_kbps.is_some() {
+                let _ = self.network.clear_rate_limit(pid);
+            }
+            if profile.cpu_priority.is_some() {
+                self.cpu.set_priority(pid, CpuPriority::Normal)?;
+            }
+        }
+
+        if let Some(mode) = state.prior_gpu_power_mode {
+            let _ = self.gpu.set_power_mode(None, mode);
+        }
+        if let Some(enabled) = state.prior_hags_enabled {
+            let _ = self.gpu.set_hags_enabled(enabled);
+        }
+
+        if let Some(enabled) = state.prior_game_mode_enabled {
+            let _ = self.game_mode.set_game_mode_enabled(enabled);
+        }
+        if let Some(enabled) = state.prior_game_dvr_enabled {
+            let _ = self.game_mode.set_game_dvr_enabled(enabled);
+        }
+        if let Some(disabled) = state.prior_fullscreen_optimizations_disabled {
+            let _ = self
+                .game_mode
+                .set_fullscreen_optimizations_disabled(&profile.target_process_name, disabled);
+        }
+
+        if let Some(schedule) = &state.prior_scan_schedule {
+            let _ = defender::set_scan_schedule(schedule);
+        }
+
+        if let Some(enabled) = state.prior_game_bar_enabled {
+            let _ = self.game_mode.set_game_bar_enabled(enabled);
+        }
+        if let Some(enabled) = state.prior_game_bar_background_tasks_enabled {
+            let _ = self.game_mode.set_game_bar_background_tasks_enabled(enabled);
+        }
+
+        if let Some(level) = state.prior_focus_assist_level {
+            let _ = self.focus_assist.set_level(level);
+        }
+
+        if state.superfetch_disabled {
+            let mut service_manager = self.service_manager.lock().unwrap();
+            let _ = self.superfetch.lock().unwrap().restore(&mut service_manager);
+        }
+
+        if let Some(enabled) = state.prior_audio_enhancements_enabled {
+            let _ = self.audio.set_enhancements_enabled(enabled);
+        }
+        if let Some(allowed) = state.prior_audio_exclusive_mode_allowed {
+            let _ = self.audio.set_exclusive_mode_allowed(allowed);
+        }
+
+        Ok(())
+    }
+
+    /// Activates `profile` the same way as [`ProfileApplier::activate`],
+    /// but first captures every setting's prior value and writes it to
+    /// `journal` before changing anything, so the change can be rolled
+    /// back later even if the app crashes or the machine reboots before
+    /// [`ProfileApplier::deactivate`] runs.
+    pub fn activate_journaled(
+        &self,
+        profile: &GamingProfile,
+        target_pid: u32,
+        journal: &RollbackJournal,
+    ) -> Result<AppliedProfileState, ApplyError> {
+        let mut prior_states = Vec::new();
+
+        if profile.cpu_priority.is_some() {
+            if let Ok(priority) = self.cpu.get_priority(target_pid) {
+                prior_states.push(PriorState::CpuPriority { pid: target_pid, priority });
+            }
+        }
+        if profile.affinity_mask.is_some() {
+            if let Ok(mask) = self.cpu.get_affinity(target_pid) {
+                prior_states.push(PriorState::CpuAffinity { pid: target_pid, mask });
+            }
+        }
+        if profile.background_network_limit_kbps.is_some() {
+            // There's no way to read back the current rate limit yet, so
+            // we record "no limit" — reverting will at least clear
+            // whatever this activation set.
+            prior_states.push(PriorState::NetworkRateLimit { pid: target_pid, kbps: None });
+        }
+        for service in &profile.disabled_services {
+            prior_states.push(PriorState::ServiceEnabled { service_name: service.clone(), enabled: true });
+        }
+
+        journal.record(JournalEntry { profile_name: profile.name.clone(), prior_states })?;
+
+        self.activate(profile, target_pid)
+    }
+
+    /// "Revert last profile": pops the most recent entry off `journal`
+    /// and restores every setting it recorded to its prior value. Works
+    /// purely from the journal file, so it's safe to call after a crash
+    /// or reboot with no other in-memory state available. Returns the
+    /// name of the reverted profile, or `None` if the journal was empty.
+    pub fn revert_last(&self, journal: &RollbackJournal) -> Result<Option<String>, ApplyError> {
+        let Some(entry) = journal.pop_last()? else {
+            return Ok(None);
+        };
+        for prior in &entry.prior_states {
+            match prior {
+                PriorState::CpuPriority { pid, priority } => {
+                    let _ = self.cpu.set_priority(*pid, *priority);
+                }
+                PriorState::CpuAffinity { pid, mask } => {
+                    let _ = self.cpu.set_affinity(*pid, *mask);
+                }
+                PriorState::ServiceEnabled { service_name, enabled } => {
+                    let _ = self.services.set_service_enabled(service_name, *enabled);
+                }
+                PriorState::NetworkRateLimit { pid, kbps } => match kbps {
+                    Some(limit) => {
+                        let _ = self.network.set_rate_limit(*pid, *limit, *limit);
+                    }
+                    None => {
+                        let _ = self.network.clear_rate_limit(*pid);
+                    }
+                },
+            }
+        }
+        Ok(Some(entry.profile_name))
+    }
+}
+
+impl Default for ProfileApplier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
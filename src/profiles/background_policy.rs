@@ -0,0 +1,41 @@
+//! Decides which running processes count as "background" for a profile
+//! — neither its target, a companion marked
+//! `exclude_from_background_limits`, nor on the user's whitelist — and
+//! what the profile wants done with them.
+
+use super::profile::GamingProfile;
+use super::watcher::ProcessSnapshot;
+
+/// A background process the profile is allowed to act on, and what to
+/// do with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackgroundAction {
+    pub pid: u32,
+    pub process_name: String,
+    pub suspend: bool,
+}
+
+/// Computes the set of [`BackgroundAction`]s for `profile` given the
+/// current process list. Returns nothing if the profile doesn't limit
+/// or suspend background apps at all.
+pub fn compute_background_actions(profile: &GamingProfile, snapshot: &impl ProcessSnapshot) -> Vec<BackgroundAction> {
+    if !profile.limit_background_apps && !profile.suspend_background_apps {
+        return Vec::new();
+    }
+
+    snapshot
+        .running_processes()
+        .into_iter()
+        .filter(|(_, name)| !is_exempt(profile, name))
+        .map(|(pid, process_name)| BackgroundAction { pid, process_name, suspend: profile.suspend_background_apps })
+        .collect()
+}
+
+fn is_exempt(profile: &GamingProfile, process_name: &str) -> bool {
+    process_name.eq_ignore_ascii_case(&profile.target_process_name)
+        || profile
+            .companions
+            .iter()
+            .any(|c| c.exclude_from_background_limits && c.process_name.eq_ignore_ascii_case(process_name))
+        || profile.background_app_whitelist.iter().any(|w| w.eq_ignore_ascii_case(process_name))
+}
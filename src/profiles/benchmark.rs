@@ -0,0 +1,192 @@
+//! Before/after benchmarking so a profile's claimed improvement is
+//! measurable rather than asserted. Runs a synthetic CPU/RAM/disk score,
+//! and [`compute_frame_time_stats`] reduces a per-frame time capture to
+//! the average plus the 1% and 0.1% lows the same way PresentMon itself
+//! summarizes a capture. No frame-time capture is taken:
+//! [`BenchmarkRunner::run`] always leaves `frame_time_stats` as `None`.
+//! [`super::benchmark_history`]
+//! is where a run gets stored so a later "before vs after" comparison
+//! doesn't depend on both runs still being in memory.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// The average plus the 1% and 0.1% lows over a frame-time capture —
+/// the same three numbers PresentMon's own summary reports, since the
+/// average alone hides the stutters a 1% low exposes. Derives
+/// `Serialize`/`Deserialize` (unlike [`BenchmarkSample`]) so
+/// [`super::benchmark_history::BenchmarkRun`] can persist it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FrameTimeStats {
+    pub avg_ms: f64,
+    /// Average frame time of the slowest 1% of frames — a stand-in for
+    /// "how bad do the worst moments get", not the 99th-percentile
+    /// frame time itself.
+    pub percentile_1_low_ms: f64,
+    /// Same idea over the slowest 0.1% of frames.
+    pub percentile_0_1_low_ms: f64,
+}
+
+/// Reduces a capture of per-frame times (in milliseconds, one per
+/// presented frame) to [`FrameTimeStats`]. `None` if `frame_times_ms`
+/// is empty — there's nothing to average.
+pub fn compute_frame_time_stats(frame_times_ms: &[f64]) -> Option<FrameTimeStats> {
+    if frame_times_ms.is_empty() {
+        return None;
+    }
+    let mut sorted = frame_times_ms.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let avg_ms = sorted.iter().sum::<f64>() / sorted.len() as f64;
+    Some(FrameTimeStats {
+        avg_ms,
+        percentile_1_low_ms: average_of_slowest_fraction(&sorted, 0.01),
+        percentile_0_1_low_ms: average_of_slowest_fraction(&sorted, 0.001),
+    })
+}
+
+/// Averages the slowest `fraction` of `sorted_ascending` (at least one
+/// frame, even if `fraction` would round down to zero on a short
+/// capture).
+fn average_of_slowest_fraction(sorted_ascending: &[f64], fraction: f64) -> f64 {
+    let count = ((sorted_ascending.len() as f64 * fraction).round() as usize).max(1);
+    let slowest = &sorted_ascending[sorted_ascending.len() - count..];
+    slowest.iter().sum::<f64>() / slowest.len() as f64
+}
+
+/// One benchmark pass's results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkSample {
+    pub cpu_score: f64,
+    pub ram_score: f64,
+    pub disk_score: f64,
+    /// Set if an ETW capture was available (a game was running and the
+    /// Present hook caught frames).
+    pub frame_time_stats: Option<FrameTimeStats>,
+}
+
+/// The difference between a "before" and "after" [`BenchmarkSample`].
+/// Positive `*_delta` values mean "after" scored higher; for
+/// `frame_time_avg_delta_ms`, negative means frames got faster.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkComparison {
+    pub cpu_delta: f64,
+    pub ram_delta: f64,
+    pub disk_delta: f64,
+    pub frame_time_avg_delta_ms: Option<f64>,
+    pub frame_time_1_low_delta_ms: Option<f64>,
+}
+
+impl BenchmarkSample {
+    pub fn compare(&self, after: &BenchmarkSample) -> BenchmarkComparison {
+        BenchmarkComparison {
+            cpu_delta: after.cpu_score - self.cpu_score,
+            ram_delta: after.ram_score - self.ram_score,
+            disk_delta: after.disk_score - self.disk_score,
+            frame_time_avg_delta_ms: match (self.frame_time_stats, after.frame_time_stats) {
+                (Some(before), Some(after)) => Some(after.avg_ms - before.avg_ms),
+                _ => None,
+            },
+            frame_time_1_low_delta_ms: match (self.frame_time_stats, after.frame_time_stats) {
+                (Some(before), Some(after)) => Some(after.percentile_1_low_ms - before.percentile_1_low_ms),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Runs the synthetic CPU/RAM/disk benchmark and, when available, an ETW
+/// frame-time capture.
+pub struct BenchmarkRunner {
+    pub synthetic_duration: Duration,
+}
+
+impl BenchmarkRunner {
+    pub fn new() -> Self {
+        Self { synthetic_duration: Duration::from_millis(500) }
+    }
+
+    /// Runs a full benchmark pass: a synthetic CPU/RAM/disk score, plus,
+    /// if `game_pid` is given and frame capture is supported, an ETW
+    /// frame-time sample over the same window.
+    pub fn run(&self, game_pid: Option<u32>) -> BenchmarkSample {
+        BenchmarkSample {
+            cpu_score: self.run_cpu_score(),
+            ram_score: self.run_ram_score(),
+            disk_score: self.run_disk_score(),
+            frame_time_stats: game_pid.and_then(|pid| self.capture_frame_times(pid)).and_then(|frames| compute_frame_time_stats(&frames)),
+        }
+    }
+
+    /// Busy-loop integer workload run for `synthetic_duration`; the
+    /// score is operations completed per second, so higher is better and
+    /// comparable across runs of the same duration.
+    fn run_cpu_score(&self) -> f64 {
+        let start = Instant::now();
+        let mut acc: u64 = 0;
+        let mut ops: u64 = 0;
+        while start.elapsed() < self.synthetic_duration {
+            acc = acc.wrapping_add(scramble(acc));
+            ops += 1;
+        }
+        ops as f64 / self.synthetic_duration.as_secs_f64()
+    }
+
+    /// Allocates and writes a scratch buffer and times it; a rough
+    /// stand-in for memory bandwidth.
+    fn run_ram_score(&self) -> f64 {
+        const SIZE: usize = 32 * 1024 * 1024;
+        let start = Instant::now();
+        let mut buf = vec![0u8; SIZE];
+        for (i, byte) in buf.iter_mut().enumerate() {
+            *byte = (i % 256) as u8;
+        }
+        let elapsed = start.elapsed().as_secs_f64().max(f64::EPSILON);
+        (SIZE as f64 / elapsed) / 1_000_000.0 // MB/s
+    }
+
+    /// Writes and reads back a temp file; a rough stand-in for disk
+    /// throughput.
+    fn run_disk_score(&self) -> f64 {
+        let path = std::env::temp_dir().join("gamebooster_bench.tmp");
+        let data = vec![0u8; 8 * 1024 * 1024];
+        let start = Instant::now();
+        let result = std::fs::write(&path, &data).and_then(|_| std::fs::read(&path));
+        let _ = std::fs::remove_file(&path);
+        match result {
+            Ok(_) => {
+                let elapsed = start.elapsed().as_secs_f64().max(f64::EPSILON);
+                (data.len() as f64 / elapsed) / 1_000_000.0 // MB/s
+            }
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Returns `None` on every platform: no ETW Present-event capture is
+    /// implemented, so no per-frame times are collected for `game_pid`.
+    #[cfg(windows)]
+    fn capture_frame_times(&self, _game_pid: u32) -> Option<Vec<f64>> {
+        // Nothing here hooks the Microsoft-Windows-DxgKrnl Present ETW
+        // events PresentMon consumes, so there are no frame times to
+        // return.
+        None
+    }
+
+    #[cfg(not(windows))]
+    fn capture_frame_times(&self, _game_pid: u32) -> Option<Vec<f64>> {
+        None
+    }
+}
+
+impl Default for BenchmarkRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cheap, deterministic mixing function so the CPU score loop does real
+/// work without allocating or touching any syscalls.
+fn scramble(seed: u64) -> u64 {
+    seed.wrapping_mul(2862933555777941757).wrapping_add(3037000493)
+}
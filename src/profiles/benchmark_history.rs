@@ -0,0 +1,110 @@
+//! Durable storage for [`super::benchmark::BenchmarkSample`] runs, so a
+//! "before" run survives long enough to compare against an "after" one
+//! taken in a separate launch — the same durable-JSON-file approach
+//! [`super::journal::RollbackJournal`] and [`crate::scheduler::history::TaskHistoryLog`]
+//! use for their own records.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use super::benchmark::{BenchmarkSample, FrameTimeStats};
+
+/// One stored [`BenchmarkSample`], labeled with when it ran and what
+/// the user called it (e.g. "before", "after", a profile name).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    pub label: String,
+    pub ran_at: SystemTime,
+    pub cpu_score: f64,
+    pub ram_score: f64,
+    pub disk_score: f64,
+    pub frame_time_stats: Option<FrameTimeStats>,
+}
+
+impl BenchmarkRun {
+    pub fn new(label: impl Into<String>, sample: BenchmarkSample) -> Self {
+        Self {
+            label: label.into(),
+            ran_at: SystemTime::now(),
+            cpu_score: sample.cpu_score,
+            ram_score: sample.ram_score,
+            disk_score: sample.disk_score,
+            frame_time_stats: sample.frame_time_stats,
+        }
+    }
+
+    /// This run's scores, in the shape [`BenchmarkSample::compare`]
+    /// expects, for comparing two runs pulled back out of history.
+    pub fn as_sample(&self) -> BenchmarkSample {
+        BenchmarkSample { cpu_score: self.cpu_score, ram_score: self.ram_score, disk_score: self.disk_score, frame_time_stats: self.frame_time_stats }
+    }
+}
+
+#[derive(Debug)]
+pub enum BenchmarkHistoryError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for BenchmarkHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BenchmarkHistoryError::Io(err) => write!(f, "io error: {err}"),
+            BenchmarkHistoryError::Parse(err) => write!(f, "invalid benchmark history: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for BenchmarkHistoryError {}
+
+impl From<std::io::Error> for BenchmarkHistoryError {
+    fn from(err: std::io::Error) -> Self {
+        BenchmarkHistoryError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BenchmarkHistoryError {
+    fn from(err: serde_json::Error) -> Self {
+        BenchmarkHistoryError::Parse(err)
+    }
+}
+
+/// Persists [`BenchmarkRun`]s to a JSON file on disk, for the
+/// Benchmarks tab's run list and before/after comparison picker.
+pub struct BenchmarkHistory {
+    path: PathBuf,
+}
+
+impl BenchmarkHistory {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Appends `run` and flushes the whole history to disk immediately.
+    pub fn record(&self, run: BenchmarkRun) -> Result<(), BenchmarkHistoryError> {
+        let mut runs = self.load_all()?;
+        runs.push(run);
+        self.write_all(&runs)
+    }
+
+    pub fn load_all(&self) -> Result<Vec<BenchmarkRun>, BenchmarkHistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        if data.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    fn write_all(&self, runs: &[BenchmarkRun]) -> Result<(), BenchmarkHistoryError> {
+        let json = serde_json::to_string_pretty(runs)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Scans common game launchers' install locations so a profile can be
+//! created from an installed game in one click, instead of the user
+//! having to browse to its executable manually.
+
+use std::path::PathBuf;
+
+use crate::cpu::CpuPriority;
+
+use super::profile::GamingProfile;
+
+/// A game found on disk by [`discover_installed_games`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscoveredGame {
+    pub name: String,
+    pub executable_path: PathBuf,
+    pub launcher: GameLauncher,
+    /// The launcher's own id for this game, e.g. a Steam app id —
+    /// `None` until the launcher-specific manifest parsing below fills
+    /// it in, which today is every launcher.
+    pub launcher_app_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameLauncher {
+    Steam,
+    Epic,
+    Gog,
+    Xbox,
+}
+
+impl DiscoveredGame {
+    /// Executable file name only — what
+    /// `GamingProfile::target_process_name` actually matches against.
+    pub fn executable_name(&self) -> String {
+        self.executable_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    /// Builds a starter profile for this game: CPU priority bumped to
+    /// High, everything else left for the user to tune.
+    pub fn to_profile_preset(&self) -> GamingProfile {
+        let mut profile = GamingProfile::new(self.name.clone(), self.executable_name());
+        profile.cpu_priority = Some(CpuPriority::High);
+        profile
+    }
+
+    /// The URI this game's own launcher uses to start it, if its
+    /// manifest carried an app id — `steam://run/<appid>` for Steam,
+    /// `com.epicgames.launcher://apps/<appid>?action=launch` for Epic.
+    /// GOG and Xbox games have no equivalent documented URI scheme, so
+    /// this is always `None` for them; launching those falls back to
+    /// running [`DiscoveredGame::executable_path`] directly.
+    pub fn launch_uri(&self) -> Option<String> {
+        let app_id = self.launcher_app_id.as_ref()?;
+        match self.launcher {
+            GameLauncher::Steam => Some(format!("steam://run/{app_id}")),
+            GameLauncher::Epic => Some(format!("com.epicgames.launcher://apps/{app_id}?action=launch")),
+            GameLauncher::Gog | GameLauncher::Xbox => None,
+        }
+    }
+}
+
+/// Scans every known launcher location and returns whatever installed
+/// games it can find. A launcher that isn't installed, or whose
+/// manifests can't be parsed, just contributes no games rather than
+/// failing the whole scan.
+pub fn discover_installed_games() -> Vec<DiscoveredGame> {
+    let mut games = Vec::new();
+    games.extend(steam::scan());
+    games.extend(epic::scan());
+    games.extend(gog::scan());
+    games.extend(xbox::scan());
+    games
+}
+
+mod steam {
+    use std::path::PathBuf;
+
+    use super::DiscoveredGame;
+
+    /// Parses Steam's `libraryfolders.vdf` to find every library, then
+    /// lists the `appmanifest_*.acf` files in each for installed app
+    /// names and install directories.
+    pub(super) fn scan() -> Vec<DiscoveredGame> {
+        let Some(steam_path) = install_path() else { return Vec::new() };
+        let library_folders_vdf = steam_path.join("steamapps").join("libraryfolders.vdf");
+        if !library_folders_vdf.exists() {
+            return Vec::new();
+        }
+        todo!("parse libraryfolders.vdf and each library's appmanifest_*.acf")
+    }
+
+    #[cfg(windows)]
+    fn install_path() -> Option<PathBuf> {
+        // HKLM\SOFTWARE\WOW6432Node\Valve\Steam\InstallPath
+        todo!("read Steam's install path from the registry")
+    }
+
+    #[cfg(not(windows))]
+    fn install_path() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".steam").join("steam"))
+    }
+}
+
+mod epic {
+    use std::path::PathBuf;
+
+    use super::DiscoveredGame;
+
+    /// Epic writes one `.item` JSON manifest per installed game under
+    /// `%PROGRAMDATA%\Epic\EpicGamesLauncher\Data\Manifests`.
+    pub(super) fn scan() -> Vec<DiscoveredGame> {
+        let Some(manifest_dir) = manifest_dir() else { return Vec::new() };
+        if !manifest_dir.exists() {
+            return Vec::new();
+        }
+        todo!("parse each *.item manifest for DisplayName/InstallLocation/LaunchExecutable")
+    }
+
+    #[cfg(windows)]
+    fn manifest_dir() -> Option<PathBuf> {
+        std::env::var_os("PROGRAMDATA").map(|programdata| {
+            PathBuf::from(programdata)
+                .join("Epic")
+                .join("EpicGamesLauncher")
+                .join("Data")
+                .join("Manifests")
+        })
+    }
+
+    #[cfg(not(windows))]
+    fn manifest_dir() -> Option<PathBuf> {
+        None
+    }
+}
+
+mod gog {
+    use super::DiscoveredGame;
+
+    /// GOG Galaxy keeps per-game install info in its local SQLite
+    /// database rather than flat manifest files.
+    pub(super) fn scan() -> Vec<DiscoveredGame> {
+        // Query GOG Galaxy's `galaxy-2.0.db` for installed titles.
+        Vec::new()
+    }
+}
+
+mod xbox {
+    use super::DiscoveredGame;
+
+    /// Xbox/PC Game Pass titles install as UWP packages; discovering
+    /// them means walking installed package state rather than scanning
+    /// a directory of manifests.
+    pub(super) fn scan() -> Vec<DiscoveredGame> {
+        // Enumerate installed Xbox packages via the AppX package APIs.
+        Vec::new()
+    }
+}
@@ -0,0 +1,93 @@
+//! Global hotkey registration, backed by `RegisterHotKey` on Windows, so
+//! a profile's hotkey fires no matter which window (if any) has focus.
+
+use std::fmt;
+
+use super::activation::Hotkey;
+
+#[derive(Debug)]
+pub enum HotkeyError {
+    AlreadyRegistered(Hotkey),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for HotkeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HotkeyError::AlreadyRegistered(hotkey) => {
+                write!(f, "{} is already registered by another app", hotkey.display())
+            }
+            HotkeyError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for HotkeyError {}
+
+/// Registers global hotkeys and reports which one fired.
+pub struct HotkeyListener;
+
+impl HotkeyListener {
+    pub fn new() -> Self {
+        HotkeyListener
+    }
+
+    /// Registers `hotkey` under `id`; a subsequent call to
+    /// [`HotkeyListener::poll_triggered`] reports `id` whenever the
+    /// combination is pressed anywhere in the system.
+    pub fn register(&mut self, id: i32, hotkey: &Hotkey) -> Result<(), HotkeyError> {
+        imp::register(id, hotkey)
+    }
+
+    pub fn unregister(&mut self, id: i32) -> Result<(), HotkeyError> {
+        imp::unregister(id)
+    }
+
+    /// Drains pending `WM_HOTKEY` messages and returns the ids that
+    /// fired since the last call.
+    pub fn poll_triggered(&mut self) -> Vec<i32> {
+        imp::poll_triggered()
+    }
+}
+
+impl Default for HotkeyListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{Hotkey, HotkeyError};
+
+    pub(super) fn register(_id: i32, _hotkey: &Hotkey) -> Result<(), HotkeyError> {
+        // RegisterHotKey(HWND::default(), id, modifiers, vk). Requires a
+        // message-only window pumping WM_HOTKEY on the UI thread.
+        todo!("register the combination via RegisterHotKey")
+    }
+
+    pub(super) fn unregister(_id: i32) -> Result<(), HotkeyError> {
+        todo!("release the combination via UnregisterHotKey")
+    }
+
+    pub(super) fn poll_triggered() -> Vec<i32> {
+        todo!("drain WM_HOTKEY messages from the message-only window's queue")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{Hotkey, HotkeyError};
+
+    pub(super) fn register(_id: i32, _hotkey: &Hotkey) -> Result<(), HotkeyError> {
+        Err(HotkeyError::Unsupported("global hotkeys aren't wired up on this platform"))
+    }
+
+    pub(super) fn unregister(_id: i32) -> Result<(), HotkeyError> {
+        Err(HotkeyError::Unsupported("global hotkeys aren't wired up on this platform"))
+    }
+
+    pub(super) fn poll_triggered() -> Vec<i32> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,180 @@
+//! Durable rollback journal: the exact prior state of every setting a
+//! profile touched, written to disk before the change is made so
+//! "Revert last profile" still works after an app crash or reboot.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cpu::CpuPriority;
+
+/// One setting's value immediately before a profile changed it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PriorState {
+    CpuPriority { pid: u32, priority: CpuPriority },
+    CpuAffinity { pid: u32, mask: u64 },
+    ServiceEnabled { service_name: String, enabled: bool },
+    /// `None` means no limit was in effect before the profile applied one.
+    NetworkRateLimit { pid: u32, kbps: Option<u32> },
+}
+
+/// A durable record of one profile activation: which profile, and every
+/// setting's prior state, written before any change is applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub profile_name: String,
+    pub prior_states: Vec<PriorState>,
+}
+
+#[derive(Debug)]
+pub enum JournalError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JournalError::Io(err) => write!(f, "io error: {err}"),
+            JournalError::Parse(err) => write!(f, "invalid rollback journal: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+impl From<std::io::Error> for JournalError {
+    fn from(err: std::io::Error) -> Self {
+        JournalError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for JournalError {
+    fn from(err: serde_json::Error) -> Self {
+        JournalError::Parse(err)
+    }
+}
+
+/// Persists rollback journal entries to a JSON file on disk so they
+/// survive an app crash or reboot, and replays the most recent one on
+/// demand.
+pub struct RollbackJournal {
+    path: PathBuf,
+}
+
+impl RollbackJournal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Appends `entry` and flushes the whole journal to disk
+    /// immediately. Called before the applier makes any of the changes
+    /// `entry` describes, so a crash mid-apply still leaves a record of
+    /// what needs reverting.
+    pub fn record(&self, entry: JournalEntry) -> Result<(), JournalError> {
+        let mut entries = self.load_all()?;
+        entries.push(entry);
+        self.write_all(&entries)
+    }
+
+    pub fn load_all(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        if data.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Returns the most recently recorded entry without removing it.
+    pub fn last(&self) -> Result<Option<JournalEntry>, JournalError> {
+        Ok(self.load_all()?.pop())
+    }
+
+    /// Removes and returns the most recent entry, so "Revert last
+    /// profile" can apply it and then drop it from the journal.
+    pub fn pop_last(&self) -> Result<Option<JournalEntry>, JournalError> {
+        let mut entries = self.load_all()?;
+        let last = entries.pop();
+        self.write_all(&entries)?;
+        Ok(last)
+    }
+
+    fn write_all(&self, entries: &[JournalEntry]) -> Result<(), JournalError> {
+        let json = serde_json::to_string_pretty(entries)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A journal backed by a throwaway file under the OS temp dir,
+    /// unique per test via the `name` each test passes in (plus the
+    /// process id) so parallel tests don't clobber each other's journal
+    /// file.
+    fn journal(name: &str) -> RollbackJournal {
+        let path = std::env::temp_dir().join(format!("gamebooster_rollback_journal_test_{name}_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        RollbackJournal::new(path)
+    }
+
+    fn entry(profile_name: &str) -> JournalEntry {
+        JournalEntry { profile_name: profile_name.to_string(), prior_states: vec![PriorState::CpuAffinity { pid: 1234, mask: 0xF }] }
+    }
+
+    #[test]
+    fn load_all_on_a_missing_file_is_an_empty_journal() {
+        let journal = journal("missing");
+        assert_eq!(journal.load_all().unwrap(), Vec::new());
+        assert!(journal.last().unwrap().is_none());
+    }
+
+    #[test]
+    fn record_appends_and_persists_entries_in_order() {
+        let journal = journal("record_appends");
+        journal.record(entry("profile-a")).unwrap();
+        journal.record(entry("profile-b")).unwrap();
+
+        let entries = journal.load_all().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].profile_name, "profile-a");
+        assert_eq!(entries[1].profile_name, "profile-b");
+    }
+
+    #[test]
+    fn last_returns_the_most_recent_entry_without_removing_it() {
+        let journal = journal("last_is_nondestructive");
+        journal.record(entry("profile-a")).unwrap();
+        journal.record(entry("profile-b")).unwrap();
+
+        assert_eq!(journal.last().unwrap().unwrap().profile_name, "profile-b");
+        assert_eq!(journal.load_all().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn pop_last_removes_and_returns_the_most_recent_entry() {
+        let journal = journal("pop_last_replays_and_removes");
+        journal.record(entry("profile-a")).unwrap();
+        journal.record(entry("profile-b")).unwrap();
+
+        let popped = journal.pop_last().unwrap().unwrap();
+        assert_eq!(popped.profile_name, "profile-b");
+
+        let remaining = journal.load_all().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].profile_name, "profile-a");
+    }
+
+    #[test]
+    fn pop_last_on_an_empty_journal_returns_none() {
+        let journal = journal("pop_last_empty");
+        assert!(journal.pop_last().unwrap().is_none());
+    }
+}
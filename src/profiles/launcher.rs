@@ -0,0 +1,81 @@
+//! Starts a [`DiscoveredGame`] from the Games tab's "Play" button. Just
+//! hands the launch off to the OS or the game's own launcher — actually
+//! applying the linked [`GamingProfile`](super::profile::GamingProfile)
+//! is left to [`super::watcher::ProfileWatcher`], which picks up the
+//! newly-started process the same way it would if the user had started
+//! the game outside this app.
+
+use std::fmt;
+
+use super::discovery::DiscoveredGame;
+
+#[derive(Debug)]
+pub enum LaunchError {
+    Os(std::io::Error),
+    /// Neither a launcher URI nor an executable path resolved to
+    /// something runnable.
+    NoLaunchTarget,
+}
+
+impl fmt::Display for LaunchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LaunchError::Os(err) => write!(f, "failed to launch: {err}"),
+            LaunchError::NoLaunchTarget => write!(f, "game has no launcher URI or executable path"),
+        }
+    }
+}
+
+impl std::error::Error for LaunchError {}
+
+/// Launches `game` via its launcher URI (`steam://run/...`) when it has
+/// one, falling back to running [`DiscoveredGame::executable_path`]
+/// directly otherwise.
+pub fn launch(game: &DiscoveredGame) -> Result<(), LaunchError> {
+    match game.launch_uri() {
+        Some(uri) => imp::open_uri(&uri),
+        None => imp::run_executable(&game.executable_path),
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::path::Path;
+    use std::process::Command;
+
+    use super::LaunchError;
+
+    /// Hands `uri` to `ShellExecuteW`'s `start`-equivalent so Windows
+    /// routes it to whichever launcher registered the scheme.
+    pub(super) fn open_uri(uri: &str) -> Result<(), LaunchError> {
+        Command::new("cmd").args(["/C", "start", "", uri]).spawn().map(|_| ()).map_err(LaunchError::Os)
+    }
+
+    pub(super) fn run_executable(path: &Path) -> Result<(), LaunchError> {
+        if path.as_os_str().is_empty() {
+            return Err(LaunchError::NoLaunchTarget);
+        }
+        Command::new(path).spawn().map(|_| ()).map_err(LaunchError::Os)
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::path::Path;
+    use std::process::Command;
+
+    use super::LaunchError;
+
+    /// Hands `uri` to `xdg-open`, which on a desktop with the launcher
+    /// installed routes it the same way `start` does on Windows.
+    pub(super) fn open_uri(uri: &str) -> Result<(), LaunchError> {
+        Command::new("xdg-open").arg(uri).spawn().map(|_| ()).map_err(LaunchError::Os)
+    }
+
+    pub(super) fn run_executable(path: &Path) -> Result<(), LaunchError> {
+        if path.as_os_str().is_empty() {
+            return Err(LaunchError::NoLaunchTarget);
+        }
+        Command::new(path).spawn().map(|_| ()).map_err(LaunchError::Os)
+    }
+}
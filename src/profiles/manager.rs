@@ -0,0 +1,117 @@
+//! Loads and saves the user's gaming profiles as `profiles.json`, and
+//! merges in built-in presets without clobbering a profile the user has
+//! customized under the same name.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::profile::GamingProfile;
+
+#[derive(Debug)]
+pub enum ProfileManagerError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ProfileManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileManagerError::Io(err) => write!(f, "io error: {err}"),
+            ProfileManagerError::Parse(err) => write!(f, "invalid profiles.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ProfileManagerError {}
+
+impl From<std::io::Error> for ProfileManagerError {
+    fn from(err: std::io::Error) -> Self {
+        ProfileManagerError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ProfileManagerError {
+    fn from(err: serde_json::Error) -> Self {
+        ProfileManagerError::Parse(err)
+    }
+}
+
+/// One stored profile plus whether the user has edited it since it was
+/// last seeded from a built-in preset. A customized profile survives
+/// [`ProfileManager::sync_presets`] untouched; an un-customized one gets
+/// replaced so preset updates still reach users who never changed it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredProfile {
+    profile: GamingProfile,
+    #[serde(default)]
+    customized: bool,
+}
+
+/// Owns the on-disk set of gaming profiles: loads `profiles.json` at
+/// startup, and saves back to it after every mutation so the app never
+/// needs an explicit "Save" action.
+pub struct ProfileManager {
+    path: PathBuf,
+    profiles: Vec<StoredProfile>,
+}
+
+impl ProfileManager {
+    /// Loads `path` if it exists, or starts empty if this is the first
+    /// run.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ProfileManagerError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, profiles: Vec::new() });
+        }
+        let data = fs::read_to_string(&path)?;
+        let profiles = if data.trim().is_empty() { Vec::new() } else { serde_json::from_str(&data)? };
+        Ok(Self { path, profiles })
+    }
+
+    pub fn save_to_file(&self) -> Result<(), ProfileManagerError> {
+        let json = serde_json::to_string_pretty(&self.profiles)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    pub fn profiles(&self) -> impl Iterator<Item = &GamingProfile> {
+        self.profiles.iter().map(|stored| &stored.profile)
+    }
+
+    pub fn find(&self, name: &str) -> Option<&GamingProfile> {
+        self.profiles.iter().find(|stored| stored.profile.name == name).map(|stored| &stored.profile)
+    }
+
+    /// Inserts `profile`, or replaces the existing one with the same
+    /// name, marks it customized, and saves immediately.
+    pub fn upsert(&mut self, profile: GamingProfile) -> Result<(), ProfileManagerError> {
+        match self.profiles.iter_mut().find(|stored| stored.profile.name == profile.name) {
+            Some(stored) => *stored = StoredProfile { profile, customized: true },
+            None => self.profiles.push(StoredProfile { profile, customized: true }),
+        }
+        self.save_to_file()
+    }
+
+    /// Removes the profile named `name`, if any, and saves immediately.
+    pub fn remove(&mut self, name: &str) -> Result<(), ProfileManagerError> {
+        self.profiles.retain(|stored| stored.profile.name != name);
+        self.save_to_file()
+    }
+
+    /// Seeds any preset in `presets` that isn't already present, and
+    /// refreshes every preset-named profile the user hasn't customized,
+    /// so preset updates reach users without overwriting their edits.
+    pub fn sync_presets(&mut self, presets: Vec<GamingProfile>) -> Result<(), ProfileManagerError> {
+        for preset in presets {
+            match self.profiles.iter_mut().find(|stored| stored.profile.name == preset.name) {
+                Some(stored) if !stored.customized => stored.profile = preset,
+                Some(_) => {}
+                None => self.profiles.push(StoredProfile { profile: preset, customized: false }),
+            }
+        }
+        self.save_to_file()
+    }
+}
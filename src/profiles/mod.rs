@@ -0,0 +1,34 @@
+//! Gaming profiles: what to change about the system while a given game
+//! is running, and the engine that actually applies and reverts it.
+
+pub mod activation;
+pub mod applier;
+pub mod background_policy;
+pub mod benchmark;
+pub mod benchmark_history;
+pub mod discovery;
+pub mod hotkey_listener;
+pub mod journal;
+pub mod launcher;
+pub mod manager;
+pub mod portable;
+pub mod presets;
+pub mod profile;
+pub mod watchdog;
+pub mod watcher;
+
+pub use activation::{Hotkey, ProfileActivation};
+pub use applier::{ApplyError, AppliedProfileState, ProfileApplier};
+pub use background_policy::{compute_background_actions, BackgroundAction};
+pub use benchmark::{compute_frame_time_stats, BenchmarkComparison, BenchmarkRunner, BenchmarkSample, FrameTimeStats};
+pub use benchmark_history::{BenchmarkHistory, BenchmarkHistoryError, BenchmarkRun};
+pub use discovery::{discover_installed_games, DiscoveredGame, GameLauncher};
+pub use hotkey_listener::{HotkeyError, HotkeyListener};
+pub use journal::{JournalEntry, JournalError, PriorState, RollbackJournal};
+pub use launcher::LaunchError;
+pub use manager::{ProfileManager, ProfileManagerError};
+pub use portable::{PortableProfileError, ProfileBundle};
+pub use presets::{detect_running_obs, streaming_preset};
+pub use profile::{CompanionProcess, DiskCleanOptions, GameModeOptions, GamingProfile, GpuOptions};
+pub use watchdog::{detect_unclean_shutdown, mark_clean_exit, mark_running, recover_if_needed};
+pub use watcher::ProfileWatcher;
@@ -0,0 +1,96 @@
+//! Versioned `.gbprofile` export/import format for sharing one or all
+//! gaming profiles between machines.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::profile::GamingProfile;
+
+/// Current format version written by this build. Bump whenever
+/// [`GamingProfile`]'s shape changes in a way [`migrate`] needs to
+/// handle.
+pub const CURRENT_FORMAT_VERSION: u32 = 1;
+
+/// The on-disk `.gbprofile` envelope: a format version plus one or more
+/// profiles, so "export one profile" and "export all profiles" round-trip
+/// through the same file shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileBundle {
+    pub format_version: u32,
+    pub profiles: Vec<GamingProfile>,
+}
+
+#[derive(Debug)]
+pub enum PortableProfileError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for PortableProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortableProfileError::Io(err) => write!(f, "io error: {err}"),
+            PortableProfileError::Parse(err) => write!(f, "invalid .gbprofile file: {err}"),
+            PortableProfileError::UnsupportedVersion(v) => {
+                write!(f, "'.gbprofile' format version {v} is newer than this app supports")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PortableProfileError {}
+
+impl From<std::io::Error> for PortableProfileError {
+    fn from(err: std::io::Error) -> Self {
+        PortableProfileError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for PortableProfileError {
+    fn from(err: serde_json::Error) -> Self {
+        PortableProfileError::Parse(err)
+    }
+}
+
+impl ProfileBundle {
+    pub fn single(profile: GamingProfile) -> Self {
+        Self { format_version: CURRENT_FORMAT_VERSION, profiles: vec![profile] }
+    }
+
+    pub fn all(profiles: Vec<GamingProfile>) -> Self {
+        Self { format_version: CURRENT_FORMAT_VERSION, profiles }
+    }
+
+    /// Writes this bundle to a `.gbprofile` file as pretty-printed JSON.
+    pub fn export_to_file(&self, path: &Path) -> Result<(), PortableProfileError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Reads a `.gbprofile` file, migrating it to the current format
+    /// version first if it was exported by an older build.
+    pub fn import_from_file(path: &Path) -> Result<Self, PortableProfileError> {
+        let data = fs::read_to_string(path)?;
+        let raw: serde_json::Value = serde_json::from_str(&data)?;
+        let from_version = raw.get("format_version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+        let migrated = migrate(raw, from_version)?;
+        let bundle: ProfileBundle = serde_json::from_value(migrated)?;
+        Ok(bundle)
+    }
+}
+
+/// Upgrades a parsed `.gbprofile` JSON value from `from_version` to
+/// [`CURRENT_FORMAT_VERSION`]. There's only one version today, so this is
+/// a no-op beyond the compatibility check, but it gives future format
+/// changes somewhere to live without breaking old exports.
+fn migrate(value: serde_json::Value, from_version: u32) -> Result<serde_json::Value, PortableProfileError> {
+    if from_version > CURRENT_FORMAT_VERSION {
+        return Err(PortableProfileError::UnsupportedVersion(from_version));
+    }
+    Ok(value)
+}
@@ -0,0 +1,44 @@
+//! Built-in profile presets. Today there's just "Streaming", which tunes
+//! a profile for running OBS (or a similar broadcaster) and voice chat
+//! alongside the game instead of treating them as background noise.
+
+use crate::cpu::CpuPriority;
+
+use super::profile::{CompanionProcess, GamingProfile};
+use super::watcher::ProcessSnapshot;
+
+/// Executable names recognized as an OBS-family broadcaster.
+const OBS_EXECUTABLES: &[&str] = &["obs64.exe", "obs32.exe", "obs"];
+
+/// Returns `true` if any running process looks like an OBS instance,
+/// used to suggest the Streaming preset automatically.
+pub fn detect_running_obs(snapshot: &impl ProcessSnapshot) -> bool {
+    snapshot
+        .running_processes()
+        .iter()
+        .any(|(_, name)| OBS_EXECUTABLES.iter().any(|obs| name.eq_ignore_ascii_case(obs)))
+}
+
+/// Builds a "Streaming" preset for `target_process_name`: keeps OBS at
+/// AboveNormal and voice chat at Normal priority, records
+/// `stream_upload_kbps` as the profile's
+/// [`GamingProfile::reserved_upload_kbps`] (which the applier does not
+/// act on), and exempts both companions from whatever background limits
+/// the profile applies to everything else.
+pub fn streaming_preset(target_process_name: impl Into<String>, stream_upload_kbps: u32) -> GamingProfile {
+    let mut profile = GamingProfile::new("Streaming", target_process_name);
+    profile.cpu_priority = Some(CpuPriority::High);
+    profile.reserved_upload_kbps = Some(stream_upload_kbps);
+    profile.companions = vec![
+        companion_with_priority("obs64.exe", CpuPriority::AboveNormal),
+        companion_with_priority("Discord.exe", CpuPriority::Normal),
+    ];
+    profile
+}
+
+fn companion_with_priority(process_name: &str, priority: CpuPriority) -> CompanionProcess {
+    let mut companion = CompanionProcess::new(process_name);
+    companion.cpu_priority = Some(priority);
+    companion.exclude_from_background_limits = true;
+    companion
+}
@@ -0,0 +1,201 @@
+//! `GamingProfile` is pure data: what the user wants changed for a given
+//! game. See [`crate::profiles::applier`] for the code that actually
+//! enforces it.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cpu::CpuPriority;
+use crate::system::defender::ScanDay;
+use crate::system::focus_assist::FocusAssistLevel;
+use crate::scheduler::TimeOfDay;
+
+use super::activation::ProfileActivation;
+
+/// Where to move Defender's built-in scheduled scans while a profile is
+/// active, since a scan starting mid-game is a common stutter source.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DefenderScanReschedule {
+    /// Moves the quick and full scan to the given off-hours times.
+    OffHours { quick_scan_time: TimeOfDay, full_scan_day: ScanDay, full_scan_time: TimeOfDay },
+    /// Turns off Defender's own schedule entirely, relying on this
+    /// app's own idle-gated scheduler to trigger scans instead.
+    OnlyWhenIdle,
+}
+
+/// GPU power-mode and scheduling preferences a profile applies on
+/// activation and reverts on exit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct GpuOptions {
+    /// Sets the target process's graphics preference to maximum
+    /// performance while the profile is active.
+    pub prefer_max_performance: bool,
+    /// `Some(true/false)` toggles hardware-accelerated GPU scheduling;
+    /// `None` leaves it untouched.
+    pub hags_enabled: Option<bool>,
+}
+
+/// Windows Game Mode, GameDVR, and fullscreen optimization preferences a
+/// profile applies on activation and reverts on exit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct GameModeOptions {
+    /// `Some(true/false)` toggles Windows' own Game Mode; `None` leaves
+    /// it untouched.
+    pub game_mode_enabled: Option<bool>,
+    /// `Some(true/false)` toggles GameDVR background recording; `None`
+    /// leaves it untouched.
+    pub game_dvr_enabled: Option<bool>,
+    /// Disables fullscreen optimizations for `target_process_name` while
+    /// the profile is active.
+    pub disable_fullscreen_optimizations: bool,
+    /// `Some(true/false)` toggles whether the Xbox Game Bar overlay
+    /// (Win+G) opens at all; `None` leaves it untouched.
+    #[serde(default)]
+    pub game_bar_enabled: Option<bool>,
+    /// `Some(true/false)` toggles whether the Xbox Game Bar's AppX
+    /// package is allowed to run background tasks; `None` leaves it
+    /// untouched.
+    #[serde(default)]
+    pub game_bar_background_tasks_enabled: Option<bool>,
+}
+
+/// Default playback device tweaks a profile applies on activation and
+/// reverts on exit.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct AudioOptions {
+    /// `Some(true/false)` toggles the default device's audio
+    /// enhancements; `None` leaves them untouched.
+    pub enhancements_enabled: Option<bool>,
+    /// `Some(true/false)` toggles whether apps can take exclusive
+    /// control of the default device; `None` leaves it untouched.
+    pub exclusive_mode_allowed: Option<bool>,
+}
+
+/// Controls what [`crate::profiles::applier::ProfileApplier`]'s
+/// pre-launch disk clean is allowed to touch for this profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DiskCleanOptions {
+    /// Paths to leave alone even if they match a cleanup category, e.g.
+    /// a shader cache the user wants to keep warm.
+    pub exclude_paths: Vec<PathBuf>,
+    /// Skip the cleanup entirely if one already ran within the last 24h.
+    pub skip_if_recent: bool,
+}
+
+/// A process that should ride along with the profile's main target
+/// instead of being treated as background noise — e.g. OBS or a voice
+/// chat client during a streaming profile.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompanionProcess {
+    /// Executable name to match, e.g. `"obs64.exe"`.
+    pub process_name: String,
+    pub cpu_priority: Option<CpuPriority>,
+    /// Exempts this process from any background CPU/network throttling
+    /// the profile would otherwise apply to non-target processes.
+    pub exclude_from_background_limits: bool,
+}
+
+impl CompanionProcess {
+    pub fn new(process_name: impl Into<String>) -> Self {
+        Self { process_name: process_name.into(), cpu_priority: None, exclude_from_background_limits: false }
+    }
+}
+
+/// A named bundle of system tweaks to apply whenever a specific game is
+/// running.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamingProfile {
+    pub name: String,
+    /// Executable name used to match the running game, e.g.
+    /// `"VALORANT-Win64-Shipping.exe"`.
+    pub target_process_name: String,
+    pub cpu_priority: Option<CpuPriority>,
+    pub affinity_mask: Option<u64>,
+    pub background_network_limit_kbps: Option<u32>,
+    /// Upload bandwidth, in kbps, to reserve for a companion like OBS.
+    /// Stored with the profile only: the applier does not read it, so no
+    /// bandwidth is reserved and the background network limit is applied
+    /// unchanged whether this is set or not.
+    #[serde(default)]
+    pub reserved_upload_kbps: Option<u32>,
+    /// Service names to stop and disable while the profile is active.
+    pub disabled_services: Vec<String>,
+    pub clean_ram_on_activate: bool,
+    /// Runs a shader-cache-safe, exclusion-aware disk cleanup
+    /// asynchronously before the rest of the profile applies.
+    #[serde(default)]
+    pub clean_disk_before_launch: bool,
+    #[serde(default)]
+    pub disk_options: DiskCleanOptions,
+    /// Processes that should be tuned alongside the target instead of
+    /// being treated as background noise.
+    #[serde(default)]
+    pub companions: Vec<CompanionProcess>,
+    /// Throttles every non-exempt background process's CPU while this
+    /// profile is active.
+    #[serde(default)]
+    pub limit_background_apps: bool,
+    /// Suspends every non-exempt background process while this profile
+    /// is active, instead of just throttling it.
+    #[serde(default)]
+    pub suspend_background_apps: bool,
+    /// Process names exempt from `limit_background_apps`,
+    /// `background_network_limit_kbps`, and suspension — e.g. Discord,
+    /// Spotify, or capture software the user wants left alone.
+    #[serde(default)]
+    pub background_app_whitelist: Vec<String>,
+    #[serde(default)]
+    pub gpu_options: GpuOptions,
+    #[serde(default)]
+    pub game_mode_options: GameModeOptions,
+    /// Schedule and/or hotkey that can activate this profile without the
+    /// process watcher seeing its target start.
+    #[serde(default)]
+    pub activation: ProfileActivation,
+    /// Where to move Defender's scheduled scans while this profile is
+    /// active. `None` leaves Defender's schedule untouched.
+    #[serde(default)]
+    pub defender_scan_reschedule: Option<DefenderScanReschedule>,
+    /// Focus Assist level to switch to while this profile is active, so
+    /// toasts don't pop over a fullscreen game. `None` leaves Focus
+    /// Assist untouched.
+    #[serde(default)]
+    pub focus_assist_level: Option<FocusAssistLevel>,
+    /// Stops and disables the SysMain (Superfetch) service and its
+    /// prefetcher registry tuning while this profile is active. See
+    /// [`crate::system::superfetch::recommendation`] for whether this is
+    /// actually a good idea on the user's system drive.
+    #[serde(default)]
+    pub disable_superfetch: bool,
+    #[serde(default)]
+    pub audio_options: AudioOptions,
+}
+
+impl GamingProfile {
+    pub fn new(name: impl Into<String>, target_process_name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            target_process_name: target_process_name.into(),
+            cpu_priority: None,
+            affinity_mask: None,
+            background_network_limit_kbps: None,
+            reserved_upload_kbps: None,
+            disabled_services: Vec::new(),
+            clean_ram_on_activate: false,
+            clean_disk_before_launch: false,
+            disk_options: DiskCleanOptions::default(),
+            companions: Vec::new(),
+            limit_background_apps: false,
+            suspend_background_apps: false,
+            background_app_whitelist: Vec::new(),
+            gpu_options: GpuOptions::default(),
+            game_mode_options: GameModeOptions::default(),
+            activation: ProfileActivation::default(),
+            defender_scan_reschedule: None,
+            focus_assist_level: None,
+            disable_superfetch: false,
+            audio_options: AudioOptions::default(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Detects that the previous launch ended without a clean shutdown (a
+//! crash, a forced kill, a power loss) and replays [`RollbackJournal`]
+//! against it, so a profile's CPU priority/affinity, service, and
+//! network-limit changes don't outlive the app that applied them.
+//!
+//! Works from a single marker file rather than a separate watchdog
+//! process: [`mark_running`] creates it at startup and [`mark_clean_exit`]
+//! removes it on a normal shutdown, so finding it still present at the
+//! *next* startup means the last run never got that far.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{ApplyError, ProfileApplier, RollbackJournal};
+
+/// Marks this launch as running, so a later launch can tell whether the
+/// previous one shut down cleanly. Call once at startup, before doing
+/// anything the rollback journal would need to undo.
+pub fn mark_running(marker_path: impl AsRef<Path>) -> std::io::Result<()> {
+    fs::write(marker_path, [])
+}
+
+/// Marks this launch as having shut down cleanly. Call on every normal
+/// exit path (the GUI closing, `--exit` from the tray, Ctrl+C in the
+/// CLI) — anything that skips this, including a crash, leaves the
+/// marker behind for the next launch to find.
+pub fn mark_clean_exit(marker_path: impl AsRef<Path>) -> std::io::Result<()> {
+    let path = marker_path.as_ref();
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Whether `marker_path` is still present from a previous launch that
+/// never called [`mark_clean_exit`].
+pub fn detect_unclean_shutdown(marker_path: impl AsRef<Path>) -> bool {
+    marker_path.as_ref().exists()
+}
+
+/// If the previous launch didn't shut down cleanly, reverts every entry
+/// still in `journal` (oldest first isn't guaranteed or needed — each
+/// entry only touches settings that entry itself changed) before
+/// re-marking this launch as running. Returns the names of every
+/// profile reverted, empty if the previous shutdown was clean or the
+/// journal was already empty.
+///
+/// Call once at startup, right after [`ProfileApplier`] and
+/// [`RollbackJournal`] are constructed and before anything else touches
+/// either.
+pub fn recover_if_needed(
+    applier: &ProfileApplier,
+    journal: &RollbackJournal,
+    marker_path: impl AsRef<Path>,
+) -> Result<Vec<String>, ApplyError> {
+    let marker_path: PathBuf = marker_path.as_ref().to_path_buf();
+    let mut reverted = Vec::new();
+
+    if detect_unclean_shutdown(&marker_path) {
+        while let Some(profile_name) = applier.revert_last(journal)? {
+            reverted.push(profile_name);
+        }
+    }
+
+    let _ = mark_running(&marker_path);
+    Ok(reverted)
+}
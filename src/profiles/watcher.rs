@@ -0,0 +1,113 @@
+//! Background watcher that activates a [`GamingProfile`] automatically
+//! when its target process starts, and rolls it back when that process
+//! exits. Polls the process list rather than subscribing to WMI process
+//! creation events, trading a little latency for something that behaves
+//! the same way on every supported platform.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use super::applier::{AppliedProfileState, ProfileApplier};
+use super::profile::GamingProfile;
+
+/// How often the watcher re-scans the process list.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Lists running processes as `(pid, executable_name)`. Implemented
+/// against the real OS process list in production; a fake can be passed
+/// to [`ProfileWatcher::with_snapshot`] instead.
+pub trait ProcessSnapshot: Send + Sync {
+    fn running_processes(&self) -> Vec<(u32, String)>;
+}
+
+/// Default [`ProcessSnapshot`] backed by `sysinfo`.
+pub struct SysinfoSnapshot;
+
+impl ProcessSnapshot for SysinfoSnapshot {
+    fn running_processes(&self) -> Vec<(u32, String)> {
+        todo!("poll the process list via sysinfo")
+    }
+}
+
+/// A profile currently applied because its target process is running.
+pub struct ActiveMatch {
+    pub pid: u32,
+    state: AppliedProfileState,
+}
+
+/// Watches for `GamingProfile::target_process_name` processes starting
+/// and exiting, applying and rolling back the matching profile.
+pub struct ProfileWatcher<S: ProcessSnapshot = SysinfoSnapshot> {
+    snapshot: S,
+    applier: ProfileApplier,
+}
+
+impl ProfileWatcher<SysinfoSnapshot> {
+    pub fn new() -> Self {
+        Self::with_snapshot(SysinfoSnapshot)
+    }
+}
+
+impl<S: ProcessSnapshot> ProfileWatcher<S> {
+    pub fn with_snapshot(snapshot: S) -> Self {
+        Self { snapshot, applier: ProfileApplier::new() }
+    }
+
+    /// Runs one poll cycle: activates profiles whose target just
+    /// started, deactivates ones whose target just exited. `active`
+    /// tracks matches across calls and is mutated in place.
+    pub fn poll_once(&self, profiles: &[GamingProfile], active: &mut HashMap<String, ActiveMatch>) {
+        let running = self.snapshot.running_processes();
+
+        for profile in profiles {
+            let still_running = running
+                .iter()
+                .find(|(_, name)| name.eq_ignore_ascii_case(&profile.target_process_name));
+
+            match (active.contains_key(&profile.name), still_running) {
+                (false, Some((pid, _))) => {
+                    if let Ok(state) = self.applier.activate(profile, *pid) {
+                        active.insert(profile.name.clone(), ActiveMatch { pid: *pid, state });
+                    }
+                }
+                (true, None) => {
+                    if let Some(m) = active.remove(&profile.name) {
+                        let _ = self.applier.deactivate(profile, &m.state);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Spawns a background thread that polls forever at
+    /// [`POLL_INTERVAL`] until `stop` is set. `profiles_source` is
+    /// re-read every cycle so profile edits take effect without
+    /// restarting the watcher.
+    pub fn spawn(
+        self: Arc<Self>,
+        profiles_source: impl Fn() -> Vec<GamingProfile> + Send + 'static,
+        stop: Arc<AtomicBool>,
+    ) -> thread::JoinHandle<()>
+    where
+        S: 'static,
+    {
+        thread::spawn(move || {
+            let mut active: HashMap<String, ActiveMatch> = HashMap::new();
+            while !stop.load(Ordering::Relaxed) {
+                let profiles = profiles_source();
+                self.poll_once(&profiles, &mut active);
+                thread::sleep(POLL_INTERVAL);
+            }
+        })
+    }
+}
+
+impl Default for ProfileWatcher<SysinfoSnapshot> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
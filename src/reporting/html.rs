@@ -0,0 +1,83 @@
+//! Assembles [`Report`]'s fields into the standalone HTML document and
+//! writes it to disk.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{svg_chart, Report};
+
+#[derive(Debug)]
+pub enum ReportExportError {
+    Io(io::Error),
+}
+
+impl fmt::Display for ReportExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReportExportError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ReportExportError {}
+
+impl From<io::Error> for ReportExportError {
+    fn from(err: io::Error) -> Self {
+        ReportExportError::Io(err)
+    }
+}
+
+/// Escapes the handful of characters that matter in HTML text content;
+/// every string going into the document passes through this first,
+/// since summary values and chart titles come from user-controlled
+/// process/task names.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+pub(super) fn render(report: &Report) -> String {
+    let mut body = String::new();
+
+    body.push_str(&format!("<h1>{}</h1>\n", escape(&report.title)));
+
+    if !report.summary.is_empty() {
+        body.push_str("<table>\n");
+        for (label, value) in &report.summary {
+            body.push_str(&format!(
+                "<tr><th>{}</th><td>{}</td></tr>\n",
+                escape(label),
+                escape(value)
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    for chart in &report.charts {
+        body.push_str(&format!("<h2>{}</h2>\n", escape(&chart.title)));
+        match svg_chart::render(&chart.points) {
+            Some(svg) => body.push_str(&svg),
+            None => body.push_str("<p>(no data)</p>\n"),
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{}</title>\
+         <style>body{{font-family:sans-serif;margin:2rem}}table{{border-collapse:collapse}}\
+         th,td{{border:1px solid #ccc;padding:4px 12px;text-align:left}}</style>\
+         </head><body>\n{}</body></html>\n",
+        escape(&report.title),
+        body
+    )
+}
+
+pub(super) fn write_to_file(report: &Report, path: impl AsRef<Path>) -> Result<(), ReportExportError> {
+    let path: PathBuf = path.as_ref().to_path_buf();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, render(report))?;
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Renders a cleanup or monitoring result to a standalone HTML file —
+//! a title, a summary table, and zero or more inline SVG charts — for
+//! the Memory and Disk result panels' "Export report" buttons. No
+//! templating or charting crate: the whole file is a handful of
+//! self-contained `<svg>`/`<table>` tags, so the output needs nothing
+//! but a browser to open.
+//!
+//! PDF export isn't implemented yet — nothing in this tree renders PDF,
+//! and pulling in a dependency just for "Export report" wasn't judged
+//! worth it. [`Report::export_html`] is what the buttons call today.
+
+mod html;
+mod svg_chart;
+
+pub use html::ReportExportError;
+
+use std::path::Path;
+
+/// One `[-seconds_ago, value]` point, the same shape
+/// [`crate::system::sampler::StatsSampler`]'s plot points already use.
+pub type ChartPoint = [f64; 2];
+
+/// A chart embedded in the report as inline SVG.
+pub struct ReportChart {
+    pub title: String,
+    pub y_axis_label: String,
+    pub points: Vec<ChartPoint>,
+}
+
+/// Everything needed to render one report: a title, a handful of
+/// label/value summary rows, and zero or more charts.
+pub struct Report {
+    pub title: String,
+    pub summary: Vec<(String, String)>,
+    pub charts: Vec<ReportChart>,
+}
+
+impl Report {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), summary: Vec::new(), charts: Vec::new() }
+    }
+
+    pub fn with_summary_row(mut self, label: impl Into<String>, value: impl Into<String>) -> Self {
+        self.summary.push((label.into(), value.into()));
+        self
+    }
+
+    pub fn with_chart(mut self, title: impl Into<String>, y_axis_label: impl Into<String>, points: Vec<ChartPoint>) -> Self {
+        self.charts.push(ReportChart { title: title.into(), y_axis_label: y_axis_label.into(), points });
+        self
+    }
+
+    /// Renders this report to a self-contained HTML string.
+    pub fn to_html(&self) -> String {
+        html::render(self)
+    }
+
+    /// Renders and writes this report to `path` as a standalone HTML
+    /// file with no external references.
+    pub fn export_html(&self, path: impl AsRef<Path>) -> Result<(), ReportExportError> {
+        html::write_to_file(self, path)
+    }
+}
@@ -0,0 +1,48 @@
+//! Draws one [`ReportChart`](super::ReportChart) as a minimal inline
+//! `<svg>` polyline — just enough to show the shape of the data in an
+//! exported report, not a replacement for the live `egui_plot` charts.
+
+use super::ChartPoint;
+
+const WIDTH: f64 = 560.0;
+const HEIGHT: f64 = 160.0;
+const PADDING: f64 = 8.0;
+
+/// Renders `points` as an SVG line chart, scaled to fill the chart's
+/// fixed canvas. Returns `None` for an empty series rather than
+/// dividing by zero.
+pub(super) fn render(points: &[ChartPoint]) -> Option<String> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let xs = points.iter().map(|p| p[0]);
+    let ys = points.iter().map(|p| p[1]);
+    let (min_x, max_x) = min_max(xs)?;
+    let (min_y, max_y) = min_max(ys)?;
+    let x_span = (max_x - min_x).max(f64::EPSILON);
+    let y_span = (max_y - min_y).max(f64::EPSILON);
+
+    let to_svg = |[x, y]: ChartPoint| {
+        let sx = PADDING + (x - min_x) / x_span * (WIDTH - 2.0 * PADDING);
+        // SVG's y axis grows downward, so flip it.
+        let sy = HEIGHT - PADDING - (y - min_y) / y_span * (HEIGHT - 2.0 * PADDING);
+        format!("{sx:.1},{sy:.1}")
+    };
+
+    let path: String = points.iter().copied().map(to_svg).collect::<Vec<_>>().join(" ");
+
+    Some(format!(
+        "<svg viewBox=\"0 0 {WIDTH} {HEIGHT}\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+         xmlns=\"http://www.w3.org/2000/svg\">\
+         <polyline points=\"{path}\" fill=\"none\" stroke=\"#4285f4\" stroke-width=\"2\"/>\
+         </svg>"
+    ))
+}
+
+fn min_max(values: impl Iterator<Item = f64>) -> Option<(f64, f64)> {
+    values.fold(None, |acc, value| match acc {
+        None => Some((value, value)),
+        Some((min, max)) => Some((min.min(value), max.max(value))),
+    })
+}
@@ -0,0 +1,219 @@
+//! Versioned `.gbtasks` export/import format for sharing a set of
+//! scheduled tasks between machines — e.g. deploying one maintenance
+//! set to several PCs — mirroring [`crate::profiles::portable`]'s
+//! `.gbprofile` format for gaming profiles.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::task::ScheduledTask;
+
+/// Current format version written by this build. Bump whenever
+/// [`ScheduledTask`]'s shape changes in a way [`migrate`] needs to
+/// handle.
+pub const CURRENT_FORMAT_VERSION: u32 = 1;
+
+/// The on-disk `.gbtasks` envelope: a format version plus one or more
+/// tasks, so "export one task" and "export my whole maintenance set"
+/// round-trip through the same file shape.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskBundle {
+    pub format_version: u32,
+    pub tasks: Vec<ScheduledTask>,
+}
+
+#[derive(Debug)]
+pub enum TaskBundleError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for TaskBundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskBundleError::Io(err) => write!(f, "io error: {err}"),
+            TaskBundleError::Parse(err) => write!(f, "invalid .gbtasks file: {err}"),
+            TaskBundleError::UnsupportedVersion(v) => {
+                write!(f, "'.gbtasks' format version {v} is newer than this app supports")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TaskBundleError {}
+
+impl From<std::io::Error> for TaskBundleError {
+    fn from(err: std::io::Error) -> Self {
+        TaskBundleError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for TaskBundleError {
+    fn from(err: serde_json::Error) -> Self {
+        TaskBundleError::Parse(err)
+    }
+}
+
+impl TaskBundle {
+    pub fn new(tasks: Vec<ScheduledTask>) -> Self {
+        Self { format_version: CURRENT_FORMAT_VERSION, tasks }
+    }
+
+    /// Writes this bundle to a `.gbtasks` file as pretty-printed JSON.
+    /// Run/success counters and last-run state are exported as-is; see
+    /// [`resolve_imports`], which strips them back out on the receiving
+    /// end since they describe the exporting machine, not the task.
+    pub fn export_to_file(&self, path: &Path) -> Result<(), TaskBundleError> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Reads a `.gbtasks` file, migrating it to the current format
+    /// version first if it was exported by an older build.
+    pub fn import_from_file(path: &Path) -> Result<Self, TaskBundleError> {
+        let data = fs::read_to_string(path)?;
+        let raw: serde_json::Value = serde_json::from_str(&data)?;
+        let from_version = raw.get("format_version").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+        let migrated = migrate(raw, from_version)?;
+        let bundle: TaskBundle = serde_json::from_value(migrated)?;
+        Ok(bundle)
+    }
+}
+
+/// Upgrades a parsed `.gbtasks` JSON value from `from_version` to
+/// [`CURRENT_FORMAT_VERSION`]. There's only one version today, so this
+/// is a no-op beyond the compatibility check, but it gives future
+/// format changes somewhere to live without breaking old exports.
+fn migrate(value: serde_json::Value, from_version: u32) -> Result<serde_json::Value, TaskBundleError> {
+    if from_version > CURRENT_FORMAT_VERSION {
+        return Err(TaskBundleError::UnsupportedVersion(from_version));
+    }
+    Ok(value)
+}
+
+/// Merges `imported` into `existing` for the task dialog's "Import
+/// bundle" action: a task that's an exact duplicate of one already
+/// present (same name, type, and schedule) is dropped; a task whose
+/// name collides with an existing one but otherwise differs is renamed
+/// to a fresh, non-colliding name instead of silently overwriting it.
+/// Every imported task has its run/success counters, last error, and
+/// last-run timestamp reset, since those describe a specific machine's
+/// execution history, not the task definition being deployed.
+pub fn resolve_imports(existing: &[ScheduledTask], imported: Vec<ScheduledTask>) -> Vec<ScheduledTask> {
+    let mut names: std::collections::HashSet<String> = existing.iter().map(|task| task.name.clone()).collect();
+    let mut resolved = Vec::new();
+
+    for mut task in imported {
+        let is_duplicate = existing
+            .iter()
+            .any(|other| other.name == task.name && other.task_type == task.task_type && other.rule == task.rule);
+        if is_duplicate {
+            continue;
+        }
+
+        if names.contains(&task.name) {
+            task.name = unique_name(&task.name, &names);
+        }
+        names.insert(task.name.clone());
+
+        task.run_count = 0;
+        task.success_count = 0;
+        task.last_error = None;
+        task.last_run_at = None;
+        resolved.push(task);
+    }
+    resolved
+}
+
+/// Appends " (2)", " (3)", ... to `base` until the result isn't in
+/// `taken`.
+fn unique_name(base: &str, taken: &std::collections::HashSet<String>) -> String {
+    for suffix in 2.. {
+        let candidate = format!("{base} ({suffix})");
+        if !taken.contains(&candidate) {
+            return candidate;
+        }
+    }
+    unreachable!("HashSet of finite names can't reject every candidate")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::rule::ScheduleRule;
+    use super::super::task::{ScheduledTask, TaskType};
+
+    fn task(name: &str) -> ScheduledTask {
+        ScheduledTask::new(name, TaskType::CleanRam, ScheduleRule::Interval { period_secs: 60, window: None })
+    }
+
+    #[test]
+    fn migrate_rejects_a_future_format_version() {
+        let value = serde_json::json!({ "format_version": CURRENT_FORMAT_VERSION + 1, "tasks": [] });
+        assert!(matches!(migrate(value, CURRENT_FORMAT_VERSION + 1), Err(TaskBundleError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn migrate_accepts_the_current_version_unchanged() {
+        let value = serde_json::json!({ "format_version": CURRENT_FORMAT_VERSION, "tasks": [] });
+        assert_eq!(migrate(value.clone(), CURRENT_FORMAT_VERSION).unwrap(), value);
+    }
+
+    #[test]
+    fn resolve_imports_drops_exact_duplicates() {
+        let existing = vec![task("daily-cleanup")];
+        let resolved = resolve_imports(&existing, vec![task("daily-cleanup")]);
+        assert!(resolved.is_empty());
+    }
+
+    #[test]
+    fn resolve_imports_renames_a_colliding_but_different_task() {
+        let existing = vec![task("daily-cleanup")];
+        let mut imported = task("daily-cleanup");
+        imported.rule = ScheduleRule::Daily { hour: 3, minute: 0, jitter_minutes: None };
+
+        let resolved = resolve_imports(&existing, vec![imported]);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].name, "daily-cleanup (2)");
+    }
+
+    #[test]
+    fn resolve_imports_resets_run_history() {
+        let mut imported = task("fresh-import");
+        imported.run_count = 5;
+        imported.success_count = 4;
+        imported.last_error = Some("boom".to_string());
+        imported.last_run_at = Some(std::time::SystemTime::now());
+
+        let resolved = resolve_imports(&[], vec![imported]);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].run_count, 0);
+        assert_eq!(resolved[0].success_count, 0);
+        assert_eq!(resolved[0].last_error, None);
+        assert_eq!(resolved[0].last_run_at, None);
+    }
+
+    #[test]
+    fn unique_name_picks_the_first_free_suffix() {
+        let taken: std::collections::HashSet<String> = ["task (2)".to_string(), "task (3)".to_string()].into_iter().collect();
+        assert_eq!(unique_name("task", &taken), "task (4)");
+    }
+
+    #[test]
+    fn export_then_import_round_trips_a_bundle() {
+        let path = std::env::temp_dir().join(format!("gamebooster_task_bundle_test_{}.json", std::process::id()));
+        let bundle = TaskBundle::new(vec![task("a"), task("b")]);
+
+        bundle.export_to_file(&path).unwrap();
+        let imported = TaskBundle::import_from_file(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(imported.format_version, CURRENT_FORMAT_VERSION);
+        assert_eq!(imported.tasks, bundle.tasks);
+    }
+}
@@ -0,0 +1,175 @@
+//! Checks whether a task is actually worth running right now — i.e.
+//! whether do-not-disturb currently suppresses it (see
+//! [`super::dnd::should_suppress`]), whether its
+//! [`super::expr::ConditionExpr`] evaluates true, and whether the user
+//! has been idle long enough and the system is on AC power — without
+//! forcing an expensive check (a disk scan behind a `FreeableDiskBytes`
+//! metric) on every scheduler tick. `check_task_condition` used to scan
+//! on every call (every 30s in the scheduler's poll loop), which is
+//! brutal on HDDs; this caches the expression's result with a TTL and
+//! can evaluate it off the calling thread at idle IO priority, via a
+//! [`BackgroundWorker`] shared across every task instead of a fresh
+//! thread per scan.
+//! The cheap do-not-disturb/idle/power-source checks are never cached —
+//! they're re-read every call so a task backs off the instant the user
+//! stops being idle or a suppressing game exits.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::cpu::{CpuLimiter, IoPriority};
+use crate::profiles::profile::GamingProfile;
+use crate::profiles::watcher::SysinfoSnapshot;
+use crate::system::idle::IdleMonitor;
+use crate::system::power_source::{PowerSource, PowerSourceMonitor};
+use crate::system::sampler::StatsSampler;
+
+use super::dnd::{should_suppress, DoNotDisturbSettings};
+use super::expr::ConditionExpr;
+use super::task::ScheduledTask;
+use super::worker::BackgroundWorker;
+
+/// Default TTL, matching the 30s interval the condition check used to
+/// hardcode before it became configurable.
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+struct CachedResult {
+    checked_at: Instant,
+    condition_met: bool,
+}
+
+/// Caches each task's last condition-check result so polling the
+/// Scheduler tab doesn't trigger a fresh disk scan on every frame.
+pub struct ConditionCheckCache {
+    ttl: Duration,
+    results: Mutex<HashMap<String, CachedResult>>,
+    worker: BackgroundWorker,
+    /// The app's shared [`StatsSampler`], if one is running, so
+    /// `RamUsedPercent` conditions reuse its last sample instead of
+    /// opening a fresh handle on every check.
+    sampler: Option<StatsSampler>,
+    /// The do-not-disturb settings and gaming profiles to suppress
+    /// every task's condition against, if do-not-disturb is wired up
+    /// for this cache. `None` means no do-not-disturb suppression —
+    /// e.g. the CLI's one-off task run, which has no profile list to
+    /// check against.
+    dnd: Option<(DoNotDisturbSettings, Vec<GamingProfile>)>,
+}
+
+impl ConditionCheckCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self::with_sampler(ttl, None)
+    }
+
+    pub fn with_sampler(ttl: Duration, sampler: Option<StatsSampler>) -> Self {
+        Self::with_dnd(ttl, sampler, None)
+    }
+
+    pub fn with_dnd(ttl: Duration, sampler: Option<StatsSampler>, dnd: Option<(DoNotDisturbSettings, Vec<GamingProfile>)>) -> Self {
+        Self { ttl, results: Mutex::new(HashMap::new()), worker: BackgroundWorker::new(), sampler, dnd }
+    }
+
+    /// Returns whether `task`'s condition is currently met, scanning on
+    /// the calling thread if the cached result (if any) is older than
+    /// the TTL. Prefer [`ConditionCheckCache::check_async`] for a
+    /// `CleanDisk` task on a large volume, where a scan can take a
+    /// while.
+    pub fn check(&self, task: &ScheduledTask) -> bool {
+        if self.suppressed_by_dnd() {
+            return false;
+        }
+        if !constraints_met(task) {
+            return false;
+        }
+        if let Some(cached) = self.cached(&task.name) {
+            return cached;
+        }
+        let condition_met = evaluate_condition(task, self.sampler.as_ref());
+        self.store(&task.name, condition_met);
+        condition_met
+    }
+
+    /// Same intent as [`ConditionCheckCache::check`], but if there's no
+    /// fresh cached result, submits the scan to the cache's shared
+    /// [`BackgroundWorker`] at idle IO priority and returns `None`
+    /// immediately rather than blocking the caller. The next call after
+    /// the background scan finishes will see it cached.
+    pub fn check_async(self: &Arc<Self>, task: ScheduledTask) -> Option<bool> {
+        if self.suppressed_by_dnd() {
+            return Some(false);
+        }
+        if !constraints_met(&task) {
+            return Some(false);
+        }
+        if let Some(cached) = self.cached(&task.name) {
+            return Some(cached);
+        }
+        let cache = Arc::clone(self);
+        let sampler = self.sampler.clone();
+        self.worker.submit(move || {
+            let cpu = CpuLimiter::new();
+            let _ = cpu.set_io_priority(std::process::id(), IoPriority::Idle);
+            let condition_met = evaluate_condition(&task, sampler.as_ref());
+            cache.store(&task.name, condition_met);
+        });
+        None
+    }
+
+    /// Whether do-not-disturb currently suppresses every task's
+    /// condition, per [`super::dnd::should_suppress`]. Always `false`
+    /// if this cache has no do-not-disturb context configured.
+    fn suppressed_by_dnd(&self) -> bool {
+        match &self.dnd {
+            Some((settings, profiles)) => should_suppress(settings, profiles, &SysinfoSnapshot),
+            None => false,
+        }
+    }
+
+    fn cached(&self, task_name: &str) -> Option<bool> {
+        let results = self.results.lock().unwrap();
+        let cached = results.get(task_name)?;
+        (cached.checked_at.elapsed() < self.ttl).then_some(cached.condition_met)
+    }
+
+    fn store(&self, task_name: &str, condition_met: bool) {
+        self.results
+            .lock()
+            .unwrap()
+            .insert(task_name.to_string(), CachedResult { checked_at: Instant::now(), condition_met });
+    }
+}
+
+/// Evaluates a task's [`super::expr::ConditionExpr`], if it has one.
+/// No condition means unconditional.
+fn evaluate_condition(task: &ScheduledTask, sampler: Option<&StatsSampler>) -> bool {
+    task.condition.as_ref().map(|condition| condition.evaluate(sampler)).unwrap_or(true)
+}
+
+/// Returns whether `task`'s [`super::task::TaskConstraints`] are
+/// currently satisfied. Best-effort: a platform that can't answer a
+/// particular check (e.g. idle detection isn't wired up on this OS yet)
+/// treats that constraint as unmet, rather than risking a heavy task
+/// running when it shouldn't.
+fn constraints_met(task: &ScheduledTask) -> bool {
+    let constraints = &task.constraints;
+    if let Some(min_idle_minutes) = constraints.min_idle_minutes {
+        match IdleMonitor::new().idle_duration() {
+            Ok(idle) if idle >= Duration::from_secs(min_idle_minutes as u64 * 60) => {}
+            _ => return false,
+        }
+    }
+    if constraints.require_ac_power {
+        match PowerSourceMonitor::new().current() {
+            Ok(PowerSource::Ac) => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Returns whether `task` is currently worth running, consulting
+/// `cache` instead of scanning unconditionally.
+pub fn check_task_condition(task: &ScheduledTask, cache: &ConditionCheckCache) -> bool {
+    cache.check(task)
+}
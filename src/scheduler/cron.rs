@@ -0,0 +1,283 @@
+//! Cron-expression parsing and "next run" computation backing
+//! [`crate::scheduler::ScheduleRule::Cron`]. Standard 5-field syntax
+//! (minute hour day-of-month month day-of-week), evaluated in UTC since
+//! there's no timezone database here to consult.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug)]
+pub enum CronError {
+    WrongFieldCount(usize),
+    InvalidField { field: &'static str, value: String },
+}
+
+impl fmt::Display for CronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CronError::WrongFieldCount(count) => {
+                write!(f, "expected 5 cron fields (minute hour dom month dow), got {count}")
+            }
+            CronError::InvalidField { field, value } => write!(f, "invalid {field} field: {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for CronError {}
+
+/// A parsed cron expression: the set of allowed values for each field,
+/// expanded up front so [`CronSchedule::next_after`] only has to do
+/// membership checks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: Vec<u32>,
+    hours: Vec<u32>,
+    doms: Vec<u32>,
+    months: Vec<u32>,
+    dows: Vec<u32>,
+}
+
+impl CronSchedule {
+    /// Parses a standard 5-field cron expression: minute (0-59), hour
+    /// (0-23), day-of-month (1-31), month (1-12), day-of-week (0-6, 0 =
+    /// Sunday). Each field accepts `*`, a single value, a `lo-hi` range,
+    /// a `/step` suffix, and comma-separated combinations of those, e.g.
+    /// `"0 22-23,0-6/2 * * 1-5"`.
+    pub fn parse(expr: &str) -> Result<Self, CronError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        let [minute, hour, dom, month, dow] = fields[..] else {
+            return Err(CronError::WrongFieldCount(fields.len()));
+        };
+        Ok(Self {
+            minutes: parse_field("minute", minute, 0, 59)?,
+            hours: parse_field("hour", hour, 0, 23)?,
+            doms: parse_field("day-of-month", dom, 1, 31)?,
+            months: parse_field("month", month, 1, 12)?,
+            dows: parse_field("day-of-week", dow, 0, 6)?,
+        })
+    }
+
+    /// Returns the next UTC instant at or after `from` matching this
+    /// schedule, searching minute by minute up to four years ahead
+    /// before giving up — long enough to cover even a `"0 0 29 2 *"`
+    /// (Feb 29-only) schedule, which only matches on leap years.
+    pub fn next_after(&self, from: SystemTime) -> Option<SystemTime> {
+        const SEARCH_LIMIT_MINUTES: i64 = 4 * 366 * 24 * 60;
+
+        let start_secs = from.duration_since(SystemTime::UNIX_EPOCH).ok()?.as_secs() as i64;
+        let mut minute_ts = start_secs.div_ceil(60) * 60;
+
+        for _ in 0..SEARCH_LIMIT_MINUTES {
+            let civil = CivilDateTime::from_unix_secs(minute_ts);
+            if self.minutes.contains(&civil.minute)
+                && self.hours.contains(&civil.hour)
+                && self.doms.contains(&civil.day)
+                && self.months.contains(&civil.month)
+                && self.dows.contains(&civil.weekday)
+            {
+                return Some(SystemTime::UNIX_EPOCH + Duration::from_secs(minute_ts as u64));
+            }
+            minute_ts += 60;
+        }
+        None
+    }
+}
+
+fn parse_field(name: &'static str, spec: &str, min: u32, max: u32) -> Result<Vec<u32>, CronError> {
+    let invalid = || CronError::InvalidField { field: name, value: spec.to_string() };
+
+    let mut values = Vec::new();
+    for part in spec.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => (range_part, step.parse::<u32>().map_err(|_| invalid())?),
+            None => (part, 1),
+        };
+        if step == 0 {
+            return Err(invalid());
+        }
+
+        let (lo, hi) = if range_part == "*" {
+            (min, max)
+        } else if let Some((lo, hi)) = range_part.split_once('-') {
+            (lo.parse::<u32>().map_err(|_| invalid())?, hi.parse::<u32>().map_err(|_| invalid())?)
+        } else {
+            let value = range_part.parse::<u32>().map_err(|_| invalid())?;
+            (value, value)
+        };
+        if lo < min || hi > max || lo > hi {
+            return Err(invalid());
+        }
+
+        let mut value = lo;
+        while value <= hi {
+            values.push(value);
+            value += step;
+        }
+    }
+
+    values.sort_unstable();
+    values.dedup();
+    if values.is_empty() {
+        return Err(invalid());
+    }
+    Ok(values)
+}
+
+/// A UTC calendar date/time broken out from a Unix timestamp.
+struct CivilDateTime {
+    month: u32,
+    day: u32,
+    /// 0 = Sunday, matching cron's day-of-week convention.
+    weekday: u32,
+    hour: u32,
+    minute: u32,
+}
+
+impl CivilDateTime {
+    fn from_unix_secs(secs: i64) -> Self {
+        let days = secs.div_euclid(86400);
+        let time_of_day = secs.rem_euclid(86400);
+        let (_year, month, day) = civil_from_days(days);
+        // 1970-01-01 was a Thursday (weekday 4 under the 0 = Sunday
+        // convention).
+        let weekday = (days.rem_euclid(7) + 4).rem_euclid(7) as u32;
+        Self { month, day, weekday, hour: (time_of_day / 3600) as u32, minute: (time_of_day / 60 % 60) as u32 }
+    }
+}
+
+/// Converts a day count since the Unix epoch into a `(year, month, day)`
+/// proleptic Gregorian date. Port of Howard Hinnant's widely used
+/// `civil_from_days` algorithm, chosen over pulling in a date/time crate
+/// for a calculation this self-contained.
+pub(crate) fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+/// Inverse of [`civil_from_days`]: converts a `(year, month, day)` civil
+/// date into a day count since the Unix epoch. Also Hinnant's algorithm.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp as u64 + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe as i64 - 719468
+}
+
+/// Number of days in `month` of `year`, accounting for leap years.
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 30,
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(year: i64, month: u32, day: u32, hour: u32, minute: u32) -> SystemTime {
+        let secs = days_from_civil(year, month, day) * 86400 + (hour as i64) * 3600 + (minute as i64) * 60;
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs as u64)
+    }
+
+    #[test]
+    fn parse_rejects_wrong_field_count() {
+        assert!(matches!(CronSchedule::parse("0 0 * *"), Err(CronError::WrongFieldCount(4))));
+    }
+
+    #[test]
+    fn parse_rejects_out_of_range_value() {
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("* 24 * * *").is_err());
+        assert!(CronSchedule::parse("* * * * 7").is_err());
+    }
+
+    #[test]
+    fn parse_expands_star_range_step_and_list() {
+        let schedule = CronSchedule::parse("0 22-23,0-6/2 * * 1-5").unwrap();
+        assert_eq!(schedule.minutes, vec![0]);
+        assert_eq!(schedule.hours, vec![0, 2, 4, 6, 22, 23]);
+        assert_eq!(schedule.doms, (1..=31).collect::<Vec<_>>());
+        assert_eq!(schedule.months, (1..=12).collect::<Vec<_>>());
+        assert_eq!(schedule.dows, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn next_after_same_minute_matches_immediately() {
+        let schedule = CronSchedule::parse("30 9 * * *").unwrap();
+        let now = ts(2026, 8, 10, 9, 30);
+        assert_eq!(schedule.next_after(now), Some(now));
+    }
+
+    #[test]
+    fn next_after_rolls_over_to_the_next_day() {
+        let schedule = CronSchedule::parse("0 0 * * *").unwrap();
+        let now = ts(2026, 8, 10, 23, 59);
+        assert_eq!(schedule.next_after(now), Some(ts(2026, 8, 11, 0, 0)));
+    }
+
+    #[test]
+    fn next_after_rolls_over_a_month_boundary() {
+        let schedule = CronSchedule::parse("0 0 1 * *").unwrap();
+        let now = ts(2026, 1, 15, 0, 0);
+        assert_eq!(schedule.next_after(now), Some(ts(2026, 2, 1, 0, 0)));
+    }
+
+    #[test]
+    fn next_after_finds_leap_day_only_on_a_leap_year() {
+        let schedule = CronSchedule::parse("0 0 29 2 *").unwrap();
+        let now = ts(2023, 3, 1, 0, 0);
+        assert_eq!(schedule.next_after(now), Some(ts(2024, 2, 29, 0, 0)));
+    }
+
+    #[test]
+    fn civil_from_days_and_days_from_civil_round_trip_across_epoch() {
+        for days in [-719468, -1, 0, 1, 18800, 100_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn civil_from_days_matches_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(19716), (2023, 12, 25));
+    }
+
+    #[test]
+    fn days_in_month_accounts_for_leap_years() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2026, 4), 30);
+    }
+
+    #[test]
+    fn is_leap_year_handles_century_exceptions() {
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(2023));
+    }
+}
@@ -0,0 +1,48 @@
+//! A minimal UTC calendar timestamp, for schedule rules that need an
+//! exact date (`Once`) or just a time of day (`Monthly`), without
+//! pulling in a full date/time crate.
+
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use super::cron::{days_from_civil, days_in_month};
+
+/// An exact UTC calendar date and time, to the minute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl DateTime {
+    /// Converts to a [`SystemTime`], or `None` if the date doesn't
+    /// exist (an out-of-range month, a day past the end of its month,
+    /// or an out-of-range hour/minute).
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        if !(1..=12).contains(&self.month) {
+            return None;
+        }
+        if self.day == 0 || u32::from(self.day) > days_in_month(i64::from(self.year), u32::from(self.month)) {
+            return None;
+        }
+        if self.hour > 23 || self.minute > 59 {
+            return None;
+        }
+        let days = days_from_civil(i64::from(self.year), u32::from(self.month), u32::from(self.day));
+        let secs = days * 86400 + i64::from(self.hour) * 3600 + i64::from(self.minute) * 60;
+        let secs = u64::try_from(secs).ok()?;
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+    }
+}
+
+/// An hour:minute pairing, for rules like `Monthly` that pin a
+/// day-of-month but don't need a full calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+}
@@ -0,0 +1,87 @@
+//! "Do Not Disturb": a global switch that suppresses scheduled task
+//! execution while a fullscreen game or any profile-matched executable
+//! is running, deferring tasks until it exits.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::profiles::profile::GamingProfile;
+use crate::profiles::watcher::ProcessSnapshot;
+
+/// The global scheduler setting toggling do-not-disturb suppression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoNotDisturbSettings {
+    pub enabled: bool,
+}
+
+impl Default for DoNotDisturbSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+#[derive(Debug)]
+pub enum DoNotDisturbError {
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for DoNotDisturbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoNotDisturbError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DoNotDisturbError {}
+
+/// Returns whether scheduled task execution should currently be
+/// suppressed: `settings.enabled` and either a fullscreen foreground
+/// app or a profile-matched executable is running. Checked by the
+/// scheduler's poll loop before consulting any individual task's
+/// condition, so a task's regular schedule just slides until the game
+/// exits rather than being skipped outright.
+pub fn should_suppress(settings: &DoNotDisturbSettings, profiles: &[GamingProfile], snapshot: &impl ProcessSnapshot) -> bool {
+    if !settings.enabled {
+        return false;
+    }
+    if foreground_app_is_fullscreen().unwrap_or(false) {
+        return true;
+    }
+    let running = snapshot.running_processes();
+    profiles
+        .iter()
+        .any(|profile| running.iter().any(|(_, name)| name.eq_ignore_ascii_case(&profile.target_process_name)))
+}
+
+/// Returns whether the current foreground window is a fullscreen
+/// application — i.e. a game running borderless or exclusive
+/// fullscreen, even one that doesn't match any configured profile.
+pub fn foreground_app_is_fullscreen() -> Result<bool, DoNotDisturbError> {
+    imp::foreground_app_is_fullscreen()
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::DoNotDisturbError;
+
+    pub(super) fn foreground_app_is_fullscreen() -> Result<bool, DoNotDisturbError> {
+        // Compares GetForegroundWindow's window rect (via GetWindowRect)
+        // against its monitor's rect from MonitorFromWindow +
+        // GetMonitorInfo; an exact match with no border means the window
+        // is borderless/exclusive fullscreen.
+        todo!("detect a fullscreen foreground window via User32 window/monitor APIs")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::DoNotDisturbError;
+
+    pub(super) fn foreground_app_is_fullscreen() -> Result<bool, DoNotDisturbError> {
+        Err(DoNotDisturbError::Unsupported(
+            "fullscreen foreground-window detection needs a platform windowing API, not implemented yet",
+        ))
+    }
+}
@@ -0,0 +1,87 @@
+//! Detects whether this process has administrator/root rights, and
+//! relaunches elevated (triggering a UAC prompt on Windows) for a task
+//! that needs more than it currently has.
+
+use std::fmt;
+use std::process::ExitStatus;
+
+#[derive(Debug)]
+pub enum ElevationError {
+    /// The user declined the UAC prompt, or there's no elevation
+    /// mechanism available on this platform.
+    Declined,
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ElevationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElevationError::Declined => write!(f, "elevation was declined or isn't available here"),
+            ElevationError::Io(err) => write!(f, "failed to relaunch elevated: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ElevationError {}
+
+impl From<std::io::Error> for ElevationError {
+    fn from(err: std::io::Error) -> Self {
+        ElevationError::Io(err)
+    }
+}
+
+/// Returns `true` if this process already has administrator/root
+/// rights.
+pub fn is_elevated() -> bool {
+    imp::is_elevated()
+}
+
+/// Relaunches `program` with `args` through a UAC-elevated helper
+/// process, blocking until it exits, for a task that needs elevation
+/// this process doesn't currently have.
+pub fn run_elevated(program: &str, args: &[String]) -> Result<ExitStatus, ElevationError> {
+    imp::run_elevated(program, args)
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::process::ExitStatus;
+
+    use super::ElevationError;
+
+    /// Checks the process token for `TokenElevation` via
+    /// `OpenProcessToken` + `GetTokenInformation`, the modern
+    /// replacement for the deprecated (and UAC-virtualization-fooled)
+    /// `IsUserAnAdmin`.
+    pub(super) fn is_elevated() -> bool {
+        todo!("OpenProcessToken(TOKEN_QUERY) then GetTokenInformation(TokenElevation)")
+    }
+
+    /// Relaunches `program` through `ShellExecuteExW` with `lpVerb`
+    /// set to `"runas"`, which triggers the UAC consent prompt, then
+    /// waits on the resulting process handle.
+    pub(super) fn run_elevated(_program: &str, _args: &[String]) -> Result<ExitStatus, ElevationError> {
+        todo!("ShellExecuteExW with lpVerb = \"runas\" and SEE_MASK_NOCLOSEPROCESS, then WaitForSingleObject on hProcess")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::process::ExitStatus;
+
+    use super::ElevationError;
+
+    pub(super) fn is_elevated() -> bool {
+        // SAFETY: geteuid() has no preconditions and just returns the
+        // calling process's effective user ID.
+        unsafe { libc::geteuid() == 0 }
+    }
+
+    /// There's no UAC-style interactive elevation prompt outside
+    /// Windows; the closest equivalents (`pkexec`, `sudo`) need a
+    /// terminal or polkit agent this app doesn't assume is present, so
+    /// elevation is simply unavailable here.
+    pub(super) fn run_elevated(_program: &str, _args: &[String]) -> Result<ExitStatus, ElevationError> {
+        Err(ElevationError::Declined)
+    }
+}
@@ -0,0 +1,244 @@
+//! Runs a [`TaskType`] to completion and reports what happened, for the
+//! scheduler's background thread to call when a task's rule fires.
+
+use std::fmt;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cleaning::DiskCleaner;
+use crate::cpu::CpuLimiter;
+use crate::network::NetworkLimiter;
+use crate::profiles::watcher::{ProcessSnapshot, SysinfoSnapshot};
+use crate::system::ram::RamCleaner;
+
+use super::elevation::{is_elevated, run_elevated};
+use super::task::TaskType;
+
+/// What running a [`TaskType`] produced, beyond plain success/failure:
+/// bytes freed for a disk/RAM clean, or captured output for a command.
+#[derive(Debug, Clone, Default)]
+pub struct TaskOutcome {
+    pub bytes_freed: u64,
+    pub output: Option<String>,
+}
+
+/// Runs `task_type` to completion and returns what it did, or an error
+/// describing why it failed.
+pub fn execute_task(task_type: &TaskType) -> Result<TaskOutcome, String> {
+    match task_type {
+        TaskType::CleanDisk { options } => {
+            let report = DiskCleaner::new().clean(options);
+            Ok(TaskOutcome { bytes_freed: report.bytes_freed, output: None })
+        }
+        TaskType::CleanRam => {
+            RamCleaner::new().clean().map_err(|err| err.to_string())?;
+            Ok(TaskOutcome::default())
+        }
+        TaskType::RunCommand { program, args, hidden, timeout, .. } => run_command(program, args, *hidden, *timeout),
+        TaskType::ApplyNetworkLimit { exe, down_kbps, up_kbps } => {
+            let pid = find_pid(exe)?;
+            NetworkLimiter::new().set_rate_limit(pid, *down_kbps, *up_kbps).map_err(|err| err.to_string())?;
+            Ok(TaskOutcome::default())
+        }
+        TaskType::SetProcessPriority { exe, priority } => {
+            let pid = find_pid(exe)?;
+            CpuLimiter::new().set_priority(pid, *priority).map_err(|err| err.to_string())?;
+            Ok(TaskOutcome::default())
+        }
+    }
+}
+
+/// Why a timed-out execution's outcome differs from [`execute_task`]'s
+/// plain `Result<TaskOutcome, String>`: a run that was aborted for
+/// taking too long is surfaced distinctly from a run that simply
+/// errored, so history and the Scheduler tab can tell them apart
+/// instead of lumping both under one generic failure message.
+#[derive(Debug)]
+pub enum TaskRunError {
+    TimedOut,
+    /// Needed administrator/elevated rights this process doesn't have,
+    /// and either there's no elevation helper for this task type or
+    /// the user declined the UAC prompt — the detail string says
+    /// which.
+    NotElevated(String),
+    Failed(String),
+}
+
+impl fmt::Display for TaskRunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskRunError::TimedOut => write!(f, "task timed out"),
+            TaskRunError::NotElevated(detail) => write!(f, "skipped: not elevated ({detail})"),
+            TaskRunError::Failed(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for TaskRunError {}
+
+/// Runs `task_type` like [`execute_task`], but gives up once
+/// `max_runtime` elapses instead of running to completion.
+/// `CleanDisk` is cancelled cooperatively through
+/// [`DiskCleaner::clean_with_cancel`]'s cancel flag; every other task
+/// type is simply abandoned on its worker thread, since nothing else in
+/// this codebase exposes a cancellation point yet. `max_runtime: None`
+/// behaves exactly like [`execute_task`].
+pub fn execute_task_with_timeout(
+    task_type: &TaskType,
+    max_runtime: Option<Duration>,
+) -> Result<TaskOutcome, TaskRunError> {
+    if task_type.requires_elevation() && !is_elevated() {
+        return run_task_elevated(task_type);
+    }
+
+    let Some(max_runtime) = max_runtime else {
+        return execute_task(task_type).map_err(TaskRunError::Failed);
+    };
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let thread_cancel = Arc::clone(&cancel);
+    let task_type = task_type.clone();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(execute_task_cancellable(&task_type, &thread_cancel));
+    });
+
+    match rx.recv_timeout(max_runtime) {
+        Ok(result) => result.map_err(TaskRunError::Failed),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            cancel.store(true, Ordering::Relaxed);
+            Err(TaskRunError::TimedOut)
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(TaskRunError::Failed("worker thread panicked".to_string())),
+    }
+}
+
+/// Relaunches `task_type` elevated via a UAC-prompted helper process,
+/// for the one task type that carries a program to relaunch
+/// ([`TaskType::RunCommand`]); any other task type needing elevation
+/// has no helper process to elevate, so it's reported as skipped
+/// rather than attempted unprivileged.
+fn run_task_elevated(task_type: &TaskType) -> Result<TaskOutcome, TaskRunError> {
+    let TaskType::RunCommand { program, args, .. } = task_type else {
+        return Err(TaskRunError::NotElevated("no elevation helper for this task type".to_string()));
+    };
+    match run_elevated(program, args) {
+        Ok(status) if status.success() => {
+            Ok(TaskOutcome { bytes_freed: 0, output: Some(format!("ran elevated, {status}")) })
+        }
+        Ok(status) => Err(TaskRunError::Failed(format!("{program} exited elevated with {status}"))),
+        Err(err) => Err(TaskRunError::NotElevated(err.to_string())),
+    }
+}
+
+/// Like [`execute_task`], but a `CleanDisk` task stops early once
+/// `cancel` is set instead of running its cleanup pass to completion.
+fn execute_task_cancellable(task_type: &TaskType, cancel: &AtomicBool) -> Result<TaskOutcome, String> {
+    match task_type {
+        TaskType::CleanDisk { options } => {
+            let report = DiskCleaner::new().clean_with_cancel(options, cancel);
+            Ok(TaskOutcome { bytes_freed: report.bytes_freed, output: None })
+        }
+        other => execute_task(other),
+    }
+}
+
+/// Reports what `task_type` would do without actually doing it, for
+/// the task dialog's "Test run now (dry run)" button. `CleanDisk`
+/// estimates the real bytes it would free; every other task type can
+/// only describe the action it would take, since actually running it
+/// is the only way to know whether e.g. a command would succeed.
+pub fn dry_run_task(task_type: &TaskType) -> TaskOutcome {
+    match task_type {
+        TaskType::CleanDisk { options } => {
+            let bytes = DiskCleaner::new().estimate_freeable_bytes(options);
+            TaskOutcome { bytes_freed: bytes, output: Some(format!("would free an estimated {bytes} bytes")) }
+        }
+        TaskType::CleanRam => TaskOutcome { bytes_freed: 0, output: Some("would clean RAM".to_string()) },
+        TaskType::RunCommand { program, args, .. } => {
+            TaskOutcome { bytes_freed: 0, output: Some(format!("would run: {program} {}", args.join(" "))) }
+        }
+        TaskType::ApplyNetworkLimit { exe, down_kbps, up_kbps } => TaskOutcome {
+            bytes_freed: 0,
+            output: Some(format!("would limit {exe} to {down_kbps} kbps down / {up_kbps} kbps up")),
+        },
+        TaskType::SetProcessPriority { exe, priority } => {
+            TaskOutcome { bytes_freed: 0, output: Some(format!("would set {exe} to {priority:?} priority")) }
+        }
+    }
+}
+
+/// Finds the PID of the running process named `exe`, case-insensitively.
+fn find_pid(exe: &str) -> Result<u32, String> {
+    SysinfoSnapshot
+        .running_processes()
+        .into_iter()
+        .find(|(_, name)| name.eq_ignore_ascii_case(exe))
+        .map(|(pid, _)| pid)
+        .ok_or_else(|| format!("no running process named {exe}"))
+}
+
+/// Spawns `program`, waits up to `timeout` polling for it to exit,
+/// killing and reporting a failure if it's still running after that,
+/// and otherwise returns its combined stdout/stderr and exit status.
+fn run_command(program: &str, args: &[String], hidden: bool, timeout: Duration) -> Result<TaskOutcome, String> {
+    let mut command = Command::new(program);
+    command.args(args).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if hidden {
+        imp::hide_console_window(&mut command);
+    }
+
+    let mut child = command.spawn().map_err(|err| format!("failed to start {program}: {err}"))?;
+    let started = Instant::now();
+
+    let status = loop {
+        match child.try_wait().map_err(|err| err.to_string())? {
+            Some(status) => break status,
+            None if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                return Err(format!("{program} timed out after {timeout:?}"));
+            }
+            None => thread::sleep(Duration::from_millis(50)),
+        }
+    };
+
+    let mut output = String::new();
+    if let Some(mut stdout) = child.stdout.take() {
+        let _ = stdout.read_to_string(&mut output);
+    }
+    if let Some(mut stderr) = child.stderr.take() {
+        let _ = stderr.read_to_string(&mut output);
+    }
+
+    if status.success() {
+        Ok(TaskOutcome { bytes_freed: 0, output: Some(output) })
+    } else {
+        Err(format!("{program} exited with {status}: {output}"))
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    /// `CREATE_NO_WINDOW`.
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    pub(super) fn hide_console_window(command: &mut Command) {
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use std::process::Command;
+
+    pub(super) fn hide_console_window(_command: &mut Command) {
+        // No console-window concept outside Windows.
+    }
+}
@@ -0,0 +1,164 @@
+//! A small boolean expression tree for per-task run conditions: leaf
+//! metric comparisons (free disk space, RAM usage, freeable disk bytes)
+//! combined into AND/OR groups, built through the Scheduler tab's
+//! condition builder instead of hardcoding one fixed precondition per
+//! task type.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cleaning::{CleanupOptions, DiskCleaner};
+use crate::system::disk_space::DiskSpaceMonitor;
+use crate::system::memory::MemoryMonitor;
+use crate::system::sampler::StatsSampler;
+
+/// Below this many freeable bytes, a `CleanDisk` task isn't worth
+/// running — the default condition new `CleanDisk` tasks get, editable
+/// or removable like any other [`ConditionExpr`].
+pub const MIN_FREEABLE_DISK_BYTES: u64 = 10 * 1024 * 1024;
+
+/// A single measurable fact about the live system a [`ConditionExpr`]
+/// leaf compares against a threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionMetric {
+    /// Free space on the volume containing `path`, in bytes.
+    FreeDiskSpaceBytes { path: PathBuf },
+    /// Percentage of physical RAM currently in use, 0-100.
+    RamUsedPercent,
+    /// What a `CleanDisk`-style dry run would free, in bytes.
+    FreeableDiskBytes { options: CleanupOptions },
+}
+
+impl ConditionMetric {
+    /// Reads the metric's current value, or `None` if the platform
+    /// can't answer right now. `sampler`, if given, lets
+    /// [`ConditionMetric::RamUsedPercent`] reuse the shared
+    /// [`StatsSampler`]'s last reading instead of opening a fresh
+    /// [`MemoryMonitor`] handle; falls back to that when there's no
+    /// sampler or it hasn't sampled yet.
+    fn read(&self, sampler: Option<&StatsSampler>) -> Option<u64> {
+        match self {
+            ConditionMetric::FreeDiskSpaceBytes { path } => DiskSpaceMonitor::new().free_bytes(path).ok(),
+            ConditionMetric::RamUsedPercent => sampler
+                .and_then(StatsSampler::latest_ram_percent)
+                .map(|percent| percent as u64)
+                .or_else(|| MemoryMonitor::new().used_percent().ok().map(u64::from)),
+            ConditionMetric::FreeableDiskBytes { options } => Some(DiskCleaner::new().estimate_freeable_bytes(options)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+/// A boolean expression over [`ConditionMetric`]s: a leaf comparison, or
+/// an AND/OR group of sub-expressions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConditionExpr {
+    Compare { metric: ConditionMetric, comparison: Comparison, threshold: u64 },
+    And(Vec<ConditionExpr>),
+    Or(Vec<ConditionExpr>),
+}
+
+impl ConditionExpr {
+    /// Evaluates the expression against the live system, consulting
+    /// `sampler` (the shared [`StatsSampler`], if the caller has one
+    /// running) for any metric that can read from it instead of
+    /// querying the OS directly. A metric that can't currently be read
+    /// counts as failing its comparison, the same conservative choice
+    /// [`super::condition::check_task_condition`] makes for idle/power
+    /// constraints — better to skip a run than risk one the user
+    /// couldn't actually verify was safe.
+    pub fn evaluate(&self, sampler: Option<&StatsSampler>) -> bool {
+        match self {
+            ConditionExpr::Compare { metric, comparison, threshold } => match metric.read(sampler) {
+                Some(value) => match comparison {
+                    Comparison::LessThan => value < *threshold,
+                    Comparison::GreaterThan => value > *threshold,
+                },
+                None => false,
+            },
+            ConditionExpr::And(exprs) => exprs.iter().all(|expr| expr.evaluate(sampler)),
+            ConditionExpr::Or(exprs) => exprs.iter().any(|expr| expr.evaluate(sampler)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A leaf that always reads `0`, via a `FreeableDiskBytes` metric
+    /// with no categories selected — deterministic on every platform,
+    /// unlike `FreeDiskSpaceBytes`/`RamUsedPercent`, which hit the real
+    /// OS.
+    fn always_zero(comparison: Comparison, threshold: u64) -> ConditionExpr {
+        ConditionExpr::Compare {
+            metric: ConditionMetric::FreeableDiskBytes { options: CleanupOptions::default() },
+            comparison,
+            threshold,
+        }
+    }
+
+    #[test]
+    fn compare_greater_than() {
+        assert!(!always_zero(Comparison::GreaterThan, 0).evaluate(None));
+        assert!(!always_zero(Comparison::GreaterThan, u64::MAX).evaluate(None));
+        assert!(!always_zero(Comparison::GreaterThan, 10).evaluate(None));
+    }
+
+    #[test]
+    fn compare_less_than() {
+        assert!(always_zero(Comparison::LessThan, 10).evaluate(None));
+        assert!(!always_zero(Comparison::LessThan, 0).evaluate(None));
+    }
+
+    #[test]
+    fn and_is_true_only_when_every_leaf_is_true() {
+        let all_true = ConditionExpr::And(vec![
+            always_zero(Comparison::LessThan, 10),
+            always_zero(Comparison::LessThan, 20),
+        ]);
+        assert!(all_true.evaluate(None));
+
+        let one_false = ConditionExpr::And(vec![
+            always_zero(Comparison::LessThan, 10),
+            always_zero(Comparison::GreaterThan, 10),
+        ]);
+        assert!(!one_false.evaluate(None));
+    }
+
+    #[test]
+    fn or_is_true_when_any_leaf_is_true() {
+        let one_true = ConditionExpr::Or(vec![
+            always_zero(Comparison::GreaterThan, 10),
+            always_zero(Comparison::LessThan, 10),
+        ]);
+        assert!(one_true.evaluate(None));
+
+        let all_false = ConditionExpr::Or(vec![
+            always_zero(Comparison::GreaterThan, 10),
+            always_zero(Comparison::GreaterThan, 20),
+        ]);
+        assert!(!all_false.evaluate(None));
+    }
+
+    #[test]
+    fn empty_and_is_vacuously_true_empty_or_is_vacuously_false() {
+        assert!(ConditionExpr::And(vec![]).evaluate(None));
+        assert!(!ConditionExpr::Or(vec![]).evaluate(None));
+    }
+
+    #[test]
+    fn nested_groups_compose() {
+        let expr = ConditionExpr::And(vec![
+            always_zero(Comparison::LessThan, 10),
+            ConditionExpr::Or(vec![always_zero(Comparison::GreaterThan, 10), always_zero(Comparison::LessThan, 5)]),
+        ]);
+        assert!(expr.evaluate(None));
+    }
+}
@@ -0,0 +1,227 @@
+//! A single, flat, round-trippable view-model for the Scheduler tab's
+//! add/edit task dialog, replacing what used to be a pile of separate
+//! `new_task_*`/`edit_task_*` fields on `CleanRamApp` duplicated between
+//! the "new" and "edit" code paths. Pairs with
+//! [`crate::ui::dialog::DialogState`] instead of each dialog inventing
+//! its own "is this new or existing" and "has it changed" tracking.
+
+use std::time::Duration;
+
+use super::cron::CronSchedule;
+use super::datetime::{DateTime, TimeOfDay};
+use super::rule::{ScheduleRule, TimeWindow};
+use super::task::{CatchUpPolicy, RetryPolicy, ScheduledTask, TaskConstraints, TaskType};
+use crate::cleaning::CleanupOptions;
+use crate::cpu::CpuPriority;
+use crate::notifications::NotificationOptions;
+
+/// Which [`ScheduleRule`] variant the form is currently editing, with
+/// every variant's fields flattened into plain, widget-friendly values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleForm {
+    Interval { period_secs: u64, window: Option<TimeWindow> },
+    Daily { hour: u8, minute: u8, jitter_minutes: Option<u32> },
+    Cron(String),
+    Once { at: DateTime },
+    Monthly { day: u8, time: TimeOfDay },
+    OnProcessExit { exe: String },
+    OnLogon,
+}
+
+/// Which [`TaskType`] variant the form is currently editing, with every
+/// variant's fields flattened into plain, widget-friendly values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskTypeForm {
+    CleanDisk { options: CleanupOptions },
+    CleanRam,
+    RunCommand { program: String, args: Vec<String>, hidden: bool, timeout_secs: u64, requires_elevation: bool },
+    ApplyNetworkLimit { exe: String, down_kbps: u32, up_kbps: u32 },
+    SetProcessPriority { exe: String, priority: CpuPriority },
+}
+
+/// One field that failed validation in [`TaskFormModel::validate`], for
+/// the dialog to render inline next to the offending widget instead of
+/// a single catch-all error banner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskFormError {
+    pub field: &'static str,
+    pub message: String,
+}
+
+/// The Scheduler tab's add/edit task dialog state, editable with plain
+/// egui widgets and converted to/from a real [`ScheduledTask`] only once
+/// it passes [`TaskFormModel::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskFormModel {
+    pub name: String,
+    pub task_type: TaskTypeForm,
+    pub rule: RuleForm,
+    pub retry_policy: RetryPolicy,
+    pub catch_up_policy: CatchUpPolicy,
+    pub constraints: TaskConstraints,
+    pub max_runtime_secs: Option<u64>,
+    pub notification_options: NotificationOptions,
+    pub group: Option<String>,
+}
+
+impl TaskFormModel {
+    /// An empty form for the "add task" dialog, defaulting to the
+    /// simplest task type and rule.
+    pub fn new_task() -> Self {
+        Self {
+            name: String::new(),
+            task_type: TaskTypeForm::CleanRam,
+            rule: RuleForm::Daily { hour: 3, minute: 0, jitter_minutes: None },
+            retry_policy: RetryPolicy::default(),
+            catch_up_policy: CatchUpPolicy::default(),
+            constraints: TaskConstraints::default(),
+            max_runtime_secs: None,
+            notification_options: NotificationOptions::default(),
+            group: None,
+        }
+    }
+
+    /// Flattens an existing [`ScheduledTask`] into form fields for the
+    /// "edit task" dialog.
+    pub fn from_task(task: &ScheduledTask) -> Self {
+        Self {
+            name: task.name.clone(),
+            task_type: match task.task_type.clone() {
+                TaskType::CleanDisk { options } => TaskTypeForm::CleanDisk { options },
+                TaskType::CleanRam => TaskTypeForm::CleanRam,
+                TaskType::RunCommand { program, args, hidden, timeout, requires_elevation } => {
+                    TaskTypeForm::RunCommand { program, args, hidden, timeout_secs: timeout.as_secs(), requires_elevation }
+                }
+                TaskType::ApplyNetworkLimit { exe, down_kbps, up_kbps } => TaskTypeForm::ApplyNetworkLimit { exe, down_kbps, up_kbps },
+                TaskType::SetProcessPriority { exe, priority } => TaskTypeForm::SetProcessPriority { exe, priority },
+            },
+            rule: match task.rule.clone() {
+                ScheduleRule::Interval { period_secs, window } => RuleForm::Interval { period_secs, window },
+                ScheduleRule::Daily { hour, minute, jitter_minutes } => RuleForm::Daily { hour, minute, jitter_minutes },
+                ScheduleRule::Cron(expr) => RuleForm::Cron(expr),
+                ScheduleRule::Once { at } => RuleForm::Once { at },
+                ScheduleRule::Monthly { day, time } => RuleForm::Monthly { day, time },
+                ScheduleRule::OnProcessExit { exe } => RuleForm::OnProcessExit { exe },
+                ScheduleRule::OnLogon => RuleForm::OnLogon,
+            },
+            retry_policy: task.retry_policy,
+            catch_up_policy: task.catch_up_policy,
+            constraints: task.constraints,
+            max_runtime_secs: task.max_runtime.map(|d| d.as_secs()),
+            notification_options: task.notification_options.clone(),
+            group: task.group.clone(),
+        }
+    }
+
+    /// Checks every field the dialog can't enforce through the widget
+    /// itself (an empty name, an out-of-range hour, an unparseable cron
+    /// expression), so the dialog can show each problem next to the
+    /// field that caused it instead of failing silently on Save.
+    pub fn validate(&self) -> Vec<TaskFormError> {
+        let mut errors = Vec::new();
+
+        if self.name.trim().is_empty() {
+            errors.push(TaskFormError { field: "name", message: "Task name can't be empty".to_string() });
+        }
+
+        match &self.rule {
+            RuleForm::Daily { hour, minute, .. } => {
+                if *hour > 23 {
+                    errors.push(TaskFormError { field: "rule.hour", message: "Hour must be 0-23".to_string() });
+                }
+                if *minute > 59 {
+                    errors.push(TaskFormError { field: "rule.minute", message: "Minute must be 0-59".to_string() });
+                }
+            }
+            RuleForm::Monthly { day, time } => {
+                if !(1..=31).contains(day) {
+                    errors.push(TaskFormError { field: "rule.day", message: "Day must be 1-31".to_string() });
+                }
+                if time.hour > 23 {
+                    errors.push(TaskFormError { field: "rule.hour", message: "Hour must be 0-23".to_string() });
+                }
+                if time.minute > 59 {
+                    errors.push(TaskFormError { field: "rule.minute", message: "Minute must be 0-59".to_string() });
+                }
+            }
+            RuleForm::Cron(expr) => {
+                if let Err(err) = CronSchedule::parse(expr) {
+                    errors.push(TaskFormError { field: "rule.cron", message: err.to_string() });
+                }
+            }
+            RuleForm::Once { at } => {
+                if at.to_system_time().is_none() {
+                    errors.push(TaskFormError { field: "rule.at", message: "Not a valid date and time".to_string() });
+                }
+            }
+            RuleForm::OnProcessExit { exe } => {
+                if exe.trim().is_empty() {
+                    errors.push(TaskFormError { field: "rule.exe", message: "Process name can't be empty".to_string() });
+                }
+            }
+            RuleForm::Interval { .. } | RuleForm::OnLogon => {}
+        }
+
+        match &self.task_type {
+            TaskTypeForm::RunCommand { program, .. } => {
+                if program.trim().is_empty() {
+                    errors.push(TaskFormError { field: "task_type.program", message: "Program can't be empty".to_string() });
+                }
+            }
+            TaskTypeForm::ApplyNetworkLimit { exe, .. } | TaskTypeForm::SetProcessPriority { exe, .. } => {
+                if exe.trim().is_empty() {
+                    errors.push(TaskFormError { field: "task_type.exe", message: "Process name can't be empty".to_string() });
+                }
+            }
+            TaskTypeForm::CleanDisk { .. } | TaskTypeForm::CleanRam => {}
+        }
+
+        errors
+    }
+
+    /// Builds the real [`ScheduledTask`] this form describes, or the
+    /// validation errors blocking it. `existing` carries over whatever
+    /// the form itself doesn't edit (run counters, last error, list
+    /// position) when editing a task rather than creating one.
+    pub fn to_task(&self, existing: Option<&ScheduledTask>) -> Result<ScheduledTask, Vec<TaskFormError>> {
+        let errors = self.validate();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let task_type = match self.task_type.clone() {
+            TaskTypeForm::CleanDisk { options } => TaskType::CleanDisk { options },
+            TaskTypeForm::CleanRam => TaskType::CleanRam,
+            TaskTypeForm::RunCommand { program, args, hidden, timeout_secs, requires_elevation } => {
+                TaskType::RunCommand { program, args, hidden, timeout: Duration::from_secs(timeout_secs), requires_elevation }
+            }
+            TaskTypeForm::ApplyNetworkLimit { exe, down_kbps, up_kbps } => TaskType::ApplyNetworkLimit { exe, down_kbps, up_kbps },
+            TaskTypeForm::SetProcessPriority { exe, priority } => TaskType::SetProcessPriority { exe, priority },
+        };
+        let rule = match self.rule.clone() {
+            RuleForm::Interval { period_secs, window } => ScheduleRule::Interval { period_secs, window },
+            RuleForm::Daily { hour, minute, jitter_minutes } => ScheduleRule::Daily { hour, minute, jitter_minutes },
+            RuleForm::Cron(expr) => ScheduleRule::Cron(expr),
+            RuleForm::Once { at } => ScheduleRule::Once { at },
+            RuleForm::Monthly { day, time } => ScheduleRule::Monthly { day, time },
+            RuleForm::OnProcessExit { exe } => ScheduleRule::OnProcessExit { exe },
+            RuleForm::OnLogon => ScheduleRule::OnLogon,
+        };
+
+        let mut task = match existing {
+            Some(existing) => existing.clone(),
+            None => ScheduledTask::new(self.name.clone(), task_type.clone(), rule.clone()),
+        };
+        task.name = self.name.clone();
+        task.task_type = task_type;
+        task.rule = rule;
+        task.retry_policy = self.retry_policy;
+        task.catch_up_policy = self.catch_up_policy;
+        task.constraints = self.constraints;
+        task.max_runtime = self.max_runtime_secs.map(Duration::from_secs);
+        task.notification_options = self.notification_options.clone();
+        task.group = self.group.clone();
+
+        Ok(task)
+    }
+}
@@ -0,0 +1,127 @@
+//! Durable per-task execution history: every run's timestamp, duration,
+//! bytes freed, and error (if any), so the Scheduler tab's history pane
+//! survives a restart and can be filtered or exported to CSV.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+/// One execution of one [`super::task::ScheduledTask`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    pub task_name: String,
+    pub ran_at: SystemTime,
+    pub duration: Duration,
+    #[serde(default)]
+    pub bytes_freed: u64,
+    pub error: Option<String>,
+    /// `true` if this run was aborted for exceeding its task's
+    /// `max_runtime` rather than failing on its own, so the history
+    /// pane and CSV export can tell a timeout apart from a generic
+    /// error instead of lumping both under `error`.
+    #[serde(default)]
+    pub timed_out: bool,
+    /// 0-indexed retry attempt number, so a task's retries show up as
+    /// distinct rows instead of only the final `last_error` surviving.
+    #[serde(default)]
+    pub attempt: u32,
+    /// Captured stdout/stderr, for a `RunCommand` task.
+    #[serde(default)]
+    pub output: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum TaskHistoryError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for TaskHistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskHistoryError::Io(err) => write!(f, "io error: {err}"),
+            TaskHistoryError::Parse(err) => write!(f, "invalid task history: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TaskHistoryError {}
+
+impl From<std::io::Error> for TaskHistoryError {
+    fn from(err: std::io::Error) -> Self {
+        TaskHistoryError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for TaskHistoryError {
+    fn from(err: serde_json::Error) -> Self {
+        TaskHistoryError::Parse(err)
+    }
+}
+
+/// Persists [`ExecutionRecord`]s to a JSON file on disk, one file for
+/// every task's history combined.
+pub struct TaskHistoryLog {
+    path: PathBuf,
+}
+
+impl TaskHistoryLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Appends `record` and flushes the whole log to disk immediately.
+    pub fn append(&self, record: ExecutionRecord) -> Result<(), TaskHistoryError> {
+        let mut records = self.load_all()?;
+        records.push(record);
+        self.write_all(&records)
+    }
+
+    pub fn load_all(&self) -> Result<Vec<ExecutionRecord>, TaskHistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let data = fs::read_to_string(&self.path)?;
+        if data.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Returns every record for `task_name`, oldest first.
+    pub fn for_task(&self, task_name: &str) -> Result<Vec<ExecutionRecord>, TaskHistoryError> {
+        Ok(self.load_all()?.into_iter().filter(|record| record.task_name == task_name).collect())
+    }
+
+    fn write_all(&self, records: &[ExecutionRecord]) -> Result<(), TaskHistoryError> {
+        let json = serde_json::to_string_pretty(records)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+}
+
+/// Renders `records` as CSV for the history pane's export button: one
+/// row per execution, Unix seconds for the timestamp.
+pub fn to_csv(records: &[ExecutionRecord]) -> String {
+    let mut csv = String::from("task_name,ran_at_unix_secs,duration_secs,bytes_freed,attempt,timed_out,error,output\n");
+    for record in records {
+        let ran_at = record.ran_at.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs();
+        let error = record.error.as_deref().unwrap_or("").replace(',', ";");
+        let output = record.output.as_deref().unwrap_or("").replace(',', ";").replace('\n', " ");
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            record.task_name,
+            ran_at,
+            record.duration.as_secs_f64(),
+            record.bytes_freed,
+            record.attempt,
+            record.timed_out,
+            error,
+            output
+        ));
+    }
+    csv
+}
@@ -0,0 +1,37 @@
+//! Schedule rules describing when a recurring action should run, plus
+//! the flat [`TaskFormModel`] view-model the Scheduler tab's add/edit
+//! dialog edits instead of a scattered pile of per-field app state.
+
+mod bundle;
+mod condition;
+mod cron;
+mod datetime;
+mod dnd;
+mod elevation;
+mod executor;
+mod expr;
+mod form;
+pub mod history;
+mod rule;
+mod runner;
+mod store;
+mod task;
+mod trigger;
+mod worker;
+
+pub use bundle::{resolve_imports, TaskBundle, TaskBundleError, CURRENT_FORMAT_VERSION as TASK_BUNDLE_FORMAT_VERSION};
+pub use condition::{check_task_condition, ConditionCheckCache, DEFAULT_CHECK_INTERVAL};
+pub use cron::{CronError, CronSchedule};
+pub use datetime::{DateTime, TimeOfDay};
+pub use dnd::{foreground_app_is_fullscreen, should_suppress, DoNotDisturbError, DoNotDisturbSettings};
+pub use elevation::{is_elevated, run_elevated, ElevationError};
+pub use executor::{dry_run_task, execute_task, execute_task_with_timeout, TaskOutcome, TaskRunError};
+pub use expr::{Comparison, ConditionExpr, ConditionMetric, MIN_FREEABLE_DISK_BYTES};
+pub use form::{RuleForm, TaskFormError, TaskFormModel, TaskTypeForm};
+pub use history::{to_csv, ExecutionRecord, TaskHistoryError, TaskHistoryLog};
+pub use rule::{ScheduleRule, ScheduleRuleError, TimeWindow};
+pub use runner::{run_if_not_already_running, ConcurrencyLimits, RunningTasks};
+pub use store::{EngineStats, SchedulerStore, SchedulerStoreError};
+pub use task::{reorder_tasks, tasks_needing_catch_up, CatchUpPolicy, RetryPolicy, ScheduledTask, TaskConstraints, TaskType};
+pub use trigger::TriggerState;
+pub use worker::BackgroundWorker;
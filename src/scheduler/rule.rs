@@ -0,0 +1,371 @@
+//! When a scheduled action should fire. Starts small; new rule kinds
+//! are added here as the scheduler grows beyond profile activation.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use super::cron::{civil_from_days, days_from_civil, days_in_month, CronError, CronSchedule};
+use super::datetime::{DateTime, TimeOfDay};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScheduleRule {
+    /// Fires every `period_secs` seconds, counted from the instant passed
+    /// to [`ScheduleRule::next_run_after`] (e.g. the last run). If `window` is set, occurrences outside it are skipped
+    /// forward to the next one inside it, e.g. "every 30 minutes, but
+    /// only between 01:00 and 06:00".
+    Interval {
+        period_secs: u64,
+        #[serde(default)]
+        window: Option<TimeWindow>,
+    },
+    /// Fires once per day at `hour:minute`, UTC. If `jitter_minutes` is
+    /// set, each occurrence is offset by a random amount within
+    /// ±`jitter_minutes`, so a fleet of machines on the same daily
+    /// schedule (e.g. a LAN café) doesn't hit disk at the exact same
+    /// instant.
+    Daily {
+        hour: u8,
+        minute: u8,
+        #[serde(default)]
+        jitter_minutes: Option<u32>,
+    },
+    /// Fires according to a standard 5-field cron expression (minute
+    /// hour day-of-month month day-of-week), e.g. `"30 3 * * 1-5"` for
+    /// "every weekday at 03:30" or `"0 22,0-6/2 * * *"` for "every 2
+    /// hours from 22:00 through 06:00". See [`CronSchedule::parse`] for
+    /// the full accepted syntax.
+    Cron(String),
+    /// Fires exactly once, at `at`. Never fires again afterward.
+    Once { at: DateTime },
+    /// Fires once a month, on `day`, at `time`. `day` is clamped to the
+    /// last day of any shorter month — e.g. `day: 31` fires on the 28th
+    /// or 29th in February.
+    Monthly { day: u8, time: TimeOfDay },
+    /// Fires once, right after `exe` stops running, having been seen
+    /// running at least once since the scheduler started watching for
+    /// it — e.g. cleaning RAM the moment a game closes. This isn't a
+    /// computable future instant, so it's detected by polling the
+    /// process list (see `scheduler::trigger::TriggerState`) rather
+    /// than through [`ScheduleRule::next_run_after`], which always
+    /// returns `None` for it.
+    OnProcessExit { exe: String },
+    /// Fires once when this app (or its background service) starts,
+    /// approximating "on logon" for the common case of a per-user
+    /// background process. Detected the same way as
+    /// [`ScheduleRule::OnProcessExit`] — see `scheduler::trigger` — not
+    /// computable ahead of time either.
+    OnLogon,
+}
+
+/// Restricts a [`ScheduleRule::Interval`] to a daily time-of-day window,
+/// UTC. `end` before `start` wraps past midnight, e.g. `22:00`–`06:00`
+/// for an overnight-only window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub start: TimeOfDay,
+    pub end: TimeOfDay,
+}
+
+impl TimeWindow {
+    fn contains(&self, time: TimeOfDay) -> bool {
+        let minutes_of = |t: TimeOfDay| i32::from(t.hour) * 60 + i32::from(t.minute);
+        let (start, end, now) = (minutes_of(self.start), minutes_of(self.end), minutes_of(time));
+        if start <= end {
+            now >= start && now < end
+        } else {
+            now >= start || now < end
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ScheduleRuleError {
+    Cron(CronError),
+    /// A `Once` rule whose date doesn't exist.
+    InvalidDate,
+    /// A `Monthly` rule whose `day` is outside `1..=31`.
+    InvalidDayOfMonth(u8),
+}
+
+impl fmt::Display for ScheduleRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleRuleError::Cron(err) => write!(f, "{err}"),
+            ScheduleRuleError::InvalidDate => write!(f, "that date doesn't exist"),
+            ScheduleRuleError::InvalidDayOfMonth(day) => write!(f, "day {day} is outside 1-31"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleRuleError {}
+
+impl From<CronError> for ScheduleRuleError {
+    fn from(err: CronError) -> Self {
+        ScheduleRuleError::Cron(err)
+    }
+}
+
+impl ScheduleRule {
+    /// Validates the rule eagerly, so the task dialog can reject a bad
+    /// schedule before it's saved instead of silently never firing.
+    /// `Interval` and `Daily` are always valid.
+    pub fn validate(&self) -> Result<(), ScheduleRuleError> {
+        match self {
+            ScheduleRule::Interval { .. } | ScheduleRule::Daily { .. } => Ok(()),
+            ScheduleRule::OnProcessExit { .. } | ScheduleRule::OnLogon => Ok(()),
+            // A window with no daily instant inside it (e.g. `start ==
+            // end`) isn't a syntax error, just a schedule that never
+            // fires; `next_run_after`'s search limit handles that.
+            ScheduleRule::Cron(expr) => {
+                CronSchedule::parse(expr)?;
+                Ok(())
+            }
+            ScheduleRule::Once { at } => at.to_system_time().map(|_| ()).ok_or(ScheduleRuleError::InvalidDate),
+            ScheduleRule::Monthly { day, .. } => {
+                if (1..=31).contains(day) {
+                    Ok(())
+                } else {
+                    Err(ScheduleRuleError::InvalidDayOfMonth(*day))
+                }
+            }
+        }
+    }
+
+    /// Returns the next instant at or after `from` this rule would fire,
+    /// for the task dialog's next-run preview. Returns `None` if the
+    /// rule is malformed, or (for `Once`) already in the past.
+    pub fn next_run_after(&self, from: SystemTime) -> Option<SystemTime> {
+        match self {
+            ScheduleRule::Interval { period_secs, window } => interval_next_after(*period_secs, *window, from),
+            ScheduleRule::Daily { hour, minute, jitter_minutes } => {
+                // Reuses the cron evaluator's calendar math rather than
+                // duplicating it: "daily at hour:minute" is just the
+                // cron expression `"minute hour * * *"`.
+                let base = CronSchedule::parse(&format!("{minute} {hour} * * *")).ok()?.next_after(from)?;
+                Some(apply_jitter(base, *jitter_minutes, from))
+            }
+            ScheduleRule::Cron(expr) => CronSchedule::parse(expr).ok()?.next_after(from),
+            ScheduleRule::Once { at } => at.to_system_time().filter(|&when| when >= from),
+            ScheduleRule::Monthly { day, time } => monthly_next_after(*day, *time, from),
+            // Event-driven: see `scheduler::trigger::TriggerState`.
+            ScheduleRule::OnProcessExit { .. } | ScheduleRule::OnLogon => None,
+        }
+    }
+
+    /// Returns up to `n` consecutive occurrences at or after `from`,
+    /// for the task dialog's "next occurrences" preview. Stops early
+    /// if [`ScheduleRule::next_run_after`] ever returns `None` — an
+    /// event-driven rule, an already-fired `Once`, or a malformed
+    /// rule.
+    pub fn next_n_after(&self, from: SystemTime, n: usize) -> Vec<SystemTime> {
+        let mut occurrences = Vec::with_capacity(n);
+        let mut cursor = from;
+        for _ in 0..n {
+            match self.next_run_after(cursor) {
+                Some(next) => {
+                    cursor = next + Duration::from_secs(1);
+                    occurrences.push(next);
+                }
+                None => break,
+            }
+        }
+        occurrences
+    }
+}
+
+/// Steps forward by `period_secs` from `from` until landing inside
+/// `window` (or returns the very next occurrence unconditionally if
+/// there's no window), giving up after [`INTERVAL_SEARCH_LIMIT_STEPS`]
+/// steps in case `window` describes an empty or unreachable range.
+fn interval_next_after(period_secs: u64, window: Option<TimeWindow>, from: SystemTime) -> Option<SystemTime> {
+    const INTERVAL_SEARCH_LIMIT_STEPS: u32 = 10_000;
+
+    let mut candidate = from.checked_add(Duration::from_secs(period_secs))?;
+    let Some(window) = window else { return Some(candidate) };
+
+    for _ in 0..INTERVAL_SEARCH_LIMIT_STEPS {
+        if window.contains(time_of_day(candidate)) {
+            return Some(candidate);
+        }
+        candidate = candidate.checked_add(Duration::from_secs(period_secs))?;
+    }
+    None
+}
+
+/// Offsets `base` by a random amount within ±`jitter_minutes` (if set
+/// and nonzero), re-adding a day at a time if the jittered result would
+/// land before `from`, since [`ScheduleRule::next_run_after`] promises
+/// its result is never earlier than that.
+fn apply_jitter(base: SystemTime, jitter_minutes: Option<u32>, from: SystemTime) -> SystemTime {
+    let Some(jitter_minutes) = jitter_minutes.filter(|&minutes| minutes > 0) else { return base };
+    let offset_secs = random_jitter_secs(jitter_minutes);
+    let mut candidate = if offset_secs >= 0 {
+        base + Duration::from_secs(offset_secs as u64)
+    } else {
+        base.checked_sub(Duration::from_secs((-offset_secs) as u64)).unwrap_or(base)
+    };
+    while candidate < from {
+        candidate += Duration::from_secs(86400);
+    }
+    candidate
+}
+
+/// A pseudorandom offset in `[-max_minutes * 60, max_minutes * 60]`
+/// seconds. Keyed off [`std::collections::hash_map::RandomState`]'s
+/// per-process random seed rather than a dedicated RNG crate, which
+/// this app doesn't depend on — good enough for spreading out
+/// schedules, not for anything security-sensitive.
+fn random_jitter_secs(max_minutes: u32) -> i64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let bits = RandomState::new().build_hasher().finish();
+    let max_secs = i64::from(max_minutes) * 60;
+    (bits % (2 * max_secs as u64 + 1)) as i64 - max_secs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_window_contains_a_same_day_window() {
+        let window = TimeWindow { start: TimeOfDay { hour: 1, minute: 0 }, end: TimeOfDay { hour: 6, minute: 0 } };
+        assert!(window.contains(TimeOfDay { hour: 3, minute: 0 }));
+        assert!(!window.contains(TimeOfDay { hour: 0, minute: 0 }));
+        assert!(!window.contains(TimeOfDay { hour: 6, minute: 0 }));
+    }
+
+    #[test]
+    fn time_window_wraps_past_midnight() {
+        let window = TimeWindow { start: TimeOfDay { hour: 22, minute: 0 }, end: TimeOfDay { hour: 6, minute: 0 } };
+        assert!(window.contains(TimeOfDay { hour: 23, minute: 0 }));
+        assert!(window.contains(TimeOfDay { hour: 3, minute: 0 }));
+        assert!(!window.contains(TimeOfDay { hour: 12, minute: 0 }));
+    }
+
+    #[test]
+    fn interval_next_after_with_no_window_just_adds_the_period() {
+        let from = SystemTime::UNIX_EPOCH + Duration::from_secs(1000);
+        let next = interval_next_after(60, None, from).unwrap();
+        assert_eq!(next, from + Duration::from_secs(60));
+    }
+
+    #[test]
+    fn interval_next_after_skips_forward_into_the_window() {
+        // from == 00:00 UTC (a Unix-epoch day boundary); a 1-hour period
+        // with a 01:00-06:00 window should land on the first in-window
+        // occurrence, 01:00, not 00:00.
+        let from = SystemTime::UNIX_EPOCH;
+        let window = TimeWindow { start: TimeOfDay { hour: 1, minute: 0 }, end: TimeOfDay { hour: 6, minute: 0 } };
+        let next = interval_next_after(3600, Some(window), from).unwrap();
+        assert_eq!(next, from + Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn apply_jitter_with_no_jitter_returns_base_unchanged() {
+        let base = SystemTime::UNIX_EPOCH + Duration::from_secs(100_000);
+        assert_eq!(apply_jitter(base, None, base), base);
+        assert_eq!(apply_jitter(base, Some(0), base), base);
+    }
+
+    #[test]
+    fn apply_jitter_never_lands_before_from() {
+        let from = SystemTime::UNIX_EPOCH + Duration::from_secs(100_000);
+        for _ in 0..50 {
+            assert!(apply_jitter(from, Some(30), from) >= from);
+        }
+    }
+
+    #[test]
+    fn apply_jitter_stays_within_max_minutes_of_base_modulo_day_wraps() {
+        let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        for _ in 0..50 {
+            let jittered = apply_jitter(base, Some(15), base);
+            let delta = jittered.duration_since(base).unwrap_or_default().as_secs();
+            // Either within ±15 minutes of base, or pushed forward by
+            // whole days to stay at or after `from` per the doc comment.
+            assert!(delta % 86400 <= 15 * 60 || delta % 86400 >= 86400 - 15 * 60);
+        }
+    }
+
+    #[test]
+    fn random_jitter_secs_stays_within_bounds() {
+        for _ in 0..200 {
+            let secs = random_jitter_secs(10);
+            assert!((-600..=600).contains(&secs));
+        }
+    }
+
+    #[test]
+    fn daily_rule_next_run_after_lands_on_the_requested_time() {
+        let rule = ScheduleRule::Daily { hour: 9, minute: 30, jitter_minutes: None };
+        let from = SystemTime::UNIX_EPOCH;
+        let next = rule.next_run_after(from).unwrap();
+        assert_eq!(time_of_day(next), TimeOfDay { hour: 9, minute: 30 });
+    }
+
+    #[test]
+    fn monthly_rule_clamps_day_to_the_shorter_month() {
+        // From 2026-02-01, day 31 clamps to Feb's 28 days (2026 isn't a
+        // leap year).
+        let from = SystemTime::UNIX_EPOCH + Duration::from_secs(days_from_civil(2026, 2, 1) as u64 * 86400);
+        let next = monthly_next_after(31, TimeOfDay { hour: 0, minute: 0 }, from).unwrap();
+        let days = next.duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs() / 86400;
+        assert_eq!(civil_from_days(days as i64), (2026, 2, 28));
+    }
+
+    #[test]
+    fn validate_rejects_a_malformed_cron_expression() {
+        assert!(ScheduleRule::Cron("not a cron expression".to_string()).validate().is_err());
+        assert!(ScheduleRule::Cron("0 0 * * *".to_string()).validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_an_out_of_range_monthly_day() {
+        assert!(matches!(
+            ScheduleRule::Monthly { day: 32, time: TimeOfDay { hour: 0, minute: 0 } }.validate(),
+            Err(ScheduleRuleError::InvalidDayOfMonth(32))
+        ));
+    }
+
+    #[test]
+    fn next_n_after_stops_early_for_an_already_fired_once_rule() {
+        let rule = ScheduleRule::Once { at: DateTime { year: 2000, month: 1, day: 1, hour: 0, minute: 0 } };
+        let occurrences = rule.next_n_after(SystemTime::now(), 5);
+        assert!(occurrences.is_empty());
+    }
+}
+
+/// The UTC hour and minute a [`SystemTime`] falls on.
+fn time_of_day(when: SystemTime) -> TimeOfDay {
+    let secs_of_day = when.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs() % 86400;
+    TimeOfDay { hour: (secs_of_day / 3600) as u8, minute: ((secs_of_day % 3600) / 60) as u8 }
+}
+
+/// Searches forward month by month for the next occurrence of `day` (at
+/// `time`) at or after `from`, clamping `day` to each candidate month's
+/// length.
+fn monthly_next_after(day: u8, time: TimeOfDay, from: SystemTime) -> Option<SystemTime> {
+    const SEARCH_LIMIT_MONTHS: i64 = 24;
+
+    let start_secs = from.duration_since(SystemTime::UNIX_EPOCH).ok()?.as_secs() as i64;
+    let (year, month, _) = civil_from_days(start_secs.div_euclid(86400));
+
+    for offset in 0..SEARCH_LIMIT_MONTHS {
+        let total_months = year * 12 + i64::from(month) - 1 + offset;
+        let candidate_year = total_months.div_euclid(12);
+        let candidate_month = (total_months.rem_euclid(12) + 1) as u32;
+        let candidate_day = u32::from(day).min(days_in_month(candidate_year, candidate_month));
+
+        let candidate_secs = days_from_civil(candidate_year, candidate_month, candidate_day) * 86400
+            + i64::from(time.hour) * 3600
+            + i64::from(time.minute) * 60;
+        if candidate_secs >= start_secs {
+            return Some(SystemTime::UNIX_EPOCH + Duration::from_secs(candidate_secs as u64));
+        }
+    }
+    None
+}
@@ -0,0 +1,146 @@
+//! Tracks which scheduled tasks are currently executing, so the engine
+//! can refuse to start a task that's still running and cap how many
+//! tasks run at once across the whole schedule.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::notifications::{notify, NotificationSettings, RunSummary};
+
+use super::executor::{execute_task_with_timeout, TaskOutcome, TaskRunError};
+use super::history::{ExecutionRecord, TaskHistoryLog};
+use super::task::ScheduledTask;
+
+/// Global cap on how many tasks may execute concurrently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConcurrencyLimits {
+    pub max_concurrent_tasks: usize,
+}
+
+impl Default for ConcurrencyLimits {
+    fn default() -> Self {
+        Self { max_concurrent_tasks: 1 }
+    }
+}
+
+/// Tracks which tasks are currently running, for the engine's
+/// overlap/concurrency checks and the Scheduler tab's live status
+/// column. Shared across the scheduler's background thread and the UI
+/// thread behind an `Arc`.
+#[derive(Default)]
+pub struct RunningTasks {
+    names: Mutex<HashSet<String>>,
+}
+
+impl RunningTasks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if `task_name` is currently running.
+    pub fn is_running(&self, task_name: &str) -> bool {
+        self.names.lock().unwrap().contains(task_name)
+    }
+
+    /// How many tasks are currently running, checked against
+    /// [`ConcurrencyLimits::max_concurrent_tasks`].
+    pub fn running_count(&self) -> usize {
+        self.names.lock().unwrap().len()
+    }
+
+    /// Marks `task_name` running and returns `true`, unless it's
+    /// already running or doing so would exceed
+    /// `limits.max_concurrent_tasks`. Pairs with
+    /// [`RunningTasks::finish`].
+    pub fn try_start(&self, task_name: &str, limits: &ConcurrencyLimits) -> bool {
+        let mut names = self.names.lock().unwrap();
+        if names.contains(task_name) || names.len() >= limits.max_concurrent_tasks {
+            return false;
+        }
+        names.insert(task_name.to_string());
+        true
+    }
+
+    /// Marks `task_name` no longer running.
+    pub fn finish(&self, task_name: &str) {
+        self.names.lock().unwrap().remove(task_name);
+    }
+
+    /// Every task name currently running, for the Scheduler tab's live
+    /// status column.
+    pub fn snapshot(&self) -> Vec<String> {
+        self.names.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// Runs `task` via [`execute_task_with_timeout`] unless it's already
+/// running or starting it would exceed `limits`, retrying per
+/// `task.retry_policy` on failure the same way
+/// [`ScheduledTask::run_with_retry`] describes, tracking the attempt in
+/// `running` for its duration, appending an [`ExecutionRecord`] per
+/// attempt to `history` (if given), and notifying the user per
+/// `task.notification_options` once the last attempt finishes, unless
+/// `notification_settings` has the global mute switch on. Returns
+/// `None` if the task was skipped for overlapping or being over the
+/// concurrency cap, rather than `Some(Err(..))`, since skipping isn't a
+/// failed execution.
+pub fn run_if_not_already_running(
+    task: &mut ScheduledTask,
+    notification_settings: &NotificationSettings,
+    running: &RunningTasks,
+    limits: &ConcurrencyLimits,
+    history: Option<&TaskHistoryLog>,
+) -> Option<Result<TaskOutcome, TaskRunError>> {
+    if !running.try_start(&task.name, limits) {
+        return None;
+    }
+
+    let task_type = task.task_type.clone();
+    let max_runtime = task.max_runtime;
+    let mut attempts: Vec<(Result<TaskOutcome, TaskRunError>, Duration)> = Vec::new();
+    let started = Instant::now();
+    task.run_with_retry(|| {
+        let attempt_started = Instant::now();
+        let result = execute_task_with_timeout(&task_type, max_runtime);
+        let mapped = result.as_ref().map(|_| ()).map_err(ToString::to_string);
+        attempts.push((result, attempt_started.elapsed()));
+        mapped
+    });
+    running.finish(&task.name);
+    task.record_run_at(SystemTime::now());
+
+    if let Some(history) = history {
+        for (attempt, (result, duration)) in attempts.iter().enumerate() {
+            let record = ExecutionRecord {
+                task_name: task.name.clone(),
+                ran_at: SystemTime::now(),
+                duration: *duration,
+                bytes_freed: result.as_ref().map(|outcome| outcome.bytes_freed).unwrap_or(0),
+                error: result.as_ref().err().map(|err| err.to_string()),
+                timed_out: matches!(result, Err(TaskRunError::TimedOut)),
+                attempt: attempt as u32,
+                output: result.as_ref().ok().and_then(|outcome| outcome.output.clone()),
+            };
+            let _ = history.append(record);
+        }
+    }
+
+    let (result, _) = attempts.pop()?;
+
+    notify(
+        notification_settings,
+        &task.notification_options,
+        &RunSummary {
+            task_name: task.name.clone(),
+            success: result.is_ok(),
+            duration: started.elapsed(),
+            bytes_freed: result.as_ref().map(|outcome| outcome.bytes_freed).unwrap_or(0),
+            error: result.as_ref().err().map(|err| err.to_string()),
+        },
+    );
+
+    Some(result)
+}
@@ -0,0 +1,145 @@
+//! Loads and saves the scheduler's full state — its task list, whether
+//! the engine was running, and accumulated uptime — as
+//! `scheduled_tasks.json`, so restarting the app resumes exactly where
+//! it left off instead of defaulting to stopped. Mirrors
+//! [`crate::profiles::manager::ProfileManager`]'s load/save-on-mutation
+//! shape for the equivalent per-task runtime stats (run/success
+//! counters, last error) [`ScheduledTask`] already tracks itself.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::task::ScheduledTask;
+
+#[derive(Debug)]
+pub enum SchedulerStoreError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for SchedulerStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulerStoreError::Io(err) => write!(f, "io error: {err}"),
+            SchedulerStoreError::Parse(err) => write!(f, "invalid scheduled_tasks.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerStoreError {}
+
+impl From<std::io::Error> for SchedulerStoreError {
+    fn from(err: std::io::Error) -> Self {
+        SchedulerStoreError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SchedulerStoreError {
+    fn from(err: serde_json::Error) -> Self {
+        SchedulerStoreError::Parse(err)
+    }
+}
+
+/// The engine-level state persisted alongside the task list: whether
+/// the user had the scheduler running, and how long it's run in total
+/// across every session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EngineStats {
+    #[serde(default)]
+    pub running: bool,
+    /// Accumulated across restarts, since an in-memory "started at"
+    /// instant doesn't survive one; callers add the elapsed time of
+    /// each run via [`SchedulerStore::add_uptime`] before stopping or
+    /// exiting.
+    #[serde(default)]
+    pub total_uptime: Duration,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PersistedState {
+    #[serde(default)]
+    engine: EngineStats,
+    #[serde(default)]
+    tasks: Vec<ScheduledTask>,
+}
+
+/// Owns the on-disk scheduler state: loads `scheduled_tasks.json` at
+/// startup, and saves back to it after every mutation so the app never
+/// needs an explicit "Save" action.
+pub struct SchedulerStore {
+    path: PathBuf,
+    state: PersistedState,
+}
+
+impl SchedulerStore {
+    /// Loads `path` if it exists, or starts empty (engine stopped, no
+    /// tasks) if this is the first run.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, SchedulerStoreError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, state: PersistedState::default() });
+        }
+        let data = fs::read_to_string(&path)?;
+        let state = if data.trim().is_empty() { PersistedState::default() } else { serde_json::from_str(&data)? };
+        Ok(Self { path, state })
+    }
+
+    pub fn save_to_file(&self) -> Result<(), SchedulerStoreError> {
+        let json = serde_json::to_string_pretty(&self.state)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    pub fn engine_stats(&self) -> &EngineStats {
+        &self.state.engine
+    }
+
+    pub fn tasks(&self) -> &[ScheduledTask] {
+        &self.state.tasks
+    }
+
+    pub fn tasks_mut(&mut self) -> &mut [ScheduledTask] {
+        &mut self.state.tasks
+    }
+
+    pub fn find(&self, name: &str) -> Option<&ScheduledTask> {
+        self.state.tasks.iter().find(|task| task.name == name)
+    }
+
+    /// Records whether the engine is running and saves immediately, so
+    /// the next startup resumes it automatically if it was running
+    /// when the app last closed.
+    pub fn set_running(&mut self, running: bool) -> Result<(), SchedulerStoreError> {
+        self.state.engine.running = running;
+        self.save_to_file()
+    }
+
+    /// Adds `elapsed` to the engine's accumulated uptime and saves
+    /// immediately. Called with the time since the engine last started
+    /// running, typically right before [`SchedulerStore::set_running`]
+    /// stops it or the app exits.
+    pub fn add_uptime(&mut self, elapsed: Duration) -> Result<(), SchedulerStoreError> {
+        self.state.engine.total_uptime += elapsed;
+        self.save_to_file()
+    }
+
+    /// Inserts `task`, or replaces the existing one with the same
+    /// name, and saves immediately.
+    pub fn upsert_task(&mut self, task: ScheduledTask) -> Result<(), SchedulerStoreError> {
+        match self.state.tasks.iter_mut().find(|existing| existing.name == task.name) {
+            Some(existing) => *existing = task,
+            None => self.state.tasks.push(task),
+        }
+        self.save_to_file()
+    }
+
+    /// Removes the task named `name`, if any, and saves immediately.
+    pub fn remove_task(&mut self, name: &str) -> Result<(), SchedulerStoreError> {
+        self.state.tasks.retain(|task| task.name != name);
+        self.save_to_file()
+    }
+}
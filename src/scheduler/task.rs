@@ -0,0 +1,397 @@
+//! A scheduled task: what to run and when, plus the run/success
+//! counters, retry policy, and last error every execution updates.
+
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cleaning::CleanupOptions;
+use crate::cpu::CpuPriority;
+use crate::notifications::NotificationOptions;
+
+use super::expr::{Comparison, ConditionExpr, ConditionMetric, MIN_FREEABLE_DISK_BYTES};
+use super::rule::ScheduleRule;
+
+/// What a [`ScheduledTask`] actually does when it fires.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TaskType {
+    CleanDisk { options: CleanupOptions },
+    CleanRam,
+    /// Runs an arbitrary program through the same scheduling engine as
+    /// the built-in task types, e.g. a user's own PowerShell script.
+    RunCommand {
+        program: String,
+        args: Vec<String>,
+        /// Hides the program's console window on Windows; no-op
+        /// elsewhere, since there's no equivalent concept.
+        hidden: bool,
+        /// Killed and treated as a failure if it's still running after
+        /// this long.
+        timeout: Duration,
+        /// Needs administrator/root rights to run, e.g. a script that
+        /// toggles a system-level setting. Checked by
+        /// [`TaskType::requires_elevation`] before every execution.
+        #[serde(default)]
+        requires_elevation: bool,
+    },
+    /// Throttles a running process's network throughput, e.g. a backup
+    /// client that should get out of the way every evening. Resolved to
+    /// a PID by matching `exe` against the running process list when
+    /// the task fires, so it's a no-op (failure) if the process isn't
+    /// running at that moment.
+    ApplyNetworkLimit { exe: String, down_kbps: u32, up_kbps: u32 },
+    /// Sets a running process's CPU scheduling priority, e.g. restoring
+    /// a backup client to normal priority in the morning. Resolved to a
+    /// PID the same way as [`TaskType::ApplyNetworkLimit`].
+    SetProcessPriority { exe: String, priority: CpuPriority },
+}
+
+impl TaskType {
+    /// Whether this task needs administrator/elevated rights to run,
+    /// checked by the executor before every attempt so a privilege gap
+    /// is reported as a clean "skipped: not elevated" instead of a
+    /// cryptic permission-denied failure partway through.
+    pub fn requires_elevation(&self) -> bool {
+        match self {
+            TaskType::RunCommand { requires_elevation, .. } => *requires_elevation,
+            _ => false,
+        }
+    }
+}
+
+/// How many times to retry a task's execution after a failure, and how
+/// long to wait between attempts. Delays grow exponentially so a
+/// transient failure (a locked file, a busy Defender scan) gets a few
+/// quick retries without hammering whatever's contending for the
+/// resource.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff_secs: u64,
+    pub backoff_multiplier: f64,
+    pub max_backoff_secs: u64,
+}
+
+impl RetryPolicy {
+    /// No retries: one attempt, then give up.
+    pub const NONE: RetryPolicy =
+        RetryPolicy { max_retries: 0, initial_backoff_secs: 0, backoff_multiplier: 1.0, max_backoff_secs: 0 };
+
+    /// The delay before retry attempt number `attempt + 1` (0-indexed),
+    /// clamped to `max_backoff_secs`.
+    pub fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        let secs = self.initial_backoff_secs as f64 * self.backoff_multiplier.powi(attempt as i32);
+        Duration::from_secs_f64(secs.min(self.max_backoff_secs as f64))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// What to do if the app wasn't running when a task's scheduled time
+/// passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CatchUpPolicy {
+    /// Run the task once, as soon as possible after startup.
+    RunAsSoonAsPossible,
+    /// Wait for the next regularly scheduled occurrence.
+    Skip,
+}
+
+impl Default for CatchUpPolicy {
+    fn default() -> Self {
+        CatchUpPolicy::Skip
+    }
+}
+
+/// Constraints on the live system checked before a task is allowed to
+/// run, independent of its [`ScheduleRule`] and [`TaskType`]'s own
+/// precondition (see [`super::condition`]). A `None`/`false` field
+/// imposes no constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct TaskConstraints {
+    /// Don't run unless the user has been idle for at least this many
+    /// minutes, so a heavy cleanup doesn't run mid-game.
+    #[serde(default)]
+    pub min_idle_minutes: Option<u32>,
+    /// Don't run while the system is on battery power.
+    #[serde(default)]
+    pub require_ac_power: bool,
+}
+
+/// A named, recurring unit of work: a [`TaskType`] to run on a
+/// [`ScheduleRule`], with the run/success counters, retry policy, and
+/// last error every execution updates.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledTask {
+    pub name: String,
+    pub task_type: TaskType,
+    pub rule: ScheduleRule,
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    #[serde(default)]
+    pub catch_up_policy: CatchUpPolicy,
+    #[serde(default)]
+    pub constraints: TaskConstraints,
+    /// How long this task is allowed to run before it's aborted and
+    /// recorded as timed out, independent of
+    /// [`TaskType::RunCommand`]'s own `timeout` field (which governs a
+    /// spawned program's exit, not the task engine's own attempt).
+    /// `None` imposes no limit.
+    #[serde(default)]
+    pub max_runtime: Option<Duration>,
+    /// What to notify the user through once a run finishes, e.g. a
+    /// tray toast or a webhook call, so an unattended run isn't silent.
+    #[serde(default)]
+    pub notification_options: NotificationOptions,
+    /// The AND/OR condition expression gating this task, evaluated
+    /// alongside `constraints` before each run. `None` means
+    /// unconditional.
+    #[serde(default)]
+    pub condition: Option<ConditionExpr>,
+    #[serde(default)]
+    pub run_count: u64,
+    #[serde(default)]
+    pub success_count: u64,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// When this task last ran, used to detect a missed occurrence at
+    /// startup. `None` until its first run.
+    #[serde(default)]
+    pub last_run_at: Option<SystemTime>,
+    /// Which collapsible category the Scheduler tab lists this task
+    /// under, e.g. "Daily maintenance" or "Gaming". `None` shows in the
+    /// tab's "Ungrouped" section. Purely organizational — doesn't gate
+    /// whether or when the task runs.
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Position in the Scheduler tab's task list, and the tie-breaker
+    /// [`tasks_needing_catch_up`] sorts by when more than one task is
+    /// due at once. Lower runs first. Renumbered across the whole list
+    /// by [`reorder_tasks`] after a drag-and-drop reorder.
+    #[serde(default)]
+    pub order: u32,
+}
+
+impl ScheduledTask {
+    pub fn new(name: impl Into<String>, task_type: TaskType, rule: ScheduleRule) -> Self {
+        let condition = default_condition(&task_type);
+        Self {
+            name: name.into(),
+            task_type,
+            rule,
+            retry_policy: RetryPolicy::default(),
+            catch_up_policy: CatchUpPolicy::default(),
+            constraints: TaskConstraints::default(),
+            max_runtime: None,
+            notification_options: NotificationOptions::default(),
+            condition,
+            run_count: 0,
+            success_count: 0,
+            last_error: None,
+            last_run_at: None,
+            group: None,
+            order: 0,
+        }
+    }
+
+    /// Returns `true` if this task's catch-up policy is
+    /// [`CatchUpPolicy::RunAsSoonAsPossible`] and it missed at least one
+    /// scheduled occurrence between its last run and `now` — i.e. the
+    /// app wasn't running when its rule would have fired. Checked once
+    /// per task at startup.
+    pub fn missed_run_at_startup(&self, now: SystemTime) -> bool {
+        if self.catch_up_policy != CatchUpPolicy::RunAsSoonAsPossible {
+            return false;
+        }
+        let Some(last_run_at) = self.last_run_at else {
+            // Never run before: there's nothing to catch up on, the
+            // regular schedule will fire it normally.
+            return false;
+        };
+        match self.rule.next_run_after(last_run_at) {
+            Some(next) => next < now,
+            None => false,
+        }
+    }
+
+    /// Records that this task just ran, for [`ScheduledTask::missed_run_at_startup`]'s
+    /// next check.
+    pub fn record_run_at(&mut self, when: SystemTime) {
+        self.last_run_at = Some(when);
+    }
+
+    /// Records the outcome of one execution: bumps `run_count`, bumps
+    /// `success_count` and clears `last_error` on success, or records
+    /// the failure otherwise.
+    pub fn record_outcome(&mut self, result: &Result<(), String>) {
+        self.run_count += 1;
+        match result {
+            Ok(()) => {
+                self.success_count += 1;
+                self.last_error = None;
+            }
+            Err(err) => self.last_error = Some(err.clone()),
+        }
+    }
+
+    /// Runs `execute` up to `retry_policy.max_retries + 1` times,
+    /// sleeping with exponential backoff between attempts and stopping
+    /// early on success. Blocks the calling thread during backoff, so
+    /// this belongs on the scheduler's background thread, not the UI
+    /// thread. Returns every attempt's result in order (attempt 0
+    /// first) so the caller can log each one to history instead of just
+    /// the final `last_error`.
+    pub fn run_with_retry(&mut self, mut execute: impl FnMut() -> Result<(), String>) -> Vec<Result<(), String>> {
+        let mut attempts = Vec::new();
+        for attempt in 0..=self.retry_policy.max_retries {
+            let result = execute();
+            self.record_outcome(&result);
+            let failed = result.is_err();
+            attempts.push(result);
+            if !failed {
+                break;
+            }
+            if attempt < self.retry_policy.max_retries {
+                thread::sleep(self.retry_policy.backoff_for_attempt(attempt));
+            }
+        }
+        attempts
+    }
+}
+
+/// The condition a newly created task starts with: `CleanDisk` defaults
+/// to "only run if it would free enough to bother", matching this
+/// app's historical behavior, but the result is just an ordinary
+/// [`ConditionExpr`] the user can edit or clear through the condition
+/// builder like any other task.
+fn default_condition(task_type: &TaskType) -> Option<ConditionExpr> {
+    match task_type {
+        TaskType::CleanDisk { options } => Some(ConditionExpr::Compare {
+            metric: ConditionMetric::FreeableDiskBytes { options: options.clone() },
+            comparison: Comparison::GreaterThan,
+            threshold: MIN_FREEABLE_DISK_BYTES,
+        }),
+        _ => None,
+    }
+}
+
+/// Returns every task in `tasks` that missed a scheduled occurrence
+/// while the app wasn't running and should be run now to catch up,
+/// ordered by [`ScheduledTask::order`] so catching up several at once
+/// runs them in the order the Scheduler tab lists them. Called once at
+/// startup.
+pub fn tasks_needing_catch_up(tasks: &[ScheduledTask], now: SystemTime) -> Vec<&ScheduledTask> {
+    let mut due: Vec<&ScheduledTask> = tasks.iter().filter(|task| task.missed_run_at_startup(now)).collect();
+    due.sort_by_key(|task| task.order);
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_none_never_waits() {
+        assert_eq!(RetryPolicy::NONE.backoff_for_attempt(0), Duration::ZERO);
+        assert_eq!(RetryPolicy::NONE.max_retries, 0);
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_by_the_multiplier() {
+        let policy = RetryPolicy { max_retries: 5, initial_backoff_secs: 2, backoff_multiplier: 2.0, max_backoff_secs: 1000 };
+        assert_eq!(policy.backoff_for_attempt(0), Duration::from_secs(2));
+        assert_eq!(policy.backoff_for_attempt(1), Duration::from_secs(4));
+        assert_eq!(policy.backoff_for_attempt(2), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn backoff_clamps_to_max_backoff_secs() {
+        let policy = RetryPolicy { max_retries: 10, initial_backoff_secs: 1, backoff_multiplier: 10.0, max_backoff_secs: 30 };
+        assert_eq!(policy.backoff_for_attempt(5), Duration::from_secs(30));
+    }
+
+    fn task_with_retries(max_retries: u32) -> ScheduledTask {
+        let mut task = ScheduledTask::new("test", TaskType::CleanRam, ScheduleRule::Interval { period_secs: 60, window: None });
+        task.retry_policy = RetryPolicy { max_retries, initial_backoff_secs: 0, backoff_multiplier: 1.0, max_backoff_secs: 0 };
+        task
+    }
+
+    #[test]
+    fn run_with_retry_stops_at_first_success() {
+        let mut task = task_with_retries(3);
+        let mut calls = 0;
+        let attempts = task.run_with_retry(|| {
+            calls += 1;
+            Ok(())
+        });
+        assert_eq!(calls, 1);
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(task.run_count, 1);
+        assert_eq!(task.success_count, 1);
+    }
+
+    #[test]
+    fn run_with_retry_retries_up_to_max_retries_on_failure() {
+        let mut task = task_with_retries(2);
+        let mut calls = 0;
+        let attempts = task.run_with_retry(|| {
+            calls += 1;
+            Err("boom".to_string())
+        });
+        assert_eq!(calls, 3);
+        assert_eq!(attempts.len(), 3);
+        assert!(attempts.iter().all(Result::is_err));
+        assert_eq!(task.run_count, 3);
+        assert_eq!(task.success_count, 0);
+        assert_eq!(task.last_error, Some("boom".to_string()));
+    }
+
+    #[test]
+    fn run_with_retry_stops_early_once_an_attempt_succeeds() {
+        let mut task = task_with_retries(5);
+        let mut calls = 0;
+        let attempts = task.run_with_retry(|| {
+            calls += 1;
+            if calls < 3 { Err("not yet".to_string()) } else { Ok(()) }
+        });
+        assert_eq!(calls, 3);
+        assert_eq!(attempts.len(), 3);
+        assert_eq!(task.last_error, None);
+        assert_eq!(task.success_count, 1);
+    }
+
+    #[test]
+    fn missed_run_at_startup_is_false_without_catch_up_policy() {
+        let mut task = task_with_retries(0);
+        task.last_run_at = Some(SystemTime::UNIX_EPOCH);
+        assert!(!task.missed_run_at_startup(SystemTime::now()));
+    }
+
+    #[test]
+    fn missed_run_at_startup_is_false_before_first_run() {
+        let mut task = task_with_retries(0);
+        task.catch_up_policy = CatchUpPolicy::RunAsSoonAsPossible;
+        assert!(!task.missed_run_at_startup(SystemTime::now()));
+    }
+}
+
+/// Moves `moved_task` immediately before `before_task` (or to the end
+/// of the list if `None`) and renumbers every task's `order` field to
+/// match its new position, for the Scheduler tab's drag-and-drop
+/// reordering.
+pub fn reorder_tasks(tasks: &mut [ScheduledTask], moved_task: &str, before_task: Option<&str>) {
+    let mut names: Vec<String> = tasks.iter().filter(|task| task.name != moved_task).map(|task| task.name.clone()).collect();
+    let insert_at = before_task.and_then(|before| names.iter().position(|name| name == before)).unwrap_or(names.len());
+    names.insert(insert_at, moved_task.to_string());
+
+    for task in tasks.iter_mut() {
+        if let Some(position) = names.iter().position(|name| name == &task.name) {
+            task.order = position as u32;
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Detects event-driven [`ScheduleRule`] fires — `OnProcessExit` and
+//! `OnLogon` — which happen in response to something external rather
+//! than at a computable future instant, so the scheduler checks them
+//! separately from [`ScheduleRule::next_run_after`]'s time-based rules.
+
+use std::collections::HashSet;
+
+use crate::profiles::watcher::ProcessSnapshot;
+
+use super::rule::ScheduleRule;
+
+/// Cross-poll state for detecting event-driven fires: which
+/// `OnProcessExit` targets were seen running as of the last poll, and
+/// whether this watcher's one-time `OnLogon` fire has already
+/// happened.
+#[derive(Debug, Default)]
+pub struct TriggerState {
+    previously_running: HashSet<String>,
+    logon_fired: bool,
+}
+
+impl TriggerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if `rule` just fired, given the current process
+    /// list from `snapshot`. Call once per poll cycle for every
+    /// event-driven task sharing this state; a time-based rule always
+    /// returns `false` here — check those with
+    /// [`ScheduleRule::next_run_after`] instead.
+    pub fn poll(&mut self, rule: &ScheduleRule, snapshot: &dyn ProcessSnapshot) -> bool {
+        match rule {
+            ScheduleRule::OnProcessExit { exe } => self.poll_process_exit(exe, snapshot),
+            ScheduleRule::OnLogon => self.poll_logon(),
+            _ => false,
+        }
+    }
+
+    /// Fires on the poll cycle right after `exe` goes from running to
+    /// not running, having been observed running on some earlier poll.
+    /// Never fires on the very first poll a not-yet-seen `exe` is
+    /// already absent — there's nothing to call an "exit" yet.
+    fn poll_process_exit(&mut self, exe: &str, snapshot: &dyn ProcessSnapshot) -> bool {
+        let key = exe.to_lowercase();
+        let running_now = snapshot.running_processes().iter().any(|(_, name)| name.eq_ignore_ascii_case(exe));
+        let was_running = self.previously_running.contains(&key);
+        if running_now {
+            self.previously_running.insert(key);
+        } else {
+            self.previously_running.remove(&key);
+        }
+        was_running && !running_now
+    }
+
+    /// `OnLogon` fires exactly once per [`TriggerState`] lifetime.
+    /// Properly detecting a different user's logon while this app's
+    /// background service keeps running would need a session
+    /// notification subscription (`WTSRegisterSessionNotification` and
+    /// `WM_WTSSESSION_CHANGE` on Windows); short of that, this watcher
+    /// starting at app/service startup is itself a logon for the
+    /// common single-user case, so the first poll is treated as one.
+    fn poll_logon(&mut self) -> bool {
+        if self.logon_fired {
+            false
+        } else {
+            self.logon_fired = true;
+            true
+        }
+    }
+}
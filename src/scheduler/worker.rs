@@ -0,0 +1,45 @@
+//! A single long-lived background thread that off-thread scheduler work
+//! — currently just [`super::condition::ConditionCheckCache::check_async`]'s
+//! disk scans — submits closures to, instead of spawning a fresh OS
+//! thread for every call. A scheduler tick happens every few seconds, so
+//! reusing one thread instead of spawning and tearing one down each time
+//! cuts real thread-churn overhead, the same complaint that would apply
+//! to building a fresh async runtime per call if this crate used one.
+
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// One shared background thread, reused across every submitted job.
+/// Cheap to keep alive for the app's whole lifetime: idle, it just
+/// blocks on an empty channel.
+pub struct BackgroundWorker {
+    sender: Sender<Job>,
+}
+
+impl BackgroundWorker {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        thread::spawn(move || {
+            for job in receiver {
+                job();
+            }
+        });
+        Self { sender }
+    }
+
+    /// Runs `job` on the shared background thread. Silently dropped if
+    /// the worker thread has gone away, the same "the scan never
+    /// finished" outcome callers already handle when nothing updates
+    /// the cache in time.
+    pub fn submit(&self, job: impl FnOnce() + Send + 'static) {
+        let _ = self.sender.send(Box::new(job));
+    }
+}
+
+impl Default for BackgroundWorker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,278 @@
+//! Lists and controls every Windows service via the Service Control
+//! Manager, for a general Services tab. [`ServiceController`](super::ServiceController)
+//! stays the narrow API gaming profiles use to flip one named service's
+//! enabled state; this is the list-everything counterpart behind
+//! search and start/stop/restart/startup-type actions, plus tracking
+//! which services this app itself has touched.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::ServiceError;
+
+/// The SCM's current run state for a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServiceStatus {
+    Running,
+    Stopped,
+    StartPending,
+    StopPending,
+    Paused,
+}
+
+/// How a service is configured to start, per the SCM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartupType {
+    Automatic,
+    AutomaticDelayed,
+    Manual,
+    Disabled,
+}
+
+/// One row of the Services tab: everything the SCM reports about a
+/// single service.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceInfo {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+    pub status: ServiceStatus,
+    pub startup_type: StartupType,
+}
+
+#[derive(Debug)]
+pub enum ServiceManagerError {
+    Service(ServiceError),
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ServiceManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceManagerError::Service(err) => write!(f, "{err}"),
+            ServiceManagerError::Io(err) => write!(f, "io error: {err}"),
+            ServiceManagerError::Parse(err) => write!(f, "invalid gamebooster_services.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceManagerError {}
+
+impl From<ServiceError> for ServiceManagerError {
+    fn from(err: ServiceError) -> Self {
+        ServiceManagerError::Service(err)
+    }
+}
+
+impl From<std::io::Error> for ServiceManagerError {
+    fn from(err: std::io::Error) -> Self {
+        ServiceManagerError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ServiceManagerError {
+    fn from(err: serde_json::Error) -> Self {
+        ServiceManagerError::Parse(err)
+    }
+}
+
+/// Lists, starts, stops, restarts, and reconfigures Windows services via
+/// the SCM, and remembers which ones this app itself has changed so the
+/// Services tab can filter down to "changed by GameBooster".
+pub struct ServiceManager {
+    path: PathBuf,
+    changed: HashSet<String>,
+}
+
+impl ServiceManager {
+    /// Loads the "changed by GameBooster" set from `path` if it exists,
+    /// or starts empty if this is the first run.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ServiceManagerError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, changed: HashSet::new() });
+        }
+        let data = fs::read_to_string(&path)?;
+        let changed = if data.trim().is_empty() { HashSet::new() } else { serde_json::from_str(&data)? };
+        Ok(Self { path, changed })
+    }
+
+    fn save_to_file(&self) -> Result<(), ServiceManagerError> {
+        let json = serde_json::to_string_pretty(&self.changed)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Lists every service the SCM knows about.
+    pub fn list_services(&self) -> Result<Vec<ServiceInfo>, ServiceManagerError> {
+        Ok(imp::list_services()?)
+    }
+
+    /// Looks up a single service by its short name, for callers that
+    /// need one service's current state rather than the whole list
+    /// (e.g. [`super::presets::GamingServicePreset::apply`] snapshotting
+    /// a service before changing it).
+    pub fn find(&self, service_name: &str) -> Result<Option<ServiceInfo>, ServiceManagerError> {
+        Ok(self.list_services()?.into_iter().find(|service| service.name == service_name))
+    }
+
+    /// Lists every service whose name or display name contains `query`,
+    /// case-insensitively, for the Services tab's search box.
+    pub fn search(&self, query: &str) -> Result<Vec<ServiceInfo>, ServiceManagerError> {
+        let query = query.to_lowercase();
+        Ok(self
+            .list_services()?
+            .into_iter()
+            .filter(|service| {
+                service.name.to_lowercase().contains(&query) || service.display_name.to_lowercase().contains(&query)
+            })
+            .collect())
+    }
+
+    /// Returns whether `service_name` has ever been changed through this
+    /// app, for the "changed by GameBooster" filter.
+    pub fn was_changed_by_app(&self, service_name: &str) -> bool {
+        self.changed.contains(service_name)
+    }
+
+    pub fn start(&mut self, service_name: &str) -> Result<(), ServiceManagerError> {
+        imp::start(service_name)?;
+        self.mark_changed(service_name)
+    }
+
+    pub fn stop(&mut self, service_name: &str) -> Result<(), ServiceManagerError> {
+        imp::stop(service_name)?;
+        self.mark_changed(service_name)
+    }
+
+    pub fn restart(&mut self, service_name: &str) -> Result<(), ServiceManagerError> {
+        imp::stop(service_name)?;
+        imp::start(service_name)?;
+        self.mark_changed(service_name)
+    }
+
+    pub fn set_startup_type(&mut self, service_name: &str, startup_type: StartupType) -> Result<(), ServiceManagerError> {
+        imp::set_startup_type(service_name, startup_type)?;
+        self.mark_changed(service_name)
+    }
+
+    fn mark_changed(&mut self, service_name: &str) -> Result<(), ServiceManagerError> {
+        self.changed.insert(service_name.to_string());
+        self.save_to_file()
+    }
+
+    /// Lists the services that directly depend on `service_name`, via
+    /// the SCM's dependency graph.
+    pub fn dependents_of(&self, service_name: &str) -> Result<Vec<String>, ServiceManagerError> {
+        Ok(imp::dependents_of(service_name)?)
+    }
+
+    /// Checks whether disabling `service_name` is safe, should warn
+    /// about what else would break, or should be blocked outright —
+    /// without actually changing anything.
+    pub fn check_before_disable(&self, service_name: &str) -> Result<DisableCheck, ServiceManagerError> {
+        if KNOWN_DANGEROUS_SERVICES.iter().any(|&dangerous| dangerous.eq_ignore_ascii_case(service_name)) {
+            return Ok(DisableCheck::Blocked {
+                reason: "this service underpins Windows' own RPC/service infrastructure; \
+                         disabling it can make the system unusable",
+            });
+        }
+        let dependents = self.dependents_of(service_name)?;
+        Ok(if dependents.is_empty() { DisableCheck::Safe } else { DisableCheck::DependentsWarning(dependents) })
+    }
+
+    /// Stops and disables `service_name`, refusing outright if
+    /// [`ServiceManager::check_before_disable`] would block it. A
+    /// dependents warning doesn't block this call — the caller is
+    /// expected to have already confirmed with the user via
+    /// [`ServiceManager::check_before_disable`] first.
+    pub fn stop_and_disable_checked(&mut self, service_name: &str) -> Result<DisableCheck, ServiceManagerError> {
+        let check = self.check_before_disable(service_name)?;
+        if matches!(check, DisableCheck::Blocked { .. }) {
+            return Ok(check);
+        }
+        self.stop(service_name)?;
+        self.set_startup_type(service_name, StartupType::Disabled)?;
+        Ok(check)
+    }
+}
+
+/// Services this app refuses to disable outright, regardless of
+/// dependents, because disabling them reliably breaks the system.
+const KNOWN_DANGEROUS_SERVICES: &[&str] = &["RpcSs", "RpcEptMapper", "DcomLaunch"];
+
+/// What [`ServiceManager::check_before_disable`] found, before anything
+/// is actually changed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisableCheck {
+    /// Nothing else depends on this service.
+    Safe,
+    /// These services depend on it and would stop working if it's
+    /// disabled.
+    DependentsWarning(Vec<String>),
+    /// This service is load-bearing enough that disabling it isn't
+    /// offered at all.
+    Blocked { reason: &'static str },
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{ServiceError, ServiceInfo, StartupType};
+
+    // OpenSCManagerW, then EnumServicesStatusExW to get each
+    // service's name and SERVICE_STATUS_PROCESS, and
+    // QueryServiceConfigW + QueryServiceConfig2W (for the
+    // description) per service to fill in the rest. Not implemented
+    // yet — report it as unsupported rather than panicking, so the
+    // Services tab and gaming-safe presets get a clean error instead
+    // of a crash.
+    pub(super) fn list_services() -> Result<Vec<ServiceInfo>, ServiceError> {
+        Err(ServiceError::Unsupported("enumerating services via the SCM isn't implemented yet"))
+    }
+
+    pub(super) fn start(_service_name: &str) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("starting services via the SCM isn't implemented yet"))
+    }
+
+    pub(super) fn stop(_service_name: &str) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("stopping services via the SCM isn't implemented yet"))
+    }
+
+    pub(super) fn set_startup_type(_service_name: &str, _startup_type: StartupType) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("changing a service's startup type via the SCM isn't implemented yet"))
+    }
+
+    pub(super) fn dependents_of(_service_name: &str) -> Result<Vec<String>, ServiceError> {
+        Err(ServiceError::Unsupported("walking the SCM's dependency graph isn't implemented yet"))
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{ServiceError, ServiceInfo, StartupType};
+
+    pub(super) fn list_services() -> Result<Vec<ServiceInfo>, ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+
+    pub(super) fn start(_service_name: &str) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+
+    pub(super) fn stop(_service_name: &str) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+
+    pub(super) fn set_startup_type(_service_name: &str, _startup_type: StartupType) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+
+    pub(super) fn dependents_of(_service_name: &str) -> Result<Vec<String>, ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+}
@@ -0,0 +1,103 @@
+//! Windows service enable/disable control, used by gaming profiles to
+//! quiet background services while a game is running. See
+//! [`manager`] for the general list-everything Services tab.
+
+pub mod manager;
+pub mod presets;
+pub mod scheduled_tasks;
+pub mod snapshot;
+pub mod task_auditor;
+pub mod telemetry;
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ServiceError {
+    NotFound(String),
+    PermissionDenied(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceError::NotFound(name) => write!(f, "service {name} not found"),
+            ServiceError::PermissionDenied(msg) => write!(f, "permission denied: {msg}"),
+            ServiceError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+/// Something that can start/stop and enable/disable a named service.
+/// Implemented against the real Service Control Manager by
+/// [`OsServiceControl`]; a fake can be passed to
+/// [`ServiceController::with_control`] instead, so the code that quiets
+/// services can be exercised without the host OS.
+pub trait ServiceControl {
+    fn set_service_enabled(&self, service_name: &str, enabled: bool) -> Result<(), ServiceError>;
+}
+
+/// Default [`ServiceControl`] backed by the real Windows Service
+/// Control Manager.
+pub struct OsServiceControl;
+
+impl ServiceControl for OsServiceControl {
+    fn set_service_enabled(&self, service_name: &str, enabled: bool) -> Result<(), ServiceError> {
+        imp::set_service_enabled(service_name, enabled)
+    }
+}
+
+/// Enables or disables a Windows service by its short name (the value
+/// `sc.exe` and the Services MMC snap-in call the "service name", not
+/// the display name).
+pub struct ServiceController<C: ServiceControl = OsServiceControl> {
+    control: C,
+}
+
+impl ServiceController<OsServiceControl> {
+    pub fn new() -> Self {
+        Self::with_control(OsServiceControl)
+    }
+}
+
+impl<C: ServiceControl> ServiceController<C> {
+    pub fn with_control(control: C) -> Self {
+        Self { control }
+    }
+
+    /// Starts (`true`) or stops and disables (`false`) the named
+    /// service.
+    pub fn set_service_enabled(&self, service_name: &str, enabled: bool) -> Result<(), ServiceError> {
+        self.control.set_service_enabled(service_name, enabled)
+    }
+}
+
+impl Default for ServiceController<OsServiceControl> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::ServiceError;
+
+    // OpenSCManagerW + OpenServiceW, then ControlService/StartServiceW and
+    // ChangeServiceConfigW to flip the startup type. Not implemented yet —
+    // report it as unsupported rather than panicking, so profile
+    // activation can surface a clean error instead of crashing.
+    pub(super) fn set_service_enabled(_service_name: &str, _enabled: bool) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("driving the Windows Service Control Manager isn't implemented yet"))
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::ServiceError;
+
+    pub(super) fn set_service_enabled(_service_name: &str, _enabled: bool) -> Result<(), ServiceError> {
+        Err(ServiceError::Unsupported("Windows services don't exist on this platform"))
+    }
+}
@@ -0,0 +1,135 @@
+//! Curated presets of services safe to disable while gaming, applied as
+//! a batch with a snapshot of each one's prior state so a single
+//! "Restore all services" action can put everything back exactly as it
+//! was.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::manager::{ServiceManager, ServiceManagerError, ServiceStatus, StartupType};
+
+/// Service names curated as safe to disable while gaming: indexing,
+/// search, and print/fax services that cost background CPU/disk but
+/// nothing a game needs running.
+pub const GAMING_SAFE_SERVICES: &[&str] =
+    &["SysMain", "WSearch", "XblAuthManager", "XblGameSave", "XboxNetApiSvc", "Spooler", "Fax"];
+
+/// A service's startup type and running state just before a preset was
+/// applied, so [`GamingServicePreset::restore_all`] can put it back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ServiceSnapshot {
+    service_name: String,
+    startup_type: StartupType,
+    was_running: bool,
+}
+
+#[derive(Debug)]
+pub enum ServicePresetError {
+    Manager(ServiceManagerError),
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for ServicePresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServicePresetError::Manager(err) => write!(f, "{err}"),
+            ServicePresetError::Io(err) => write!(f, "io error: {err}"),
+            ServicePresetError::Parse(err) => write!(f, "invalid gaming_preset_snapshot.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ServicePresetError {}
+
+impl From<ServiceManagerError> for ServicePresetError {
+    fn from(err: ServiceManagerError) -> Self {
+        ServicePresetError::Manager(err)
+    }
+}
+
+impl From<std::io::Error> for ServicePresetError {
+    fn from(err: std::io::Error) -> Self {
+        ServicePresetError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ServicePresetError {
+    fn from(err: serde_json::Error) -> Self {
+        ServicePresetError::Parse(err)
+    }
+}
+
+/// Applies and reverts [`GAMING_SAFE_SERVICES`] as one batch, persisting
+/// the snapshot so "Restore all services" survives an app restart
+/// between applying and restoring.
+pub struct GamingServicePreset {
+    path: PathBuf,
+    snapshot: Option<Vec<ServiceSnapshot>>,
+}
+
+impl GamingServicePreset {
+    /// Loads a pending snapshot from `path` if one exists, or starts
+    /// with no preset applied.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ServicePresetError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, snapshot: None });
+        }
+        let data = fs::read_to_string(&path)?;
+        let snapshot = if data.trim().is_empty() { None } else { serde_json::from_str(&data)? };
+        Ok(Self { path, snapshot })
+    }
+
+    fn save_to_file(&self) -> Result<(), ServicePresetError> {
+        let json = serde_json::to_string_pretty(&self.snapshot)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Whether a preset is currently applied, i.e. there's a snapshot to
+    /// restore.
+    pub fn is_applied(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Stops and disables every service in [`GAMING_SAFE_SERVICES`],
+    /// recording each one's prior startup type and running state first.
+    /// Services not present on this machine (e.g. the Xbox services on
+    /// a non-Xbox install) are skipped rather than failing the batch.
+    pub fn apply(&mut self, manager: &mut ServiceManager) -> Result<(), ServicePresetError> {
+        let mut snapshot = Vec::new();
+        for &service_name in GAMING_SAFE_SERVICES {
+            let Some(info) = manager.find(service_name)? else { continue };
+            snapshot.push(ServiceSnapshot {
+                service_name: service_name.to_string(),
+                startup_type: info.startup_type,
+                was_running: info.status == ServiceStatus::Running,
+            });
+            if info.status == ServiceStatus::Running {
+                manager.stop(service_name)?;
+            }
+            manager.set_startup_type(service_name, StartupType::Disabled)?;
+        }
+        self.snapshot = Some(snapshot);
+        self.save_to_file()
+    }
+
+    /// Restores every service captured by the last
+    /// [`GamingServicePreset::apply`] to its prior startup type and
+    /// running state, for the "Restore all services" button. Does
+    /// nothing if no preset is currently applied.
+    pub fn restore_all(&mut self, manager: &mut ServiceManager) -> Result<(), ServicePresetError> {
+        let Some(snapshot) = self.snapshot.take() else { return Ok(()) };
+        for entry in snapshot {
+            manager.set_startup_type(&entry.service_name, entry.startup_type)?;
+            if entry.was_running {
+                manager.start(&entry.service_name)?;
+            }
+        }
+        self.save_to_file()
+    }
+}
@@ -0,0 +1,81 @@
+//! Enables and disables native Windows Scheduled Tasks by their Task
+//! Scheduler path, e.g. `\Microsoft\Windows\Application
+//! Experience\Microsoft Compatibility Appraiser` — distinct from
+//! [`crate::scheduler`], this app's own engine, which only knows about
+//! tasks the user created inside this app.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ScheduledTaskError {
+    NotFound(String),
+    PermissionDenied(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for ScheduledTaskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduledTaskError::NotFound(path) => write!(f, "scheduled task {path} not found"),
+            ScheduledTaskError::PermissionDenied(msg) => write!(f, "permission denied: {msg}"),
+            ScheduledTaskError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduledTaskError {}
+
+/// Drives the native Windows Task Scheduler by task path.
+pub struct WindowsTaskScheduler;
+
+impl WindowsTaskScheduler {
+    pub fn new() -> Self {
+        WindowsTaskScheduler
+    }
+
+    /// Whether the task at `task_path` is currently enabled.
+    pub fn is_task_enabled(&self, task_path: &str) -> Result<bool, ScheduledTaskError> {
+        imp::is_task_enabled(task_path)
+    }
+
+    /// Enables or disables the task at `task_path`.
+    pub fn set_task_enabled(&self, task_path: &str, enabled: bool) -> Result<(), ScheduledTaskError> {
+        imp::set_task_enabled(task_path, enabled)
+    }
+}
+
+impl Default for WindowsTaskScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::ScheduledTaskError;
+
+    pub(super) fn is_task_enabled(_task_path: &str) -> Result<bool, ScheduledTaskError> {
+        // ITaskService::Connect, then GetFolder on the task's parent
+        // path and ITaskFolder::GetTask for the leaf name, reading
+        // IRegisteredTask::get_Enabled.
+        todo!("read a scheduled task's Enabled property via the Task Scheduler COM API")
+    }
+
+    pub(super) fn set_task_enabled(_task_path: &str, _enabled: bool) -> Result<(), ScheduledTaskError> {
+        // Same lookup as `is_task_enabled`, then IRegisteredTask::put_Enabled.
+        todo!("write a scheduled task's Enabled property via the Task Scheduler COM API")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::ScheduledTaskError;
+
+    pub(super) fn is_task_enabled(_task_path: &str) -> Result<bool, ScheduledTaskError> {
+        Err(ScheduledTaskError::Unsupported("the Windows Task Scheduler doesn't exist on this platform"))
+    }
+
+    pub(super) fn set_task_enabled(_task_path: &str, _enabled: bool) -> Result<(), ScheduledTaskError> {
+        Err(ScheduledTaskError::Unsupported("the Windows Task Scheduler doesn't exist on this platform"))
+    }
+}
@@ -0,0 +1,173 @@
+//! Named, timestamped restore points for batch service/registry
+//! changes, independent of Windows System Restore — captured once
+//! before a batch change (a gaming preset, a telemetry toggle) and
+//! picked from a list in Settings to revert, instead of relying on a
+//! full OS restore point for a handful of service/registry values.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::system::registry::{restore_value, snapshot_value, RegistryError, RegistryValue};
+
+use super::manager::{ServiceManager, ServiceManagerError, ServiceStatus, StartupType};
+
+/// A service's startup type and running state at the moment a restore
+/// point was captured.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceState {
+    pub service_name: String,
+    pub startup_type: StartupType,
+    pub was_running: bool,
+}
+
+/// One restore point: a user-facing label, when it was taken, and
+/// every service and registry value it captured.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestorePoint {
+    pub label: String,
+    pub created_at_unix_secs: u64,
+    pub services: Vec<ServiceState>,
+    pub registry_values: Vec<RegistryValue>,
+}
+
+#[derive(Debug)]
+pub enum SnapshotStoreError {
+    Manager(ServiceManagerError),
+    Registry(RegistryError),
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+    InvalidIndex(usize),
+}
+
+impl fmt::Display for SnapshotStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotStoreError::Manager(err) => write!(f, "{err}"),
+            SnapshotStoreError::Registry(err) => write!(f, "{err}"),
+            SnapshotStoreError::Io(err) => write!(f, "io error: {err}"),
+            SnapshotStoreError::Parse(err) => write!(f, "invalid service_snapshots.json: {err}"),
+            SnapshotStoreError::InvalidIndex(index) => write!(f, "no restore point at index {index}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotStoreError {}
+
+impl From<ServiceManagerError> for SnapshotStoreError {
+    fn from(err: ServiceManagerError) -> Self {
+        SnapshotStoreError::Manager(err)
+    }
+}
+
+impl From<RegistryError> for SnapshotStoreError {
+    fn from(err: RegistryError) -> Self {
+        SnapshotStoreError::Registry(err)
+    }
+}
+
+impl From<std::io::Error> for SnapshotStoreError {
+    fn from(err: std::io::Error) -> Self {
+        SnapshotStoreError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotStoreError {
+    fn from(err: serde_json::Error) -> Self {
+        SnapshotStoreError::Parse(err)
+    }
+}
+
+/// Owns every restore point taken so far, persisted to `path` and saved
+/// after every mutation.
+pub struct SnapshotStore {
+    path: PathBuf,
+    points: Vec<RestorePoint>,
+}
+
+impl SnapshotStore {
+    /// Loads `path` if it exists, or starts with no restore points.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, SnapshotStoreError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, points: Vec::new() });
+        }
+        let data = fs::read_to_string(&path)?;
+        let points = if data.trim().is_empty() { Vec::new() } else { serde_json::from_str(&data)? };
+        Ok(Self { path, points })
+    }
+
+    fn save_to_file(&self) -> Result<(), SnapshotStoreError> {
+        let json = serde_json::to_string_pretty(&self.points)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Every restore point taken so far, newest last, for the Settings
+    /// picker to list.
+    pub fn list(&self) -> &[RestorePoint] {
+        &self.points
+    }
+
+    /// Captures `service_names`'s current startup type/running state
+    /// and `registry_values`'s current data under `label`, timestamped
+    /// with the current time.
+    pub fn capture(
+        &mut self,
+        label: impl Into<String>,
+        manager: &ServiceManager,
+        service_names: &[&str],
+        registry_values: &[(&str, &str)],
+    ) -> Result<(), SnapshotStoreError> {
+        let mut services = Vec::new();
+        for &service_name in service_names {
+            if let Some(info) = manager.find(service_name)? {
+                services.push(ServiceState {
+                    service_name: service_name.to_string(),
+                    startup_type: info.startup_type,
+                    was_running: info.status == ServiceStatus::Running,
+                });
+            }
+        }
+
+        let mut registry = Vec::new();
+        for &(key_path, value_name) in registry_values {
+            registry.push(snapshot_value(key_path, value_name)?);
+        }
+
+        let created_at_unix_secs =
+            SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs();
+        self.points.push(RestorePoint { label: label.into(), created_at_unix_secs, services, registry_values: registry });
+        self.save_to_file()
+    }
+
+    /// Restores the restore point at `index` in [`SnapshotStore::list`],
+    /// leaving it in the list afterward in case the user wants to
+    /// re-apply it.
+    pub fn restore(&self, index: usize, manager: &mut ServiceManager) -> Result<(), SnapshotStoreError> {
+        let point = self.points.get(index).ok_or(SnapshotStoreError::InvalidIndex(index))?;
+        for service in &point.services {
+            manager.set_startup_type(&service.service_name, service.startup_type)?;
+            if service.was_running {
+                manager.start(&service.service_name)?;
+            }
+        }
+        for value in &point.registry_values {
+            restore_value(value)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the restore point at `index`, for the Settings picker's
+    /// cleanup action.
+    pub fn remove(&mut self, index: usize) -> Result<(), SnapshotStoreError> {
+        if index >= self.points.len() {
+            return Err(SnapshotStoreError::InvalidIndex(index));
+        }
+        self.points.remove(index);
+        self.save_to_file()
+    }
+}
@@ -0,0 +1,67 @@
+//! Lists and toggles Microsoft's own Task Scheduler entries known to
+//! cause gaming hitches — Compatibility Appraiser's disk scans,
+//! ScheduledDefrag kicking in mid-session, CEIP's telemetry uploads —
+//! independent of both [`super::telemetry`]'s privacy-focused list and
+//! [`crate::scheduler`], this app's own engine.
+
+use super::scheduled_tasks::{ScheduledTaskError, WindowsTaskScheduler};
+
+/// Task Scheduler paths this app flags as worth disabling while gaming,
+/// not because they're privacy-invasive but because they're known to
+/// spike disk/CPU at unpredictable times.
+pub const HITCH_PRONE_TASKS: &[&str] = &[
+    r"\Microsoft\Windows\Application Experience\Microsoft Compatibility Appraiser",
+    r"\Microsoft\Windows\Defrag\ScheduledDefrag",
+    r"\Microsoft\Windows\Customer Experience Improvement Program\Consolidator",
+    r"\Microsoft\Windows\Customer Experience Improvement Program\UsbCeip",
+];
+
+/// One scheduled task's current enabled state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskAuditEntry {
+    pub path: String,
+    pub enabled: bool,
+}
+
+/// Audits and toggles [`HITCH_PRONE_TASKS`] via the native Task
+/// Scheduler.
+pub struct TaskAuditor {
+    tasks: WindowsTaskScheduler,
+}
+
+impl TaskAuditor {
+    pub fn new() -> Self {
+        Self { tasks: WindowsTaskScheduler::new() }
+    }
+
+    /// Reads the current enabled state of every task in
+    /// [`HITCH_PRONE_TASKS`]. A task that isn't found on this machine is
+    /// skipped rather than failing the whole audit.
+    pub fn audit(&self) -> Vec<TaskAuditEntry> {
+        HITCH_PRONE_TASKS
+            .iter()
+            .filter_map(|path| {
+                let enabled = self.tasks.is_task_enabled(path).ok()?;
+                Some(TaskAuditEntry { path: path.to_string(), enabled })
+            })
+            .collect()
+    }
+
+    pub fn set_enabled(&self, task_path: &str, enabled: bool) -> Result<(), ScheduledTaskError> {
+        self.tasks.set_task_enabled(task_path, enabled)
+    }
+
+    /// Disables every task in [`HITCH_PRONE_TASKS`], skipping (rather
+    /// than aborting on) any that fail.
+    pub fn disable_all(&self) {
+        for path in HITCH_PRONE_TASKS {
+            let _ = self.tasks.set_task_enabled(path, false);
+        }
+    }
+}
+
+impl Default for TaskAuditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
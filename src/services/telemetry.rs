@@ -0,0 +1,196 @@
+//! Toggles Windows' built-in telemetry/diagnostics surface as one
+//! batch — the DiagTrack and dmwappushservice services, plus the CEIP
+//! and Compatibility Appraiser scheduled tasks that phone usage data
+//! back to Microsoft — with a plain list of what's affected and full
+//! revert support, mirroring [`super::presets::GamingServicePreset`]'s
+//! snapshot/restore shape.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::manager::{ServiceManager, ServiceManagerError, ServiceStatus, StartupType};
+use super::scheduled_tasks::{ScheduledTaskError, WindowsTaskScheduler};
+
+/// Service names this toggle controls.
+pub const TELEMETRY_SERVICES: &[&str] = &["DiagTrack", "dmwappushservice"];
+
+/// Scheduled-task paths this toggle controls, as Task Scheduler names
+/// them (not a filesystem path).
+pub const TELEMETRY_SCHEDULED_TASKS: &[&str] = &[
+    r"\Microsoft\Windows\Customer Experience Improvement Program\Consolidator",
+    r"\Microsoft\Windows\Customer Experience Improvement Program\UsbCeip",
+    r"\Microsoft\Windows\Application Experience\Microsoft Compatibility Appraiser",
+    r"\Microsoft\Windows\Application Experience\ProgramDataUpdater",
+];
+
+/// One service or scheduled task [`TelemetryControl::disable_all`]
+/// would touch, for a confirmation dialog to list up front instead of
+/// disabling things as a black box.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TelemetryItem {
+    Service(String),
+    ScheduledTask(String),
+}
+
+/// Every item [`TelemetryControl::disable_all`] would touch.
+pub fn affected_items() -> Vec<TelemetryItem> {
+    TELEMETRY_SERVICES
+        .iter()
+        .map(|name| TelemetryItem::Service(name.to_string()))
+        .chain(TELEMETRY_SCHEDULED_TASKS.iter().map(|path| TelemetryItem::ScheduledTask(path.to_string())))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ServiceSnapshot {
+    service_name: String,
+    startup_type: StartupType,
+    was_running: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ScheduledTaskSnapshot {
+    task_path: String,
+    was_enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct TelemetrySnapshot {
+    services: Vec<ServiceSnapshot>,
+    scheduled_tasks: Vec<ScheduledTaskSnapshot>,
+}
+
+#[derive(Debug)]
+pub enum TelemetryControlError {
+    Manager(ServiceManagerError),
+    ScheduledTask(ScheduledTaskError),
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for TelemetryControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TelemetryControlError::Manager(err) => write!(f, "{err}"),
+            TelemetryControlError::ScheduledTask(err) => write!(f, "{err}"),
+            TelemetryControlError::Io(err) => write!(f, "io error: {err}"),
+            TelemetryControlError::Parse(err) => write!(f, "invalid telemetry_snapshot.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for TelemetryControlError {}
+
+impl From<ServiceManagerError> for TelemetryControlError {
+    fn from(err: ServiceManagerError) -> Self {
+        TelemetryControlError::Manager(err)
+    }
+}
+
+impl From<ScheduledTaskError> for TelemetryControlError {
+    fn from(err: ScheduledTaskError) -> Self {
+        TelemetryControlError::ScheduledTask(err)
+    }
+}
+
+impl From<std::io::Error> for TelemetryControlError {
+    fn from(err: std::io::Error) -> Self {
+        TelemetryControlError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for TelemetryControlError {
+    fn from(err: serde_json::Error) -> Self {
+        TelemetryControlError::Parse(err)
+    }
+}
+
+/// Disables and restores [`TELEMETRY_SERVICES`] and
+/// [`TELEMETRY_SCHEDULED_TASKS`] as one batch, persisting the snapshot
+/// so "restore" survives an app restart between disabling and
+/// restoring.
+pub struct TelemetryControl {
+    path: PathBuf,
+    snapshot: Option<TelemetrySnapshot>,
+    tasks: WindowsTaskScheduler,
+}
+
+impl TelemetryControl {
+    /// Loads a pending snapshot from `path` if one exists, or starts
+    /// with nothing disabled.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, TelemetryControlError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, snapshot: None, tasks: WindowsTaskScheduler::new() });
+        }
+        let data = fs::read_to_string(&path)?;
+        let snapshot = if data.trim().is_empty() { None } else { serde_json::from_str(&data)? };
+        Ok(Self { path, snapshot, tasks: WindowsTaskScheduler::new() })
+    }
+
+    fn save_to_file(&self) -> Result<(), TelemetryControlError> {
+        let json = serde_json::to_string_pretty(&self.snapshot)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Whether telemetry is currently disabled through this app, i.e.
+    /// there's a snapshot to restore.
+    pub fn is_disabled(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Stops and disables every telemetry service and scheduled task,
+    /// recording each one's prior state first. An item that's already
+    /// missing (e.g. dmwappushservice absent on some SKUs) is skipped
+    /// rather than failing the whole batch.
+    pub fn disable_all(&mut self, manager: &mut ServiceManager) -> Result<(), TelemetryControlError> {
+        let mut services = Vec::new();
+        for &service_name in TELEMETRY_SERVICES {
+            let Some(info) = manager.find(service_name)? else { continue };
+            services.push(ServiceSnapshot {
+                service_name: service_name.to_string(),
+                startup_type: info.startup_type,
+                was_running: info.status == ServiceStatus::Running,
+            });
+            if info.status == ServiceStatus::Running {
+                manager.stop(service_name)?;
+            }
+            manager.set_startup_type(service_name, StartupType::Disabled)?;
+        }
+
+        let mut scheduled_tasks = Vec::new();
+        for &task_path in TELEMETRY_SCHEDULED_TASKS {
+            let was_enabled = match self.tasks.is_task_enabled(task_path) {
+                Ok(enabled) => enabled,
+                Err(ScheduledTaskError::NotFound(_)) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            scheduled_tasks.push(ScheduledTaskSnapshot { task_path: task_path.to_string(), was_enabled });
+            self.tasks.set_task_enabled(task_path, false)?;
+        }
+
+        self.snapshot = Some(TelemetrySnapshot { services, scheduled_tasks });
+        self.save_to_file()
+    }
+
+    /// Restores every service and scheduled task captured by the last
+    /// [`TelemetryControl::disable_all`]. Does nothing if telemetry
+    /// isn't currently disabled through this app.
+    pub fn restore_all(&mut self, manager: &mut ServiceManager) -> Result<(), TelemetryControlError> {
+        let Some(snapshot) = self.snapshot.take() else { return Ok(()) };
+        for entry in snapshot.services {
+            manager.set_startup_type(&entry.service_name, entry.startup_type)?;
+            if entry.was_running {
+                manager.start(&entry.service_name)?;
+            }
+        }
+        for entry in snapshot.scheduled_tasks {
+            self.tasks.set_task_enabled(&entry.task_path, entry.was_enabled)?;
+        }
+        self.save_to_file()
+    }
+}
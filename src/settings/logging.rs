@@ -0,0 +1,85 @@
+//! What the (not yet built) log writer should do: how verbose to be,
+//! per-module overrides, and how long to keep old log files around.
+//! Read live off [`AppSettings`](super::AppSettings) rather than cached
+//! at startup, so flipping [`LoggingSettings::verbose_debug_session`]
+//! takes effect on the very next line logged, with no restart needed.
+
+use serde::{Deserialize, Serialize};
+
+/// How noisy a log line has to be to get written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+/// Overrides [`LoggingSettings::level`] for everything logged from
+/// `module` (a crate module path, e.g. `"scheduler::runner"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleLogFilter {
+    pub module: String,
+    pub level: LogLevel,
+}
+
+/// How aggressively old log files get pruned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogRetention {
+    /// A file at or past this size gets rotated to a new one instead
+    /// of growing further.
+    pub max_file_size_bytes: u64,
+    /// A rotated file older than this many days gets deleted the next
+    /// time pruning runs.
+    pub max_age_days: u32,
+}
+
+impl Default for LogRetention {
+    fn default() -> Self {
+        Self { max_file_size_bytes: 10 * 1024 * 1024, max_age_days: 14 }
+    }
+}
+
+/// Settings-controlled logging configuration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoggingSettings {
+    #[serde(default)]
+    pub level: LogLevel,
+    #[serde(default)]
+    pub module_filters: Vec<ModuleLogFilter>,
+    #[serde(default)]
+    pub retention: LogRetention,
+    /// Temporarily forces [`LogLevel::Debug`] everywhere, overriding
+    /// both [`LoggingSettings::level`] and every
+    /// [`LoggingSettings::module_filters`] entry, for grabbing a
+    /// detailed log around a bug that's hard to reproduce without
+    /// leaving debug logging on permanently.
+    #[serde(default)]
+    pub verbose_debug_session: bool,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self { level: LogLevel::default(), module_filters: Vec::new(), retention: LogRetention::default(), verbose_debug_session: false }
+    }
+}
+
+impl LoggingSettings {
+    /// The level a line logged from `module` should be checked
+    /// against: [`LogLevel::Debug`] during a verbose debug session,
+    /// otherwise the most specific [`ModuleLogFilter`] whose `module`
+    /// is a prefix of `module`, falling back to [`LoggingSettings::level`].
+    pub fn effective_level(&self, module: &str) -> LogLevel {
+        if self.verbose_debug_session {
+            return LogLevel::Debug;
+        }
+        self.module_filters
+            .iter()
+            .filter(|filter| module.starts_with(&filter.module))
+            .max_by_key(|filter| filter.module.len())
+            .map(|filter| filter.level)
+            .unwrap_or(self.level)
+    }
+}
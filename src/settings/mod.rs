@@ -0,0 +1,182 @@
+//! A single persisted file for the odds and ends that would otherwise
+//! reset every launch: theme choice, default disk-cleanup options, the
+//! last network speed limit typed in, which tab was open, the window's
+//! size, position and monitor, the UI scale for high-DPI screens and
+//! accessibility, whether live Defender status polling is opted into,
+//! whether the window should always reopen on the primary monitor, the
+//! configured log level/retention, and whether the Prometheus
+//! `/metrics` exporter is on and which port it listens on. One
+//! versioned JSON file rather than one file per
+//! field, so a future release can add a migration step keyed off
+//! `version` instead of losing all of it at once. Loaded once by
+//! [`crate::ui::CleanRamApp`] and flushed back to disk on every change,
+//! the same way its other persisted state is handled.
+
+mod logging;
+mod window;
+
+pub use logging::{LogLevel, LogRetention, LoggingSettings, ModuleLogFilter};
+pub use window::{MonitorGeometry, WindowState};
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::profiles::profile::DiskCleanOptions;
+use crate::theme::ThemeConfig;
+
+#[derive(Debug)]
+pub enum AppSettingsError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for AppSettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AppSettingsError::Io(err) => write!(f, "{err}"),
+            AppSettingsError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for AppSettingsError {}
+
+impl From<io::Error> for AppSettingsError {
+    fn from(err: io::Error) -> Self {
+        AppSettingsError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for AppSettingsError {
+    fn from(err: serde_json::Error) -> Self {
+        AppSettingsError::Parse(err)
+    }
+}
+
+/// Bumped whenever a field changes shape in a way `#[serde(default)]`
+/// can't paper over. Nothing has needed a real migration yet, so
+/// [`AppSettings::load_from_file`] just stamps every loaded file with
+/// the current version.
+const CURRENT_VERSION: u32 = 1;
+
+fn current_version() -> u32 {
+    CURRENT_VERSION
+}
+
+/// `egui::Context::set_pixels_per_point`'s default — no scaling beyond
+/// whatever the OS itself reports for the display.
+const DEFAULT_UI_SCALE: f32 = 1.0;
+
+fn default_ui_scale() -> f32 {
+    DEFAULT_UI_SCALE
+}
+
+/// Arbitrary high port, picked so it doesn't need elevation to bind and
+/// is unlikely to already be in use by another local exporter.
+const DEFAULT_PROMETHEUS_EXPORTER_PORT: u16 = 9812;
+
+fn default_prometheus_exporter_port() -> u16 {
+    DEFAULT_PROMETHEUS_EXPORTER_PORT
+}
+
+/// Everything this app persists that isn't already owned by its own
+/// dedicated settings file (notification mute, locale, shortcut
+/// bindings all keep saving themselves independently).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppSettings {
+    #[serde(default = "current_version")]
+    version: u32,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    #[serde(default)]
+    pub disk_options: DiskCleanOptions,
+    /// The last value typed into the network speed limit field,
+    /// independent of any single profile's
+    /// `background_network_limit_kbps`.
+    #[serde(default)]
+    pub speed_limit_kbps: Option<u32>,
+    #[serde(default)]
+    pub selected_tab: usize,
+    #[serde(default)]
+    pub window: WindowState,
+    /// Passed straight to `egui::Context::set_pixels_per_point`; higher
+    /// values scale every widget up for high-DPI screens or low vision,
+    /// independent of the OS's own display scaling.
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+    /// Whether the Services tab polls
+    /// [`crate::system::defender::query_defender_status`] every
+    /// [`crate::system::defender::DEFENDER_STATUS_POLL_INTERVAL`]
+    /// instead of only on demand. Off by default — left opt-in from
+    /// when this used to shell out to `powershell.exe` and pop up a
+    /// console window every minute; the WMI-backed query doesn't do
+    /// that anymore, but there's no reason to poll for users who never
+    /// open the Defender panel.
+    #[serde(default)]
+    pub defender_live_status_enabled: bool,
+    /// Skip restoring the saved window position on launch and always
+    /// open on the primary monitor instead — for users who move the
+    /// window to a secondary monitor that isn't always connected.
+    #[serde(default)]
+    pub always_open_on_primary_monitor: bool,
+    #[serde(default)]
+    pub logging: LoggingSettings,
+    /// Whether a Prometheus `/metrics` endpoint is served on
+    /// `prometheus_exporter_port`, for homelab users graphing this
+    /// app's stats in Grafana. Off by default: it's a listening socket,
+    /// even if localhost-only.
+    #[serde(default)]
+    pub prometheus_exporter_enabled: bool,
+    #[serde(default = "default_prometheus_exporter_port")]
+    pub prometheus_exporter_port: u16,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            theme: ThemeConfig::default(),
+            disk_options: DiskCleanOptions::default(),
+            speed_limit_kbps: None,
+            selected_tab: 0,
+            window: WindowState::default(),
+            ui_scale: DEFAULT_UI_SCALE,
+            defender_live_status_enabled: false,
+            always_open_on_primary_monitor: false,
+            logging: LoggingSettings::default(),
+            prometheus_exporter_enabled: false,
+            prometheus_exporter_port: DEFAULT_PROMETHEUS_EXPORTER_PORT,
+        }
+    }
+}
+
+impl AppSettings {
+    /// Loads `path`, or starts with every default if it doesn't exist
+    /// yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, AppSettingsError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let mut settings: AppSettings = serde_json::from_str(&contents)?;
+                settings.version = CURRENT_VERSION;
+                Ok(settings)
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Saves this settings snapshot to `path` immediately, so a change
+    /// to the theme, tab, window geometry or speed limit survives an
+    /// unexpected exit.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), AppSettingsError> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
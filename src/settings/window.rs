@@ -0,0 +1,75 @@
+//! The main window's last size, position, and the monitor it was on,
+//! restored at startup so it doesn't pop back to the default spot or
+//! drift off-screen after a monitor gets unplugged or reconfigured.
+
+use serde::{Deserialize, Serialize};
+
+/// Plain pixel geometry, independent of any particular UI framework's
+/// window type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub width: f32,
+    pub height: f32,
+    pub x: f32,
+    pub y: f32,
+    /// The monitor this position was saved against (its OS-reported
+    /// name), so a monitor that's since been unplugged or swapped can
+    /// be told apart from one that simply changed resolution.
+    #[serde(default)]
+    pub monitor_name: Option<String>,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self { width: 1280.0, height: 800.0, x: 100.0, y: 100.0, monitor_name: None }
+    }
+}
+
+/// One connected display's name and usable area, as reported by the
+/// windowing backend at startup.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonitorGeometry {
+    pub name: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl MonitorGeometry {
+    fn fully_contains(&self, window: &WindowState) -> bool {
+        window.x >= self.x
+            && window.y >= self.y
+            && window.x + window.width <= self.x + self.width
+            && window.y + window.height <= self.y + self.height
+    }
+}
+
+impl WindowState {
+    /// Validates this saved geometry against the monitors actually
+    /// connected right now, falling back to [`WindowState::default`] if
+    /// the saved monitor is gone or the saved rectangle no longer fits
+    /// on any of them — e.g. after unplugging the second monitor this
+    /// window used to live on. `always_open_on_primary` skips the
+    /// saved position entirely and starts fresh on `primary`.
+    pub fn validated(&self, monitors: &[MonitorGeometry], primary: &MonitorGeometry, always_open_on_primary: bool) -> WindowState {
+        if always_open_on_primary {
+            return WindowState {
+                width: self.width,
+                height: self.height,
+                x: primary.x,
+                y: primary.y,
+                monitor_name: Some(primary.name.clone()),
+            };
+        }
+        let saved_monitor_connected = self
+            .monitor_name
+            .as_ref()
+            .map_or(true, |name| monitors.iter().any(|monitor| &monitor.name == name));
+        if saved_monitor_connected && monitors.iter().any(|monitor| monitor.fully_contains(self)) {
+            self.clone()
+        } else {
+            WindowState::default()
+        }
+    }
+}
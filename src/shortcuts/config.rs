@@ -0,0 +1,158 @@
+//! Configurable keyboard shortcuts, persisted to disk and restored at
+//! startup with sensible defaults if nothing's been customized.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::profiles::activation::Hotkey;
+
+#[derive(Debug)]
+pub enum ShortcutsConfigError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for ShortcutsConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShortcutsConfigError::Io(err) => write!(f, "{err}"),
+            ShortcutsConfigError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutsConfigError {}
+
+impl From<io::Error> for ShortcutsConfigError {
+    fn from(err: io::Error) -> Self {
+        ShortcutsConfigError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ShortcutsConfigError {
+    fn from(err: serde_json::Error) -> Self {
+        ShortcutsConfigError::Parse(err)
+    }
+}
+
+/// An action bound to an in-app shortcut, checked against the UI
+/// framework's input each frame while the main window has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InAppAction {
+    /// Switches to the tab at this 1-based position, matching Ctrl+1
+    /// through Ctrl+6 by default.
+    SwitchToTab(u8),
+    CleanRamNow,
+    /// Cycles to the next/previous tab, wrapping around, for keyboard
+    /// users who'd rather tab through in order than jump straight to a
+    /// number — matches Ctrl+Tab / Ctrl+Shift+Tab by default.
+    NextTab,
+    PreviousTab,
+}
+
+/// An action bound to a global hotkey, registered via
+/// [`crate::profiles::hotkey_listener::HotkeyListener`] so it fires no
+/// matter which window — including a fullscreen game — has focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GlobalAction {
+    CleanRamNow,
+    ToggleFocusMode,
+}
+
+impl GlobalAction {
+    /// A stable id for `HotkeyListener::register`/`unregister`. Starts
+    /// well above any id a profile's own activation hotkey is likely to
+    /// use, since both share the same Windows-wide hotkey-id namespace.
+    pub fn hotkey_id(self) -> i32 {
+        match self {
+            GlobalAction::CleanRamNow => 1_000_001,
+            GlobalAction::ToggleFocusMode => 1_000_002,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedShortcuts {
+    in_app: Vec<(InAppAction, Hotkey)>,
+    global: Vec<(GlobalAction, Hotkey)>,
+}
+
+/// The full set of keyboard shortcuts, both in-app and global.
+#[derive(Debug, Clone)]
+pub struct ShortcutsConfig {
+    in_app: Vec<(InAppAction, Hotkey)>,
+    global: Vec<(GlobalAction, Hotkey)>,
+}
+
+impl ShortcutsConfig {
+    /// Loads `path`, or starts with [`ShortcutsConfig::default`]'s
+    /// bindings if it doesn't exist yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ShortcutsConfigError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                let persisted: PersistedShortcuts = serde_json::from_str(&contents)?;
+                Ok(Self { in_app: persisted.in_app, global: persisted.global })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Saves this config to `path` immediately.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), ShortcutsConfigError> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let persisted = PersistedShortcuts { in_app: self.in_app.clone(), global: self.global.clone() };
+        fs::write(path, serde_json::to_string_pretty(&persisted)?)?;
+        Ok(())
+    }
+
+    pub fn in_app_shortcuts(&self) -> &[(InAppAction, Hotkey)] {
+        &self.in_app
+    }
+
+    pub fn global_shortcuts(&self) -> &[(GlobalAction, Hotkey)] {
+        &self.global
+    }
+
+    /// Rebinds `action`'s in-app shortcut, adding it if it wasn't bound
+    /// before.
+    pub fn set_in_app(&mut self, action: InAppAction, hotkey: Hotkey) {
+        match self.in_app.iter_mut().find(|(bound, _)| *bound == action) {
+            Some((_, existing)) => *existing = hotkey,
+            None => self.in_app.push((action, hotkey)),
+        }
+    }
+
+    /// Rebinds `action`'s global hotkey, adding it if it wasn't bound
+    /// before.
+    pub fn set_global(&mut self, action: GlobalAction, hotkey: Hotkey) {
+        match self.global.iter_mut().find(|(bound, _)| *bound == action) {
+            Some((_, existing)) => *existing = hotkey,
+            None => self.global.push((action, hotkey)),
+        }
+    }
+}
+
+impl Default for ShortcutsConfig {
+    fn default() -> Self {
+        let mut in_app: Vec<(InAppAction, Hotkey)> = (1..=6)
+            .map(|tab| (InAppAction::SwitchToTab(tab), Hotkey::new(tab.to_string()).with_ctrl()))
+            .collect();
+        in_app.push((InAppAction::CleanRamNow, Hotkey::new("C").with_ctrl().with_shift()));
+        in_app.push((InAppAction::NextTab, Hotkey::new("Tab").with_ctrl()));
+        in_app.push((InAppAction::PreviousTab, Hotkey::new("Tab").with_ctrl().with_shift()));
+
+        let global = vec![
+            (GlobalAction::CleanRamNow, Hotkey::new("R").with_ctrl().with_alt()),
+            (GlobalAction::ToggleFocusMode, Hotkey::new("F").with_ctrl().with_alt()),
+        ];
+
+        Self { in_app, global }
+    }
+}
@@ -0,0 +1,10 @@
+//! Keyboard shortcuts: in-app bindings checked against the UI
+//! framework's input each frame, and global hotkeys registered via
+//! [`crate::profiles::hotkey_listener::HotkeyListener`] so they fire
+//! even while a fullscreen game has focus. Both halves share the same
+//! [`crate::profiles::activation::Hotkey`] representation and are
+//! configured and persisted together through [`ShortcutsConfig`].
+
+mod config;
+
+pub use config::{GlobalAction, InAppAction, ShortcutsConfig, ShortcutsConfigError};
@@ -0,0 +1,127 @@
+use std::fmt;
+
+use crate::system::registry::RegistryError;
+
+/// Where a startup entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupLocation {
+    /// `HKCU\...\Run`, applying to the current user only.
+    RunKeyCurrentUser,
+    /// `HKLM\...\Run`, applying to every user on the machine.
+    RunKeyMachine,
+    /// A shortcut in the per-user or all-users Startup folder.
+    StartupFolder,
+}
+
+/// One program Windows launches at logon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartupEntry {
+    pub name: String,
+    pub command: String,
+    pub location: StartupLocation,
+    /// Whether it actually runs at next logon. Disabling an entry keeps
+    /// it listed (like Task Manager's Startup tab) rather than deleting
+    /// it, so re-enabling doesn't require retyping the command.
+    pub enabled: bool,
+}
+
+#[derive(Debug)]
+pub enum StartupEntryError {
+    Registry(RegistryError),
+    Io(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for StartupEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupEntryError::Registry(err) => write!(f, "registry error: {err}"),
+            StartupEntryError::Io(err) => write!(f, "I/O error: {err}"),
+            StartupEntryError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StartupEntryError {}
+
+impl From<RegistryError> for StartupEntryError {
+    fn from(err: RegistryError) -> Self {
+        StartupEntryError::Registry(err)
+    }
+}
+
+impl From<std::io::Error> for StartupEntryError {
+    fn from(err: std::io::Error) -> Self {
+        StartupEntryError::Io(err)
+    }
+}
+
+/// Lists and toggles startup entries, the same way
+/// [`crate::services::manager`] does for services.
+pub struct StartupManager;
+
+impl StartupManager {
+    pub fn new() -> Self {
+        StartupManager
+    }
+
+    /// Every Run-key and Startup-folder entry found, enabled or not.
+    pub fn list_entries(&self) -> Result<Vec<StartupEntry>, StartupEntryError> {
+        imp::list_entries()
+    }
+
+    /// Flips whether `entry` runs at next logon, via the
+    /// `StartupApproved` registry flag Windows itself uses for this
+    /// rather than deleting the Run value (which would lose the command
+    /// line if the user re-enables it later).
+    pub fn set_enabled(&self, entry: &StartupEntry, enabled: bool) -> Result<(), StartupEntryError> {
+        imp::set_enabled(entry, enabled)
+    }
+}
+
+impl Default for StartupManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{StartupEntry, StartupEntryError};
+
+    pub(super) fn list_entries() -> Result<Vec<StartupEntry>, StartupEntryError> {
+        // RegOpenKeyExW + RegEnumValueW over both the per-user and
+        // machine `...\Run` keys, plus a directory listing of the
+        // per-user and all-users Startup folders (resolved via
+        // SHGetKnownFolderPath), then cross-referencing each against the
+        // binary enabled/disabled flag Explorer stores under
+        // `...\Explorer\StartupApproved\Run` (and `\StartupFolder`) to
+        // fill in `enabled`.
+        todo!("enumerate Run keys and the Startup folder via the Win32 registry and shell APIs")
+    }
+
+    pub(super) fn set_enabled(_entry: &StartupEntry, _enabled: bool) -> Result<(), StartupEntryError> {
+        // RegSetValueExW on the matching `StartupApproved` value: a
+        // leading 0x02 byte means enabled, 0x03 means disabled (the
+        // remaining bytes are a FILETIME Explorer doesn't actually
+        // check, so they're left alone).
+        todo!("flip a startup entry's StartupApproved flag via the Win32 registry API")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{StartupEntry, StartupEntryError};
+
+    pub(super) fn list_entries() -> Result<Vec<StartupEntry>, StartupEntryError> {
+        Err(StartupEntryError::Unsupported(
+            "Run keys and the Startup folder don't exist on this platform",
+        ))
+    }
+
+    pub(super) fn set_enabled(_entry: &StartupEntry, _enabled: bool) -> Result<(), StartupEntryError> {
+        Err(StartupEntryError::Unsupported(
+            "Run keys and the Startup folder don't exist on this platform",
+        ))
+    }
+}
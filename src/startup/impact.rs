@@ -0,0 +1,38 @@
+use super::StartupEntry;
+
+/// How much a startup entry is believed to slow down logon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupImpactLevel {
+    Low,
+    Medium,
+    High,
+    /// No measurement is available for this entry yet.
+    NotMeasured,
+}
+
+/// A startup entry's measured (or not yet measured) impact, for the
+/// Startup tab to sort or badge entries by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartupImpact {
+    pub entry_name: String,
+    pub level: StartupImpactLevel,
+    pub measured_delay_ms: Option<u64>,
+}
+
+/// Looks up a startup-impact rating for each of `entries`, defaulting
+/// every one to [`StartupImpactLevel::NotMeasured`] — a real measurement
+/// needs a boot trace correlating each entry's process creation with
+/// when the desktop became interactive, which nothing in this crate
+/// captures yet. Still useful to call: a UI column can render
+/// `NotMeasured` as a dash today and start showing real ratings the day
+/// that trace exists, without changing its shape.
+pub fn measure_impact(entries: &[StartupEntry]) -> Vec<StartupImpact> {
+    entries
+        .iter()
+        .map(|entry| StartupImpact {
+            entry_name: entry.name.clone(),
+            level: StartupImpactLevel::NotMeasured,
+            measured_delay_ms: None,
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+//! Enumerates what launches at logon — the per-user and machine Run
+//! registry keys and the Startup folder; RunOnce keys are not read —
+//! and lets the user flip each entry on or off, the same companion role
+//! [`crate::services::manager`] plays for services. [`impact`] pairs
+//! each entry with a startup-impact rating for the UI to sort or badge
+//! by; no measurement is taken, so every rating is
+//! [`StartupImpactLevel::NotMeasured`].
+
+mod entry;
+mod impact;
+
+pub use entry::{StartupEntry, StartupEntryError, StartupLocation, StartupManager};
+pub use impact::{measure_impact, StartupImpact, StartupImpactLevel};
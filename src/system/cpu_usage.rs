@@ -0,0 +1,107 @@
+//! Reads system-wide (not per-process) CPU usage, for the mini overlay
+//! and anything else that wants one number rather than the CPU tab's
+//! per-process breakdown in [`crate::cpu::history`].
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CpuUsageError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for CpuUsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CpuUsageError::Os(err) => write!(f, "os error: {err}"),
+            CpuUsageError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CpuUsageError {}
+
+/// Samples total CPU usage across every core. The first call after
+/// construction always returns zero, since usage needs two readings of
+/// the cumulative idle/total counters to compute a rate from.
+pub struct CpuUsageMonitor {
+    last: std::sync::Mutex<Option<imp::Cumulative>>,
+}
+
+impl CpuUsageMonitor {
+    pub fn new() -> Self {
+        Self { last: std::sync::Mutex::new(None) }
+    }
+
+    /// Returns the percentage of total CPU time spent non-idle since
+    /// the previous call, 0-100.
+    pub fn used_percent(&self) -> Result<u8, CpuUsageError> {
+        let current = imp::read_cumulative()?;
+        let mut last = self.last.lock().unwrap();
+
+        let percent = match &*last {
+            Some(prev) => {
+                let total_delta = current.total.saturating_sub(prev.total);
+                let idle_delta = current.idle.saturating_sub(prev.idle);
+                if total_delta == 0 {
+                    0
+                } else {
+                    (100 * total_delta.saturating_sub(idle_delta) / total_delta) as u8
+                }
+            }
+            None => 0,
+        };
+
+        *last = Some(current);
+        Ok(percent)
+    }
+}
+
+impl Default for CpuUsageMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::CpuUsageError;
+
+    pub(super) struct Cumulative {
+        pub(super) idle: u64,
+        pub(super) total: u64,
+    }
+
+    pub(super) fn read_cumulative() -> Result<Cumulative, CpuUsageError> {
+        // GetSystemTimes fills idle/kernel/user FILETIMEs; total is
+        // kernel + user (kernel already includes idle time on Windows).
+        todo!("read idle/kernel/user times via Kernel32::GetSystemTimes")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::CpuUsageError;
+    use std::fs;
+
+    pub(super) struct Cumulative {
+        pub(super) idle: u64,
+        pub(super) total: u64,
+    }
+
+    pub(super) fn read_cumulative() -> Result<Cumulative, CpuUsageError> {
+        let contents = fs::read_to_string("/proc/stat").map_err(CpuUsageError::Os)?;
+        let line = contents
+            .lines()
+            .next()
+            .ok_or(CpuUsageError::Unsupported("/proc/stat is empty"))?;
+        let fields: Vec<u64> = line.split_whitespace().skip(1).filter_map(|field| field.parse().ok()).collect();
+        if fields.len() < 4 {
+            return Err(CpuUsageError::Unsupported("cpu line in /proc/stat is too short"));
+        }
+        // user, nice, system, idle, iowait, irq, softirq, steal, ...
+        let idle = fields[3] + fields.get(4).copied().unwrap_or(0);
+        let total: u64 = fields.iter().sum();
+        Ok(Cumulative { idle, total })
+    }
+}
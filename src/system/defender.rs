@@ -0,0 +1,404 @@
+//! Windows Defender's real-time-protection status, queried via WMI
+//! (`root\Microsoft\Windows\Defender`'s `MSFT_MpComputerStatus` class)
+//! instead of spawning `powershell.exe`, so a refresh costs milliseconds
+//! instead of a process spawn, and a fullscreen-game poll loop doesn't
+//! stutter on it.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::scheduler::TimeOfDay;
+
+/// How often the Services tab re-queries Defender's status when the
+/// user has opted into live polling
+/// ([`crate::settings::AppSettings::defender_live_status_enabled`]).
+pub const DEFENDER_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How many samples [`DefenderStatusHistory`] keeps before dropping the
+/// oldest — a day's worth at the poll interval above.
+const DEFENDER_STATUS_HISTORY_CAPACITY: usize = 24 * 60;
+
+/// One point on the Services tab's real-time-protection timeline.
+#[derive(Debug, Clone, Copy)]
+pub struct DefenderStatusSample {
+    pub at: SystemTime,
+    pub real_time_protection_enabled: bool,
+}
+
+/// A rolling window of [`DefenderStatusSample`]s, for the Services
+/// tab's enabled/disabled timeline — the same rolling-`VecDeque`
+/// approach [`crate::system::sampler::StatsSampler`] uses for its
+/// charts, capped instead of time-windowed since a status flip is rare
+/// enough that a sample count is the more useful limit.
+#[derive(Debug, Default)]
+pub struct DefenderStatusHistory {
+    samples: VecDeque<DefenderStatusSample>,
+}
+
+impl DefenderStatusHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `status` as of now, dropping the oldest sample once
+    /// [`DEFENDER_STATUS_HISTORY_CAPACITY`] is exceeded.
+    pub fn record(&mut self, status: DefenderStatus) {
+        if self.samples.len() >= DEFENDER_STATUS_HISTORY_CAPACITY {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(DefenderStatusSample { at: SystemTime::now(), real_time_protection_enabled: status.real_time_protection_enabled });
+    }
+
+    pub fn samples(&self) -> impl Iterator<Item = &DefenderStatusSample> {
+        self.samples.iter()
+    }
+}
+
+/// A snapshot of Defender's protection state, as reported by
+/// `MSFT_MpComputerStatus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefenderStatus {
+    pub antivirus_enabled: bool,
+    pub real_time_protection_enabled: bool,
+    /// Whether Tamper Protection is on — if so, neither this app nor
+    /// PowerShell nor Group Policy can change Defender's settings until
+    /// the user turns it off in Windows Security.
+    pub tamper_protection_enabled: bool,
+}
+
+#[derive(Debug)]
+pub enum DefenderStatusError {
+    Wmi(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for DefenderStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefenderStatusError::Wmi(msg) => write!(f, "WMI query failed: {msg}"),
+            DefenderStatusError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DefenderStatusError {}
+
+/// Queries Defender's current status.
+pub fn query_defender_status() -> Result<DefenderStatus, DefenderStatusError> {
+    imp::query()
+}
+
+/// Which day of the week Defender's scheduled full scan runs on, as
+/// configured via `MpPreference`'s `ScanScheduleDay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanDay {
+    Everyday,
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    /// The scheduled full scan is turned off entirely.
+    Never,
+}
+
+/// Defender's current scheduled-scan configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanSchedule {
+    /// `None` if the quick scan has no fixed time (Defender picks one
+    /// automatically).
+    pub quick_scan_time: Option<TimeOfDay>,
+    pub full_scan_day: ScanDay,
+    pub full_scan_time: TimeOfDay,
+}
+
+/// Reads Defender's current scheduled-scan configuration.
+pub fn read_scan_schedule() -> Result<ScanSchedule, DefenderStatusError> {
+    imp::read_scan_schedule()
+}
+
+/// Writes `schedule` back verbatim, for restoring a previously-read
+/// schedule on profile deactivation.
+pub fn set_scan_schedule(schedule: &ScanSchedule) -> Result<(), DefenderStatusError> {
+    imp::write_scan_schedule(schedule)
+}
+
+/// Moves the quick and full scan to `quick_scan_time`/`full_scan_time`,
+/// for pushing them into off-hours so they don't land mid-game.
+pub fn reschedule_to_off_hours(
+    quick_scan_time: TimeOfDay,
+    full_scan_day: ScanDay,
+    full_scan_time: TimeOfDay,
+) -> Result<(), DefenderStatusError> {
+    set_scan_schedule(&ScanSchedule { quick_scan_time: Some(quick_scan_time), full_scan_day, full_scan_time })
+}
+
+/// Turns off Defender's own scheduled scan entirely, for profiles that
+/// would rather trigger a scan through this app's own idle-gated
+/// scheduler (see [`crate::scheduler::TaskConstraints::min_idle_minutes`])
+/// than have Defender pick its own time.
+pub fn disable_scheduled_scans_for_idle_only() -> Result<(), DefenderStatusError> {
+    set_scan_schedule(&ScanSchedule { quick_scan_time: None, full_scan_day: ScanDay::Never, full_scan_time: TimeOfDay { hour: 0, minute: 0 } })
+}
+
+/// Turns real-time protection on or off.
+pub fn set_real_time_protection_enabled(enabled: bool) -> Result<(), DefenderStatusError> {
+    imp::set_real_time_protection_enabled(enabled)
+}
+
+/// Where a real-time-protection toggle is at, for callers (the Services
+/// UI, the scheduler engine) that want to show progress instead of
+/// freezing on a blocking call.
+#[derive(Debug)]
+pub enum DefenderToggleProgress {
+    /// Querying current status and checking Tamper Protection isn't
+    /// going to block the write.
+    Checking,
+    /// The WMI write is in flight.
+    Applying,
+    /// Re-querying status to confirm the write actually took — Defender
+    /// sometimes silently ignores a write a moment before Tamper
+    /// Protection re-asserts itself.
+    Verifying,
+    Done(Result<(), DefenderStatusError>),
+}
+
+/// Drives [`set_real_time_protection_enabled`] on a background thread,
+/// reporting [`DefenderToggleProgress`] as it goes, so neither the
+/// Services UI nor the scheduler engine blocks on a WMI round-trip.
+pub struct DefenderService;
+
+impl DefenderService {
+    pub fn new() -> Self {
+        DefenderService
+    }
+
+    pub fn toggle_real_time_protection(&self, enabled: bool) -> Receiver<DefenderToggleProgress> {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = tx.send(DefenderToggleProgress::Checking);
+            match query_defender_status() {
+                Ok(status) if status.tamper_protection_enabled => {
+                    let _ = tx.send(DefenderToggleProgress::Done(Err(DefenderStatusError::Wmi(
+                        explain_toggle_failure(&status).unwrap_or_default().to_string(),
+                    ))));
+                    return;
+                }
+                Err(err) => {
+                    let _ = tx.send(DefenderToggleProgress::Done(Err(err)));
+                    return;
+                }
+                Ok(_) => {}
+            }
+
+            let _ = tx.send(DefenderToggleProgress::Applying);
+            if let Err(err) = set_real_time_protection_enabled(enabled) {
+                let _ = tx.send(DefenderToggleProgress::Done(Err(err)));
+                return;
+            }
+
+            let _ = tx.send(DefenderToggleProgress::Verifying);
+            let result = match query_defender_status() {
+                Ok(status) if status.real_time_protection_enabled == enabled => Ok(()),
+                Ok(_) => Err(DefenderStatusError::Wmi(
+                    "the write succeeded but real-time protection didn't change — Tamper Protection \
+                     may have reverted it"
+                        .to_string(),
+                )),
+                Err(err) => Err(err),
+            };
+            let _ = tx.send(DefenderToggleProgress::Done(result));
+        });
+        rx
+    }
+}
+
+impl Default for DefenderService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Explains why a toggle attempt on `status` would fail (or did fail),
+/// for the Defender tab to show next to a greyed-out switch, instead of
+/// a bare "access denied".
+pub fn explain_toggle_failure(status: &DefenderStatus) -> Option<&'static str> {
+    status.tamper_protection_enabled.then_some(
+        "Tamper Protection is on, which blocks changes to Defender settings from this app, \
+         PowerShell, and Group Policy alike. Turn it off in Windows Security > Virus & threat \
+         protection settings first.",
+    )
+}
+
+#[cfg(windows)]
+mod imp {
+    use serde::{Deserialize, Serialize};
+    use wmi::{COMLibrary, WMIConnection, WMIError};
+
+    use crate::scheduler::TimeOfDay;
+
+    use super::{DefenderStatus, DefenderStatusError, ScanDay, ScanSchedule};
+
+    const NAMESPACE: &str = "ROOT\\Microsoft\\Windows\\Defender";
+
+    impl From<WMIError> for DefenderStatusError {
+        fn from(err: WMIError) -> Self {
+            DefenderStatusError::Wmi(err.to_string())
+        }
+    }
+
+    fn connect() -> Result<WMIConnection, DefenderStatusError> {
+        let com = COMLibrary::new().map_err(|err| DefenderStatusError::Wmi(err.to_string()))?;
+        Ok(WMIConnection::with_namespace_path(NAMESPACE, com)?)
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "MSFT_MpComputerStatus")]
+    #[serde(rename_all = "PascalCase")]
+    struct MpComputerStatus {
+        antivirus_enabled: bool,
+        real_time_protection_enabled: bool,
+        is_tamper_protected: bool,
+    }
+
+    pub(super) fn query() -> Result<DefenderStatus, DefenderStatusError> {
+        let conn = connect()?;
+        let rows: Vec<MpComputerStatus> = conn.query()?;
+        let status = rows
+            .into_iter()
+            .next()
+            .ok_or_else(|| DefenderStatusError::Wmi("MSFT_MpComputerStatus returned no rows".to_string()))?;
+        Ok(DefenderStatus {
+            antivirus_enabled: status.antivirus_enabled,
+            real_time_protection_enabled: status.real_time_protection_enabled,
+            tamper_protection_enabled: status.is_tamper_protected,
+        })
+    }
+
+    /// `MSFT_MpPreference`'s `ScanScheduleDay` is an integer, 0 meaning
+    /// "every day" and 1-7 meaning Sunday-Saturday, matching
+    /// `Set-MpPreference -ScanScheduleDay`'s own encoding.
+    fn scan_day_from_wmi(value: u8) -> ScanDay {
+        match value {
+            1 => ScanDay::Sunday,
+            2 => ScanDay::Monday,
+            3 => ScanDay::Tuesday,
+            4 => ScanDay::Wednesday,
+            5 => ScanDay::Thursday,
+            6 => ScanDay::Friday,
+            7 => ScanDay::Saturday,
+            8 => ScanDay::Never,
+            _ => ScanDay::Everyday,
+        }
+    }
+
+    fn scan_day_to_wmi(day: ScanDay) -> u8 {
+        match day {
+            ScanDay::Everyday => 0,
+            ScanDay::Sunday => 1,
+            ScanDay::Monday => 2,
+            ScanDay::Tuesday => 3,
+            ScanDay::Wednesday => 4,
+            ScanDay::Thursday => 5,
+            ScanDay::Friday => 6,
+            ScanDay::Saturday => 7,
+            ScanDay::Never => 8,
+        }
+    }
+
+    /// `MSFT_MpPreference` stores a scan time of day as minutes since
+    /// midnight; `None` (Defender picks automatically) is represented as
+    /// the sentinel value Microsoft's own tooling uses, u32::MAX.
+    fn time_of_day_from_wmi(minutes: u32) -> Option<TimeOfDay> {
+        (minutes != u32::MAX).then(|| TimeOfDay { hour: (minutes / 60) as u8, minute: (minutes % 60) as u8 })
+    }
+
+    fn time_of_day_to_wmi(time: Option<TimeOfDay>) -> u32 {
+        time.map(|time| time.hour as u32 * 60 + time.minute as u32).unwrap_or(u32::MAX)
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename = "MSFT_MpPreference")]
+    #[serde(rename_all = "PascalCase")]
+    struct MpPreferenceSchedule {
+        scan_schedule_quick_scan_time: u32,
+        scan_schedule_day: u8,
+        scan_schedule_time: u32,
+    }
+
+    pub(super) fn read_scan_schedule() -> Result<ScanSchedule, DefenderStatusError> {
+        let conn = connect()?;
+        let rows: Vec<MpPreferenceSchedule> = conn.query()?;
+        let preference = rows
+            .into_iter()
+            .next()
+            .ok_or_else(|| DefenderStatusError::Wmi("MSFT_MpPreference returned no rows".to_string()))?;
+        Ok(ScanSchedule {
+            quick_scan_time: time_of_day_from_wmi(preference.scan_schedule_quick_scan_time),
+            full_scan_day: scan_day_from_wmi(preference.scan_schedule_day),
+            full_scan_time: time_of_day_from_wmi(preference.scan_schedule_time).unwrap_or(TimeOfDay { hour: 2, minute: 0 }),
+        })
+    }
+
+    #[derive(Serialize)]
+    struct SetScanScheduleParams {
+        #[serde(rename = "ScanScheduleQuickScanTime")]
+        scan_schedule_quick_scan_time: u32,
+        #[serde(rename = "ScanScheduleDay")]
+        scan_schedule_day: u8,
+        #[serde(rename = "ScanScheduleTime")]
+        scan_schedule_time: u32,
+    }
+
+    pub(super) fn write_scan_schedule(schedule: &ScanSchedule) -> Result<(), DefenderStatusError> {
+        let conn = connect()?;
+        let params = SetScanScheduleParams {
+            scan_schedule_quick_scan_time: time_of_day_to_wmi(schedule.quick_scan_time),
+            scan_schedule_day: scan_day_to_wmi(schedule.full_scan_day),
+            scan_schedule_time: time_of_day_to_wmi(Some(schedule.full_scan_time)),
+        };
+        conn.exec_class_method::<_, ()>("MSFT_MpPreference", "Set", params)?;
+        Ok(())
+    }
+
+    #[derive(Serialize)]
+    struct SetRealTimeProtectionParams {
+        #[serde(rename = "DisableRealtimeMonitoring")]
+        disable_realtime_monitoring: bool,
+    }
+
+    pub(super) fn set_real_time_protection_enabled(enabled: bool) -> Result<(), DefenderStatusError> {
+        let conn = connect()?;
+        let params = SetRealTimeProtectionParams { disable_realtime_monitoring: !enabled };
+        conn.exec_class_method::<_, ()>("MSFT_MpPreference", "Set", params)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{DefenderStatus, DefenderStatusError, ScanSchedule};
+
+    pub(super) fn query() -> Result<DefenderStatus, DefenderStatusError> {
+        Err(DefenderStatusError::Unsupported("Windows Defender doesn't exist on this platform"))
+    }
+
+    pub(super) fn read_scan_schedule() -> Result<ScanSchedule, DefenderStatusError> {
+        Err(DefenderStatusError::Unsupported("Windows Defender doesn't exist on this platform"))
+    }
+
+    pub(super) fn write_scan_schedule(_schedule: &ScanSchedule) -> Result<(), DefenderStatusError> {
+        Err(DefenderStatusError::Unsupported("Windows Defender doesn't exist on this platform"))
+    }
+
+    pub(super) fn set_real_time_protection_enabled(_enabled: bool) -> Result<(), DefenderStatusError> {
+        Err(DefenderStatusError::Unsupported("Windows Defender doesn't exist on this platform"))
+    }
+}
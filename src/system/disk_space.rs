@@ -0,0 +1,71 @@
+//! Reads how much free space remains on the volume containing a given
+//! path, for disk-space-based task conditions and warnings.
+
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum DiskSpaceError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for DiskSpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiskSpaceError::Os(err) => write!(f, "os error: {err}"),
+            DiskSpaceError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DiskSpaceError {}
+
+/// Reads free space for the volume containing a given path.
+pub struct DiskSpaceMonitor;
+
+impl DiskSpaceMonitor {
+    pub fn new() -> Self {
+        DiskSpaceMonitor
+    }
+
+    /// Returns the number of free bytes on the volume containing `path`.
+    pub fn free_bytes(&self, path: &Path) -> Result<u64, DiskSpaceError> {
+        imp::free_bytes(path)
+    }
+}
+
+impl Default for DiskSpaceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::DiskSpaceError;
+    use std::path::Path;
+
+    pub(super) fn free_bytes(_path: &Path) -> Result<u64, DiskSpaceError> {
+        // GetDiskFreeSpaceExW on the volume root resolved from `path`.
+        todo!("read free space via Kernel32::GetDiskFreeSpaceExW")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::DiskSpaceError;
+    use std::ffi::CString;
+    use std::path::Path;
+
+    pub(super) fn free_bytes(path: &Path) -> Result<u64, DiskSpaceError> {
+        let c_path = CString::new(path.to_string_lossy().as_bytes())
+            .map_err(|_| DiskSpaceError::Unsupported("path contains an embedded NUL byte"))?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        let rc = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+        if rc != 0 {
+            return Err(DiskSpaceError::Os(std::io::Error::last_os_error()));
+        }
+        Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+    }
+}
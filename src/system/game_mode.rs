@@ -0,0 +1,216 @@
+//! Windows Game Mode, GameDVR (Xbox Game Bar background recording), and
+//! per-exe fullscreen optimization overrides. All three live in the
+//! registry; there's no non-Windows equivalent, so every entry point
+//! returns [`GameModeError::Unsupported`] elsewhere.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum GameModeError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for GameModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameModeError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            GameModeError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GameModeError {}
+
+/// Reads and writes Windows' Game Mode, GameDVR, and per-exe fullscreen
+/// optimization settings.
+pub struct GameModeController;
+
+impl GameModeController {
+    pub fn new() -> Self {
+        GameModeController
+    }
+
+    /// Toggles Windows' own Game Mode (Settings > Gaming > Game Mode).
+    pub fn set_game_mode_enabled(&self, enabled: bool) -> Result<(), GameModeError> {
+        imp::set_game_mode_enabled(enabled)
+    }
+
+    pub fn game_mode_enabled(&self) -> Result<bool, GameModeError> {
+        imp::game_mode_enabled()
+    }
+
+    /// Toggles GameDVR, the background capture service behind the Xbox
+    /// Game Bar overlay. Disabling it is a common fix for DPC-latency
+    /// spikes during capture.
+    pub fn set_game_dvr_enabled(&self, enabled: bool) -> Result<(), GameModeError> {
+        imp::set_game_dvr_enabled(enabled)
+    }
+
+    pub fn game_dvr_enabled(&self) -> Result<bool, GameModeError> {
+        imp::game_dvr_enabled()
+    }
+
+    /// Sets the "Disable fullscreen optimizations" compatibility flag
+    /// for `exe_name`, the same checkbox as the exe's Properties >
+    /// Compatibility tab.
+    pub fn set_fullscreen_optimizations_disabled(&self, exe_name: &str, disabled: bool) -> Result<(), GameModeError> {
+        imp::set_fullscreen_optimizations_disabled(exe_name, disabled)
+    }
+
+    pub fn fullscreen_optimizations_disabled(&self, exe_name: &str) -> Result<bool, GameModeError> {
+        imp::fullscreen_optimizations_disabled(exe_name)
+    }
+
+    /// Whether the Xbox Game Bar AppX package (`Microsoft.XboxGamingOverlay`)
+    /// is installed at all, for the profile editor to hide Game Bar
+    /// controls on a machine that never had it.
+    pub fn game_bar_installed(&self) -> Result<bool, GameModeError> {
+        imp::game_bar_installed()
+    }
+
+    /// Toggles whether the Xbox Game Bar opens at all (the Win+G
+    /// overlay), independent of GameDVR's background recording.
+    pub fn set_game_bar_enabled(&self, enabled: bool) -> Result<(), GameModeError> {
+        imp::set_game_bar_enabled(enabled)
+    }
+
+    pub fn game_bar_enabled(&self) -> Result<bool, GameModeError> {
+        imp::game_bar_enabled()
+    }
+
+    /// Toggles whether the Xbox Game Bar's AppX package is allowed to
+    /// run background tasks (the same per-app switch as Settings >
+    /// Apps > Xbox Game Bar > "Let this app run in the background"),
+    /// separate from disabling the overlay itself.
+    pub fn set_game_bar_background_tasks_enabled(&self, enabled: bool) -> Result<(), GameModeError> {
+        imp::set_game_bar_background_tasks_enabled(enabled)
+    }
+
+    pub fn game_bar_background_tasks_enabled(&self) -> Result<bool, GameModeError> {
+        imp::game_bar_background_tasks_enabled()
+    }
+}
+
+impl Default for GameModeController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::GameModeError;
+
+    pub(super) fn set_game_mode_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        // HKCU\Software\Microsoft\GameBar, value "AutoGameModeEnabled"
+        // (DWORD, 1 = on).
+        todo!("write AutoGameModeEnabled under HKCU\\Software\\Microsoft\\GameBar")
+    }
+
+    pub(super) fn game_mode_enabled() -> Result<bool, GameModeError> {
+        todo!("read AutoGameModeEnabled under HKCU\\Software\\Microsoft\\GameBar")
+    }
+
+    pub(super) fn set_game_dvr_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        // HKCU\System\GameConfigStore, value "GameDVR_Enabled" (DWORD),
+        // plus the policy-equivalent HKCU\Software\Microsoft\Windows\
+        // CurrentVersion\GameDVR, value "AppCaptureEnabled".
+        todo!("write GameDVR_Enabled under HKCU\\System\\GameConfigStore")
+    }
+
+    pub(super) fn game_dvr_enabled() -> Result<bool, GameModeError> {
+        todo!("read GameDVR_Enabled under HKCU\\System\\GameConfigStore")
+    }
+
+    pub(super) fn set_fullscreen_optimizations_disabled(_exe_name: &str, _disabled: bool) -> Result<(), GameModeError> {
+        // HKCU\Software\Microsoft\Windows NT\CurrentVersion\AppCompatFlags\Layers,
+        // value name is the exe's full path, data is a space-separated
+        // layer flag list; toggling adds/removes the "DISABLEDXMAXIMIZEDWINDOWEDMODE"
+        // token from whatever's already there.
+        todo!("toggle the DISABLEDXMAXIMIZEDWINDOWEDMODE layer flag under AppCompatFlags\\Layers")
+    }
+
+    pub(super) fn fullscreen_optimizations_disabled(_exe_name: &str) -> Result<bool, GameModeError> {
+        todo!("read the exe's layer flags under AppCompatFlags\\Layers")
+    }
+
+    pub(super) fn game_bar_installed() -> Result<bool, GameModeError> {
+        // Windows.Management.Deployment.PackageManager::FindPackagesForUser
+        // for the family name Microsoft.XboxGamingOverlay_8wekyb3d8bbwe,
+        // the same lookup `Get-AppxPackage Microsoft.XboxGamingOverlay`
+        // does internally.
+        todo!("query installed AppX packages via the PackageManager WinRT API")
+    }
+
+    pub(super) fn set_game_bar_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        // HKCU\Software\Microsoft\GameBar, value "UseNexusForGameBarEnabled"
+        // (DWORD, 0 disables the Win+G overlay entirely).
+        todo!("write UseNexusForGameBarEnabled under HKCU\\Software\\Microsoft\\GameBar")
+    }
+
+    pub(super) fn game_bar_enabled() -> Result<bool, GameModeError> {
+        todo!("read UseNexusForGameBarEnabled under HKCU\\Software\\Microsoft\\GameBar")
+    }
+
+    pub(super) fn set_game_bar_background_tasks_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        // HKCU\Software\Microsoft\Windows\CurrentVersion\BackgroundAccessApplications,
+        // under the Xbox Game Bar package family name's subkey, value
+        // "Disabled" (DWORD) — the same switch Settings > Apps > Xbox
+        // Game Bar > "Let this app run in the background" flips.
+        todo!("write the Xbox Game Bar's BackgroundAccessApplications entry")
+    }
+
+    pub(super) fn game_bar_background_tasks_enabled() -> Result<bool, GameModeError> {
+        todo!("read the Xbox Game Bar's BackgroundAccessApplications entry")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::GameModeError;
+
+    pub(super) fn set_game_mode_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        Err(GameModeError::Unsupported("Game Mode is a Windows-only concept"))
+    }
+
+    pub(super) fn game_mode_enabled() -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("Game Mode is a Windows-only concept"))
+    }
+
+    pub(super) fn set_game_dvr_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        Err(GameModeError::Unsupported("GameDVR is a Windows-only concept"))
+    }
+
+    pub(super) fn game_dvr_enabled() -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("GameDVR is a Windows-only concept"))
+    }
+
+    pub(super) fn set_fullscreen_optimizations_disabled(_exe_name: &str, _disabled: bool) -> Result<(), GameModeError> {
+        Err(GameModeError::Unsupported("fullscreen optimizations are a Windows-only concept"))
+    }
+
+    pub(super) fn fullscreen_optimizations_disabled(_exe_name: &str) -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("fullscreen optimizations are a Windows-only concept"))
+    }
+
+    pub(super) fn game_bar_installed() -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("the Xbox Game Bar is a Windows-only concept"))
+    }
+
+    pub(super) fn set_game_bar_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        Err(GameModeError::Unsupported("the Xbox Game Bar is a Windows-only concept"))
+    }
+
+    pub(super) fn game_bar_enabled() -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("the Xbox Game Bar is a Windows-only concept"))
+    }
+
+    pub(super) fn set_game_bar_background_tasks_enabled(_enabled: bool) -> Result<(), GameModeError> {
+        Err(GameModeError::Unsupported("the Xbox Game Bar is a Windows-only concept"))
+    }
+
+    pub(super) fn game_bar_background_tasks_enabled() -> Result<bool, GameModeError> {
+        Err(GameModeError::Unsupported("the Xbox Game Bar is a Windows-only concept"))
+    }
+}
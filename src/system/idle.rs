@@ -0,0 +1,68 @@
+//! Tracks how long the user has been idle (no keyboard/mouse input), so
+//! a heavy background task can wait for the user to step away instead
+//! of running mid-game.
+
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum IdleError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for IdleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdleError::Os(err) => write!(f, "os error: {err}"),
+            IdleError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for IdleError {}
+
+/// Reads how long it's been since the user last touched the keyboard or
+/// mouse.
+pub struct IdleMonitor;
+
+impl IdleMonitor {
+    pub fn new() -> Self {
+        IdleMonitor
+    }
+
+    /// Returns how long the system has been idle.
+    pub fn idle_duration(&self) -> Result<Duration, IdleError> {
+        imp::idle_duration()
+    }
+}
+
+impl Default for IdleMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::IdleError;
+    use std::time::Duration;
+
+    pub(super) fn idle_duration() -> Result<Duration, IdleError> {
+        // GetLastInputInfo fills a LASTINPUTINFO with the tick count of
+        // the last input event; idle time is GetTickCount() minus that.
+        todo!("read idle time via User32::GetLastInputInfo")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::IdleError;
+    use std::time::Duration;
+
+    pub(super) fn idle_duration() -> Result<Duration, IdleError> {
+        Err(IdleError::Unsupported(
+            "idle detection needs a platform input API (e.g. XScreenSaverQueryInfo on X11), not implemented yet",
+        ))
+    }
+}
@@ -0,0 +1,106 @@
+//! Ensures only one instance of the app runs at a time, so a second
+//! launch doesn't start a second scheduler fighting the first one over
+//! the same task store. A second launch finds the first instance's
+//! named mutex already held, signals it through a named pipe to raise
+//! and focus its window, and exits instead of opening a second window.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum InstanceError {
+    Os(std::io::Error),
+}
+
+impl fmt::Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstanceError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InstanceError {}
+
+/// The name the single-instance mutex and activation pipe are
+/// registered under, shared across every launch so they find each
+/// other regardless of install path.
+const INSTANCE_NAME: &str = "GameBoosterSingleInstance";
+
+/// What claiming the single-instance slot found.
+pub enum InstanceClaim {
+    /// No other instance was running; `InstanceGuard` holds the slot
+    /// until dropped.
+    Primary(InstanceGuard),
+    /// Another instance already holds the slot. The caller should call
+    /// [`signal_existing_instance`] and exit without doing anything
+    /// else (opening its own window, starting its own scheduler).
+    AlreadyRunning,
+}
+
+/// Holds the named mutex claiming this process as the single instance;
+/// releases it on drop, so a crashed instance doesn't permanently lock
+/// out every future launch.
+pub struct InstanceGuard(imp::MutexHandle);
+
+impl InstanceGuard {
+    /// Drains "activate me" signals a second launch has sent since the
+    /// last call, for the embedder's update loop to act on by raising
+    /// and focusing its window — there's no render loop in this crate
+    /// to poll this automatically.
+    pub fn poll_activation_requests(&mut self) -> bool {
+        imp::poll_activation_requests(&mut self.0)
+    }
+}
+
+/// Claims the single-instance slot, or reports that another instance
+/// already holds it. Called once at startup, before constructing
+/// `CleanRamApp`.
+pub fn claim_single_instance() -> Result<InstanceClaim, InstanceError> {
+    imp::claim()
+}
+
+/// Signals whichever instance currently holds the slot to raise and
+/// focus its window. Called by a second launch immediately after
+/// [`claim_single_instance`] reports [`InstanceClaim::AlreadyRunning`],
+/// right before it exits.
+pub fn signal_existing_instance() -> Result<(), InstanceError> {
+    imp::signal_existing()
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{InstanceClaim, InstanceError, INSTANCE_NAME};
+
+    pub struct MutexHandle;
+
+    pub fn claim() -> Result<InstanceClaim, InstanceError> {
+        todo!("CreateMutexW(None, false, \"Local\\\\{INSTANCE_NAME}\"), checking GetLastError() == ERROR_ALREADY_EXISTS to tell Primary from AlreadyRunning")
+    }
+
+    pub fn signal_existing() -> Result<(), InstanceError> {
+        todo!("connect to \\\\.\\pipe\\{INSTANCE_NAME} and write a single activation byte")
+    }
+
+    pub fn poll_activation_requests(_handle: &mut MutexHandle) -> bool {
+        todo!("non-blocking read of pending connections on the \\\\.\\pipe\\{INSTANCE_NAME} server started alongside the mutex")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{InstanceClaim, InstanceError, INSTANCE_NAME};
+
+    pub struct MutexHandle;
+
+    pub fn claim() -> Result<InstanceClaim, InstanceError> {
+        todo!("flock() an exclusive lock on a /run or /tmp lockfile named {INSTANCE_NAME}, falling back to AlreadyRunning if it's already held")
+    }
+
+    pub fn signal_existing() -> Result<(), InstanceError> {
+        todo!("connect to the {INSTANCE_NAME} unix domain socket and write a single activation byte")
+    }
+
+    pub fn poll_activation_requests(_handle: &mut MutexHandle) -> bool {
+        todo!("non-blocking accept() on the {INSTANCE_NAME} unix domain socket listener started alongside the lockfile")
+    }
+}
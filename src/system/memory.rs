@@ -0,0 +1,75 @@
+//! Reads how much physical RAM is currently in use, for RAM-based task
+//! conditions and the dashboard's memory gauge.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum MemoryError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::Os(err) => write!(f, "os error: {err}"),
+            MemoryError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+/// Reads how much of the system's physical RAM is currently in use.
+pub struct MemoryMonitor;
+
+impl MemoryMonitor {
+    pub fn new() -> Self {
+        MemoryMonitor
+    }
+
+    /// Returns the percentage of physical RAM currently in use, 0-100.
+    pub fn used_percent(&self) -> Result<u8, MemoryError> {
+        imp::used_percent()
+    }
+}
+
+impl Default for MemoryMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::MemoryError;
+
+    pub(super) fn used_percent() -> Result<u8, MemoryError> {
+        // GlobalMemoryStatusEx fills a MEMORYSTATUSEX whose dwMemoryLoad
+        // field is already the used-percentage Windows computes itself.
+        todo!("read memory load via Kernel32::GlobalMemoryStatusEx")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::MemoryError;
+    use std::fs;
+
+    pub(super) fn used_percent() -> Result<u8, MemoryError> {
+        let contents = fs::read_to_string("/proc/meminfo").map_err(MemoryError::Os)?;
+        let total = meminfo_value(&contents, "MemTotal:")
+            .ok_or(MemoryError::Unsupported("MemTotal missing from /proc/meminfo"))?;
+        let available = meminfo_value(&contents, "MemAvailable:")
+            .ok_or(MemoryError::Unsupported("MemAvailable missing from /proc/meminfo"))?;
+        if total == 0 {
+            return Ok(0);
+        }
+        let used = total.saturating_sub(available);
+        Ok(((used * 100) / total) as u8)
+    }
+
+    fn meminfo_value(contents: &str, key: &str) -> Option<u64> {
+        contents.lines().find(|line| line.starts_with(key))?.split_whitespace().nth(1)?.parse().ok()
+    }
+}
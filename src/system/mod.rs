@@ -0,0 +1,21 @@
+//! OS-level helpers that don't belong to any single tab: RAM cleanup and
+//! friends.
+
+pub mod cpu_usage;
+pub mod defender;
+pub mod disk_space;
+pub mod drivers;
+pub mod event_log;
+pub mod focus_assist;
+pub mod game_mode;
+pub mod idle;
+pub mod instance;
+pub mod memory;
+pub mod power_source;
+pub mod powershell;
+pub mod ram;
+pub mod registry;
+pub mod sampler;
+pub mod storage_location;
+pub mod superfetch;
+pub mod system_info;
@@ -0,0 +1,86 @@
+//! Detects whether the system is running on AC power or battery, so a
+//! heavy background task can defer itself until it's plugged in.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    Ac,
+    Battery,
+}
+
+#[derive(Debug)]
+pub enum PowerSourceError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for PowerSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerSourceError::Os(err) => write!(f, "os error: {err}"),
+            PowerSourceError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for PowerSourceError {}
+
+/// Reads the system's current power source.
+pub struct PowerSourceMonitor;
+
+impl PowerSourceMonitor {
+    pub fn new() -> Self {
+        PowerSourceMonitor
+    }
+
+    /// Returns whether the system is currently on AC or battery power. A
+    /// desktop with no battery is always [`PowerSource::Ac`].
+    pub fn current(&self) -> Result<PowerSource, PowerSourceError> {
+        imp::current()
+    }
+}
+
+impl Default for PowerSourceMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{PowerSource, PowerSourceError};
+
+    pub(super) fn current() -> Result<PowerSource, PowerSourceError> {
+        // GetSystemPowerStatus fills a SYSTEM_POWER_STATUS whose
+        // ACLineStatus field is 0 (battery), 1 (AC), or 255 (unknown).
+        todo!("read AC line status via Kernel32::GetSystemPowerStatus")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{PowerSource, PowerSourceError};
+    use std::fs;
+
+    /// A system with no battery entries under `/sys/class/power_supply`
+    /// has no battery at all and is always treated as being on AC power.
+    pub(super) fn current() -> Result<PowerSource, PowerSourceError> {
+        let entries = fs::read_dir("/sys/class/power_supply").map_err(PowerSourceError::Os)?;
+        for entry in entries {
+            let entry = entry.map_err(PowerSourceError::Os)?;
+            let Ok(kind) = fs::read_to_string(entry.path().join("type")) else {
+                continue;
+            };
+            if kind.trim() != "Battery" {
+                continue;
+            }
+            if let Ok(status) = fs::read_to_string(entry.path().join("status")) {
+                if status.trim() == "Discharging" {
+                    return Ok(PowerSource::Battery);
+                }
+            }
+        }
+        Ok(PowerSource::Ac)
+    }
+}
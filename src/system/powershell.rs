@@ -0,0 +1,272 @@
+//! Runs PowerShell scripts with a timeout, cooperative cancellation, and
+//! incremental output, for the handful of operations (still) not worth
+//! reimplementing against a native API directly — see [`super::defender`]
+//! for why most Defender queries avoid this path.
+
+use std::fmt;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub enum PowerShellError {
+    Io(std::io::Error),
+    TimedOut,
+    Cancelled,
+}
+
+impl fmt::Display for PowerShellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerShellError::Io(err) => write!(f, "io error: {err}"),
+            PowerShellError::TimedOut => write!(f, "script timed out"),
+            PowerShellError::Cancelled => write!(f, "script was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for PowerShellError {}
+
+impl From<std::io::Error> for PowerShellError {
+    fn from(err: std::io::Error) -> Self {
+        PowerShellError::Io(err)
+    }
+}
+
+/// A line of output from a running script, or its final result.
+#[derive(Debug)]
+pub enum ScriptOutput {
+    Stdout(String),
+    Stderr(String),
+    Finished(Result<i32, PowerShellError>),
+}
+
+/// Lets the caller stop a running script early; cloning shares the same
+/// underlying flag, so the UI thread and the worker thread can both hold
+/// one.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Runs PowerShell scripts out-of-process.
+pub struct PowerShellRunner;
+
+impl PowerShellRunner {
+    pub fn new() -> Self {
+        PowerShellRunner
+    }
+
+    /// Starts `script` in the background and returns a channel of
+    /// [`ScriptOutput`] as it streams in. The script is killed if
+    /// `timeout` elapses or `token` is cancelled before it finishes.
+    ///
+    /// `script` is passed via `-EncodedCommand` (UTF-16LE, base64) rather
+    /// than `-Command` so embedded quotes, `$`, and backticks never need
+    /// shell-escaping.
+    pub fn run_streaming(
+        &self,
+        script: &str,
+        timeout: Option<Duration>,
+        token: CancellationToken,
+    ) -> Result<Receiver<ScriptOutput>, PowerShellError> {
+        let mut child = Command::new(resolve_executable())
+            .args(["-NoProfile", "-NonInteractive", "-EncodedCommand", &encode_command(script)])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let (tx, rx) = mpsc::channel();
+        spawn_reader(child.stdout.take(), tx.clone(), ScriptOutput::Stdout);
+        spawn_reader(child.stderr.take(), tx.clone(), ScriptOutput::Stderr);
+
+        thread::spawn(move || {
+            let result = wait_with_timeout(&mut child, timeout, &token);
+            let _ = tx.send(ScriptOutput::Finished(result));
+        });
+
+        Ok(rx)
+    }
+
+    /// Runs a short diagnostic script synchronously to find out whether
+    /// the resolved PowerShell host is running in Constrained Language
+    /// Mode or under an execution policy that would silently no-op the
+    /// caller's real script.
+    pub fn probe_environment(&self) -> Result<ExecutionEnvironment, PowerShellError> {
+        let rx = self.run_streaming(
+            "$ExecutionContext.SessionState.LanguageMode; Get-ExecutionPolicy",
+            Some(Duration::from_secs(10)),
+            CancellationToken::new(),
+        )?;
+
+        let mut lines = Vec::new();
+        for output in rx {
+            match output {
+                ScriptOutput::Stdout(line) => lines.push(line),
+                ScriptOutput::Finished(result) => {
+                    result?;
+                    break;
+                }
+                ScriptOutput::Stderr(_) => {}
+            }
+        }
+
+        let language_mode = match lines.first().map(String::as_str) {
+            Some("ConstrainedLanguage") => LanguageMode::ConstrainedLanguage,
+            Some("RestrictedLanguage") => LanguageMode::RestrictedLanguage,
+            Some("NoLanguage") => LanguageMode::NoLanguage,
+            _ => LanguageMode::FullLanguage,
+        };
+        let execution_policy_blocked = lines.get(1).map(String::as_str) == Some("Restricted");
+
+        Ok(ExecutionEnvironment { executable: resolve_executable(), language_mode, execution_policy_blocked })
+    }
+}
+
+impl Default for PowerShellRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What `LanguageMode` the resolved PowerShell host is running under;
+/// anything other than [`LanguageMode::FullLanguage`] means some of the
+/// .NET-reflection tricks other scripts rely on will silently fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LanguageMode {
+    FullLanguage,
+    ConstrainedLanguage,
+    RestrictedLanguage,
+    NoLanguage,
+}
+
+/// What [`PowerShellRunner::probe_environment`] found about the host
+/// that will actually run scripts.
+#[derive(Debug, Clone, Copy)]
+pub struct ExecutionEnvironment {
+    pub executable: &'static str,
+    pub language_mode: LanguageMode,
+    /// `true` when `Get-ExecutionPolicy` reports `Restricted`, which
+    /// blocks every script regardless of `-EncodedCommand`.
+    pub execution_policy_blocked: bool,
+}
+
+fn spawn_reader<R: std::io::Read + Send + 'static>(
+    pipe: Option<R>,
+    tx: mpsc::Sender<ScriptOutput>,
+    wrap: fn(String) -> ScriptOutput,
+) {
+    let Some(pipe) = pipe else { return };
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            if tx.send(wrap(line)).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+    token: &CancellationToken,
+) -> Result<i32, PowerShellError> {
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status.code().unwrap_or(-1));
+        }
+        if token.is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(PowerShellError::Cancelled);
+        }
+        if let Some(timeout) = timeout {
+            if started.elapsed() >= timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(PowerShellError::TimedOut);
+            }
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Prefers PowerShell 7 (`pwsh`) when it's on PATH, since it's the
+/// actively-maintained runtime and shares Constrained Language Mode
+/// behavior with Windows PowerShell; falls back to the in-box
+/// `powershell.exe` otherwise.
+fn resolve_executable() -> &'static str {
+    if pwsh_available() {
+        "pwsh"
+    } else {
+        fallback_executable()
+    }
+}
+
+fn pwsh_available() -> bool {
+    Command::new("pwsh")
+        .args(["-NoLogo", "-NoProfile", "-Command", "exit"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn fallback_executable() -> &'static str {
+    "powershell.exe"
+}
+
+#[cfg(not(windows))]
+fn fallback_executable() -> &'static str {
+    "pwsh"
+}
+
+/// Encodes `script` the way `-EncodedCommand` expects: UTF-16LE bytes,
+/// base64. Avoids all quoting/escaping bugs from passing scripts as a
+/// `-Command` argument through the shell.
+fn encode_command(script: &str) -> String {
+    let utf16_bytes: Vec<u8> = script.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    base64_encode(&utf16_bytes)
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
@@ -0,0 +1,72 @@
+//! Frees cached memory before launching a game: trims process working
+//! sets and drops the page cache.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RamCleanError {
+    Os(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for RamCleanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RamCleanError::Os(err) => write!(f, "os error: {err}"),
+            RamCleanError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for RamCleanError {}
+
+pub struct RamCleaner;
+
+impl RamCleaner {
+    pub fn new() -> Self {
+        RamCleaner
+    }
+
+    /// Frees as much cached memory as the platform allows without
+    /// touching anything a running process actually needs.
+    pub fn clean(&self) -> Result<(), RamCleanError> {
+        imp::clean()
+    }
+}
+
+impl Default for RamCleaner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::RamCleanError;
+
+    pub(super) fn clean() -> Result<(), RamCleanError> {
+        // EmptyWorkingSet on every accessible process handle, plus
+        // SetSystemFileCacheSize(-1, -1, 0) to trim the system file cache.
+        todo!("trim working sets and the system file cache via the windows crate")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::RamCleanError;
+    use std::fs;
+
+    pub(super) fn clean() -> Result<(), RamCleanError> {
+        // Dropping the page cache (and reclaimable slab) requires root;
+        // `1` clears the page cache only, which is enough to free most
+        // of what a browser or launcher leaves behind without needing to
+        // also evict dentries/inodes.
+        fs::write("/proc/sys/vm/drop_caches", "1").map_err(|err| {
+            if err.kind() == std::io::ErrorKind::PermissionDenied {
+                RamCleanError::Unsupported("dropping the page cache requires root")
+            } else {
+                RamCleanError::Os(err)
+            }
+        })
+    }
+}
@@ -0,0 +1,118 @@
+//! Minimal generic Windows registry read/write, for callers that need
+//! to snapshot and restore an arbitrary value (see
+//! [`crate::services::snapshot`]) rather than a single named setting
+//! with its own dedicated accessor — see [`crate::gpu::power`] and
+//! [`crate::system::game_mode`] for those.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A single registry value, identified by its full key path (e.g.
+/// `HKLM\SYSTEM\CurrentControlSet\Services\DiagTrack`) and value name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryValue {
+    pub key_path: String,
+    pub value_name: String,
+    /// The value's data at capture time, as a string. `None` means the
+    /// value didn't exist, so restoring it means deleting it again
+    /// rather than writing an empty string.
+    pub data: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum RegistryError {
+    NotFound(String),
+    PermissionDenied(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::NotFound(path) => write!(f, "registry key {path} not found"),
+            RegistryError::PermissionDenied(msg) => write!(f, "permission denied: {msg}"),
+            RegistryError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// Reads `value_name` under `key_path`, or `None` if it doesn't exist.
+pub fn read_value(key_path: &str, value_name: &str) -> Result<Option<String>, RegistryError> {
+    imp::read(key_path, value_name)
+}
+
+/// Writes `data` to `value_name` under `key_path`, creating the key if
+/// it doesn't exist.
+pub fn write_value(key_path: &str, value_name: &str, data: &str) -> Result<(), RegistryError> {
+    imp::write(key_path, value_name, data)
+}
+
+/// Deletes `value_name` under `key_path`, if it exists.
+pub fn delete_value(key_path: &str, value_name: &str) -> Result<(), RegistryError> {
+    imp::delete(key_path, value_name)
+}
+
+/// Captures `key_path`/`value_name`'s current data, for restoring it
+/// later with [`restore_value`].
+pub fn snapshot_value(key_path: &str, value_name: &str) -> Result<RegistryValue, RegistryError> {
+    Ok(RegistryValue {
+        key_path: key_path.to_string(),
+        value_name: value_name.to_string(),
+        data: read_value(key_path, value_name)?,
+    })
+}
+
+/// Restores a previously captured [`RegistryValue`]: writes its data
+/// back if it had any, or deletes the value if it didn't exist when
+/// captured.
+pub fn restore_value(value: &RegistryValue) -> Result<(), RegistryError> {
+    match &value.data {
+        Some(data) => write_value(&value.key_path, &value.value_name, data),
+        None => delete_value(&value.key_path, &value.value_name),
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::RegistryError;
+
+    pub(super) fn read(_key_path: &str, _value_name: &str) -> Result<Option<String>, RegistryError> {
+        // RegOpenKeyExW on the key path (splitting the HKLM/HKCU root
+        // from the subkey) followed by RegQueryValueExW; a missing key
+        // or value is treated as `Ok(None)`, not an error.
+        todo!("read a registry value via the Win32 registry API")
+    }
+
+    pub(super) fn write(_key_path: &str, _value_name: &str, _data: &str) -> Result<(), RegistryError> {
+        // RegCreateKeyExW (so a missing key is created, matching
+        // restoring a value that existed in a key the snapshot deleted)
+        // followed by RegSetValueExW.
+        todo!("write a registry value via the Win32 registry API")
+    }
+
+    pub(super) fn delete(_key_path: &str, _value_name: &str) -> Result<(), RegistryError> {
+        // RegOpenKeyExW + RegDeleteValueW; deleting an already-absent
+        // value is a no-op, not an error.
+        todo!("delete a registry value via the Win32 registry API")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::RegistryError;
+
+    pub(super) fn read(_key_path: &str, _value_name: &str) -> Result<Option<String>, RegistryError> {
+        Err(RegistryError::Unsupported("the Windows registry doesn't exist on this platform"))
+    }
+
+    pub(super) fn write(_key_path: &str, _value_name: &str, _data: &str) -> Result<(), RegistryError> {
+        Err(RegistryError::Unsupported("the Windows registry doesn't exist on this platform"))
+    }
+
+    pub(super) fn delete(_key_path: &str, _value_name: &str) -> Result<(), RegistryError> {
+        Err(RegistryError::Unsupported("the Windows registry doesn't exist on this platform"))
+    }
+}
@@ -0,0 +1,206 @@
+//! A single background thread that periodically samples RAM usage,
+//! system-wide CPU usage, network throughput and ping, so the Memory,
+//! CPU and Network tabs — and the mini overlay — can plot rolling
+//! charts and current readings without each spawning its own poll
+//! loop. [`StatsSampler::subscribe`] lets a consumer that can't afford
+//! to poll every frame, like [`crate::scheduler::ConditionMetric::RamUsedPercent`],
+//! react to each new sample as it lands instead.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cpu::history::HISTORY_WINDOW_SECS;
+use crate::network::ping::{PingMonitor, DEFAULT_PING_HOST};
+use crate::network::throughput::NetworkThroughputMonitor;
+use crate::system::cpu_usage::CpuUsageMonitor;
+use crate::system::memory::MemoryMonitor;
+
+/// How often the background thread takes a new sample.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A bounded ring of `(sample instant, value)` pairs, evicting anything
+/// older than [`HISTORY_WINDOW_SECS`] — the same window the CPU tab's
+/// sparklines use, so every rolling chart in the app covers the same
+/// span of history.
+#[derive(Debug, Clone, Default)]
+struct Series {
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl Series {
+    fn push(&mut self, value: f64) {
+        let now = Instant::now();
+        self.samples.push_back((now, value));
+        while let Some(&(at, _)) = self.samples.front() {
+            if now.duration_since(at).as_secs() > HISTORY_WINDOW_SECS {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn as_plot_points(&self) -> Vec<[f64; 2]> {
+        let now = Instant::now();
+        self.samples.iter().map(|&(at, value)| [-(now.duration_since(at).as_secs_f64()), value]).collect()
+    }
+
+    fn latest(&self) -> Option<f64> {
+        self.samples.back().map(|&(_, value)| value)
+    }
+}
+
+#[derive(Debug, Default)]
+struct SamplerState {
+    ram_percent: Series,
+    cpu_percent: Series,
+    network_down_kbps: Series,
+    network_up_kbps: Series,
+    ping_ms: Option<u32>,
+}
+
+impl SamplerState {
+    fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            ram_percent: self.ram_percent.latest(),
+            cpu_percent: self.cpu_percent.latest(),
+            network_down_kbps: self.network_down_kbps.latest(),
+            network_up_kbps: self.network_up_kbps.latest(),
+            ping_ms: self.ping_ms,
+        }
+    }
+}
+
+/// A point-in-time read of every metric [`StatsSampler`] tracks, handed
+/// to each [`StatsSampler::subscribe`] callback right after a new
+/// sample lands.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsSnapshot {
+    pub ram_percent: Option<f64>,
+    pub cpu_percent: Option<f64>,
+    pub network_down_kbps: Option<f64>,
+    pub network_up_kbps: Option<f64>,
+    pub ping_ms: Option<u32>,
+}
+
+type Subscriber = Box<dyn Fn(MetricsSnapshot) + Send>;
+
+/// Owns the background sampling thread and the rolling history it
+/// feeds. Cheap to clone: every clone shares the same underlying state
+/// and the same thread, so each tab can hold its own handle without
+/// starting a second sampler.
+#[derive(Clone)]
+pub struct StatsSampler {
+    state: Arc<Mutex<SamplerState>>,
+    running: Arc<AtomicBool>,
+    subscribers: Arc<Mutex<Vec<Subscriber>>>,
+}
+
+impl StatsSampler {
+    /// Spawns the background sampling thread immediately.
+    pub fn start() -> Self {
+        let sampler = Self {
+            state: Arc::new(Mutex::new(SamplerState::default())),
+            running: Arc::new(AtomicBool::new(true)),
+            subscribers: Arc::new(Mutex::new(Vec::new())),
+        };
+
+        let state = sampler.state.clone();
+        let running = sampler.running.clone();
+        let subscribers = sampler.subscribers.clone();
+        thread::spawn(move || {
+            let memory = MemoryMonitor::new();
+            let cpu = CpuUsageMonitor::new();
+            let network = NetworkThroughputMonitor::new();
+            let ping = PingMonitor::new();
+            while running.load(Ordering::Relaxed) {
+                if let Ok(percent) = memory.used_percent() {
+                    state.lock().unwrap().ram_percent.push(percent as f64);
+                }
+                if let Ok(percent) = cpu.used_percent() {
+                    state.lock().unwrap().cpu_percent.push(percent as f64);
+                }
+                if let Ok(sample) = network.sample_kbps() {
+                    let mut state = state.lock().unwrap();
+                    state.network_down_kbps.push(sample.down_kbps as f64);
+                    state.network_up_kbps.push(sample.up_kbps as f64);
+                }
+                if let Ok(round_trip_ms) = ping.ping_ms(DEFAULT_PING_HOST) {
+                    state.lock().unwrap().ping_ms = Some(round_trip_ms);
+                }
+
+                let snapshot = state.lock().unwrap().snapshot();
+                for subscriber in subscribers.lock().unwrap().iter() {
+                    subscriber(snapshot);
+                }
+
+                thread::sleep(SAMPLE_INTERVAL);
+            }
+        });
+
+        sampler
+    }
+
+    /// Registers `callback` to run on the sampling thread after every
+    /// new sample, for a consumer — a scheduler condition check, the
+    /// mini overlay — that wants to react immediately instead of
+    /// polling `latest_*` itself. Runs for the sampler's lifetime;
+    /// there's no matching unsubscribe, since nothing in this crate
+    /// tears one down before the app exits.
+    pub fn subscribe(&self, callback: impl Fn(MetricsSnapshot) + Send + 'static) {
+        self.subscribers.lock().unwrap().push(Box::new(callback));
+    }
+
+    /// Stops the background thread once its current sleep finishes.
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+
+    /// RAM usage history as `[-seconds_ago, percent]` points, oldest
+    /// first, suitable for handing straight to a plot widget.
+    pub fn ram_percent_plot_points(&self) -> Vec<[f64; 2]> {
+        self.state.lock().unwrap().ram_percent.as_plot_points()
+    }
+
+    /// System-wide CPU usage history as `[-seconds_ago, percent]` points.
+    pub fn cpu_percent_plot_points(&self) -> Vec<[f64; 2]> {
+        self.state.lock().unwrap().cpu_percent.as_plot_points()
+    }
+
+    /// Download throughput history as `[-seconds_ago, kbps]` points.
+    pub fn network_down_plot_points(&self) -> Vec<[f64; 2]> {
+        self.state.lock().unwrap().network_down_kbps.as_plot_points()
+    }
+
+    /// Upload throughput history as `[-seconds_ago, kbps]` points.
+    pub fn network_up_plot_points(&self) -> Vec<[f64; 2]> {
+        self.state.lock().unwrap().network_up_kbps.as_plot_points()
+    }
+
+    /// The most recent RAM usage reading, or `None` before the first
+    /// sample lands.
+    pub fn latest_ram_percent(&self) -> Option<f64> {
+        self.state.lock().unwrap().ram_percent.latest()
+    }
+
+    /// The most recent CPU usage reading, or `None` before the first
+    /// sample lands.
+    pub fn latest_cpu_percent(&self) -> Option<f64> {
+        self.state.lock().unwrap().cpu_percent.latest()
+    }
+
+    /// The most recent download throughput reading, or `None` before
+    /// the first sample lands.
+    pub fn latest_network_down_kbps(&self) -> Option<f64> {
+        self.state.lock().unwrap().network_down_kbps.latest()
+    }
+
+    /// The most recent ping round-trip time, or `None` before the first
+    /// sample lands.
+    pub fn latest_ping_ms(&self) -> Option<u32> {
+        self.state.lock().unwrap().ping_ms
+    }
+}
@@ -0,0 +1,95 @@
+//! Resolves where config, tasks and logs should live: `%APPDATA%\GameBooster`
+//! by default, or the directory the exe runs from when a
+//! `portable.flag` file sits next to it, for users who keep the whole
+//! app on a USB drive. [`migrate_legacy_state_dir`] is the one-time
+//! copy from the old always-next-to-the-exe behavior into wherever
+//! [`resolve_state_dir`] now points, for anyone updating from a
+//! version that didn't have this distinction.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const PORTABLE_FLAG_FILE_NAME: &str = "portable.flag";
+const APP_FOLDER_NAME: &str = "GameBooster";
+/// Left behind in the new state dir once migration has run, so a later
+/// launch doesn't re-copy (or silently overwrite) anything a user has
+/// since changed in the new location.
+const MIGRATION_MARKER_FILE_NAME: &str = ".migrated_from_legacy";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageMode {
+    /// Data lives under `%APPDATA%\GameBooster`, independent of where
+    /// the exe itself is installed.
+    Installed,
+    /// Data lives next to the exe, so the whole install can be moved
+    /// or run from removable media without losing its settings.
+    Portable,
+}
+
+/// Picks [`StorageMode::Portable`] if `exe_dir` has a
+/// [`PORTABLE_FLAG_FILE_NAME`] file next to it, [`StorageMode::Installed`]
+/// otherwise.
+pub fn detect_storage_mode(exe_dir: &Path) -> StorageMode {
+    if exe_dir.join(PORTABLE_FLAG_FILE_NAME).exists() {
+        StorageMode::Portable
+    } else {
+        StorageMode::Installed
+    }
+}
+
+/// Where config/tasks/logs should live for `mode` — what a future
+/// `main.rs` would pass to `CleanRamApp::new` as its `state_dir`.
+pub fn resolve_state_dir(mode: StorageMode, exe_dir: &Path) -> PathBuf {
+    match mode {
+        StorageMode::Portable => exe_dir.to_path_buf(),
+        StorageMode::Installed => appdata_dir(),
+    }
+}
+
+fn appdata_dir() -> PathBuf {
+    match std::env::var_os("APPDATA") {
+        Some(appdata) => PathBuf::from(appdata).join(APP_FOLDER_NAME),
+        // %APPDATA% isn't set outside Windows; fall back to the
+        // current directory so a dev build run directly still has
+        // somewhere to write.
+        None => std::env::current_dir().unwrap_or_default().join(APP_FOLDER_NAME),
+    }
+}
+
+/// How much a [`migrate_legacy_state_dir`] pass copied.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub files_copied: u64,
+}
+
+/// Copies every file directly under `old_dir` into `new_dir`, skipping
+/// anything already there, then leaves a marker in `new_dir` so this
+/// only ever runs once. A no-op once the marker exists, or if `old_dir`
+/// and `new_dir` are the same path (portable installs that have always
+/// lived next to the exe).
+pub fn migrate_legacy_state_dir(old_dir: &Path, new_dir: &Path) -> io::Result<MigrationReport> {
+    let mut report = MigrationReport::default();
+    let marker = new_dir.join(MIGRATION_MARKER_FILE_NAME);
+    if old_dir == new_dir || marker.exists() {
+        return Ok(report);
+    }
+
+    fs::create_dir_all(new_dir)?;
+    if let Ok(entries) = fs::read_dir(old_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let Some(file_name) = path.file_name() else { continue };
+            let dest = new_dir.join(file_name);
+            if !dest.exists() {
+                fs::copy(&path, &dest)?;
+                report.files_copied += 1;
+            }
+        }
+    }
+    fs::write(&marker, [])?;
+    Ok(report)
+}
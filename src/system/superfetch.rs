@@ -0,0 +1,192 @@
+//! Disables the SysMain (Superfetch) service plus its
+//! `EnablePrefetcher`/`EnableSuperfetch` registry tuning, with an
+//! SSD-aware recommendation: Superfetch's seek-time benefit disappears
+//! on an SSD, where its background disk scanning can itself cause
+//! stutter, so the right call flips from "leave it on" to "turn it off"
+//! depending on the system drive's type.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::manager::{ServiceManager, ServiceManagerError, ServiceStatus, StartupType};
+use crate::system::registry::{self, RegistryError, RegistryValue};
+
+const PREFETCH_PARAMETERS_KEY: &str =
+    r"HKLM\SYSTEM\CurrentControlSet\Control\Session Manager\Memory Management\PrefetchParameters";
+const SYSMAIN_SERVICE: &str = "SysMain";
+
+#[derive(Debug)]
+pub enum SuperfetchError {
+    Manager(ServiceManagerError),
+    Registry(RegistryError),
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+}
+
+impl fmt::Display for SuperfetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuperfetchError::Manager(err) => write!(f, "{err}"),
+            SuperfetchError::Registry(err) => write!(f, "{err}"),
+            SuperfetchError::Io(err) => write!(f, "io error: {err}"),
+            SuperfetchError::Parse(err) => write!(f, "invalid superfetch_snapshot.json: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SuperfetchError {}
+
+impl From<ServiceManagerError> for SuperfetchError {
+    fn from(err: ServiceManagerError) -> Self {
+        SuperfetchError::Manager(err)
+    }
+}
+
+impl From<RegistryError> for SuperfetchError {
+    fn from(err: RegistryError) -> Self {
+        SuperfetchError::Registry(err)
+    }
+}
+
+impl From<std::io::Error> for SuperfetchError {
+    fn from(err: std::io::Error) -> Self {
+        SuperfetchError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SuperfetchError {
+    fn from(err: serde_json::Error) -> Self {
+        SuperfetchError::Parse(err)
+    }
+}
+
+/// Whether disabling Superfetch/Prefetcher is actually a good idea on
+/// this machine's system drive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefetchRecommendation {
+    /// Spinning disk: Superfetch's seek-time savings still matter.
+    KeepEnabled,
+    /// SSD: no seek penalty to amortize, and the background scanning
+    /// costs more than it saves.
+    DisableOnSsd,
+}
+
+pub fn recommendation(system_drive_is_ssd: bool) -> PrefetchRecommendation {
+    if system_drive_is_ssd {
+        PrefetchRecommendation::DisableOnSsd
+    } else {
+        PrefetchRecommendation::KeepEnabled
+    }
+}
+
+/// Whether the drive Windows is installed on is solid-state.
+pub fn system_drive_is_ssd() -> Result<bool, SuperfetchError> {
+    Ok(imp::system_drive_is_ssd()?)
+}
+
+/// SysMain's prior startup type/running state and the prior registry
+/// values, so [`SuperfetchTuner::restore`] can put everything back
+/// exactly as it was.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SuperfetchSnapshot {
+    service_startup_type: StartupType,
+    service_was_running: bool,
+    prefetcher: RegistryValue,
+    superfetch: RegistryValue,
+}
+
+/// Applies and reverts the Superfetch/SysMain tuning as one batch,
+/// persisting the snapshot so it survives an app restart between
+/// disabling and restoring.
+pub struct SuperfetchTuner {
+    path: PathBuf,
+    snapshot: Option<SuperfetchSnapshot>,
+}
+
+impl SuperfetchTuner {
+    /// Loads a pending snapshot from `path` if one exists, or starts
+    /// with Superfetch untouched.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, SuperfetchError> {
+        let path = path.into();
+        if !path.exists() {
+            return Ok(Self { path, snapshot: None });
+        }
+        let data = fs::read_to_string(&path)?;
+        let snapshot = if data.trim().is_empty() { None } else { serde_json::from_str(&data)? };
+        Ok(Self { path, snapshot })
+    }
+
+    fn save_to_file(&self) -> Result<(), SuperfetchError> {
+        let json = serde_json::to_string_pretty(&self.snapshot)?;
+        fs::write(&self.path, json)?;
+        Ok(())
+    }
+
+    /// Whether Superfetch is currently disabled by this tuner, i.e.
+    /// there's a snapshot to restore.
+    pub fn is_disabled(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    /// Stops and disables the SysMain service, and zeroes out
+    /// `EnablePrefetcher`/`EnableSuperfetch`, recording everything's
+    /// prior state first.
+    pub fn disable(&mut self, manager: &mut ServiceManager) -> Result<(), SuperfetchError> {
+        let info = manager.find(SYSMAIN_SERVICE)?;
+        let (service_startup_type, service_was_running) = match info {
+            Some(info) => (info.startup_type, info.status == ServiceStatus::Running),
+            None => (StartupType::Automatic, false),
+        };
+        if service_was_running {
+            manager.stop(SYSMAIN_SERVICE)?;
+        }
+        manager.set_startup_type(SYSMAIN_SERVICE, StartupType::Disabled)?;
+
+        let prefetcher = registry::snapshot_value(PREFETCH_PARAMETERS_KEY, "EnablePrefetcher")?;
+        let superfetch = registry::snapshot_value(PREFETCH_PARAMETERS_KEY, "EnableSuperfetch")?;
+        registry::write_value(PREFETCH_PARAMETERS_KEY, "EnablePrefetcher", "0")?;
+        registry::write_value(PREFETCH_PARAMETERS_KEY, "EnableSuperfetch", "0")?;
+
+        self.snapshot = Some(SuperfetchSnapshot { service_startup_type, service_was_running, prefetcher, superfetch });
+        self.save_to_file()
+    }
+
+    /// Restores SysMain and the registry tuning to what
+    /// [`SuperfetchTuner::disable`] captured. Does nothing if Superfetch
+    /// isn't currently disabled by this tuner.
+    pub fn restore(&mut self, manager: &mut ServiceManager) -> Result<(), SuperfetchError> {
+        let Some(snapshot) = self.snapshot.take() else { return Ok(()) };
+        manager.set_startup_type(SYSMAIN_SERVICE, snapshot.service_startup_type)?;
+        if snapshot.service_was_running {
+            manager.start(SYSMAIN_SERVICE)?;
+        }
+        registry::restore_value(&snapshot.prefetcher)?;
+        registry::restore_value(&snapshot.superfetch)?;
+        self.save_to_file()
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use crate::system::registry::RegistryError;
+
+    pub(super) fn system_drive_is_ssd() -> Result<bool, RegistryError> {
+        // DeviceIoControl with IOCTL_STORAGE_QUERY_PROPERTY, requesting
+        // StorageDeviceSeekPenaltyProperty on the volume backing
+        // %SystemDrive% — `IncursSeekPenalty == FALSE` means SSD, the
+        // same check `Get-PhysicalDisk`'s MediaType column is built on.
+        todo!("query StorageDeviceSeekPenaltyProperty for the system drive")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use crate::system::registry::RegistryError;
+
+    pub(super) fn system_drive_is_ssd() -> Result<bool, RegistryError> {
+        Err(RegistryError::Unsupported("drive type detection needs the Win32 storage API"))
+    }
+}
@@ -0,0 +1,127 @@
+//! Hardware and OS inventory, via WMI (`root\cimv2`'s `Win32_OperatingSystem`,
+//! `Win32_Processor`, `Win32_PhysicalMemory`, `Win32_VideoController`,
+//! `Win32_BaseBoard` and `Win32_DiskDrive` classes), for the Settings tab's
+//! "About this PC" section and embedding in [`crate::reporting::Report`]
+//! summary rows — real values instead of the OS name/version guessed from
+//! `cfg!`/crate metadata.
+
+use std::fmt;
+
+/// One installed RAM module, as reported by `Win32_PhysicalMemory`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoryModule {
+    pub device_locator: String,
+    pub capacity_bytes: u64,
+    pub speed_mhz: u32,
+    pub manufacturer: String,
+}
+
+/// One GPU, as reported by `Win32_VideoController`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuInfo {
+    pub name: String,
+    pub driver_version: String,
+    pub adapter_ram_bytes: u64,
+}
+
+/// One storage device, as reported by `Win32_DiskDrive`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageDevice {
+    pub model: String,
+    pub size_bytes: u64,
+    pub is_ssd: bool,
+}
+
+/// A full hardware/OS inventory snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemInfo {
+    pub os_name: String,
+    pub os_build: String,
+    pub cpu_model: String,
+    pub physical_core_count: u32,
+    pub logical_core_count: u32,
+    pub memory_modules: Vec<MemoryModule>,
+    pub gpus: Vec<GpuInfo>,
+    pub motherboard_model: String,
+    pub storage_devices: Vec<StorageDevice>,
+}
+
+#[derive(Debug)]
+pub enum SystemInfoError {
+    Wmi(String),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for SystemInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemInfoError::Wmi(msg) => write!(f, "WMI query failed: {msg}"),
+            SystemInfoError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SystemInfoError {}
+
+/// Queries a full hardware/OS inventory.
+pub fn query_system_info() -> Result<SystemInfo, SystemInfoError> {
+    imp::query()
+}
+
+impl SystemInfo {
+    /// Flattens this snapshot into label/value rows, for
+    /// [`crate::reporting::Report::with_summary_row`] — the same shape
+    /// an exported report's other summary rows already use.
+    pub fn to_report_rows(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("OS".to_string(), format!("{} (build {})", self.os_name, self.os_build)),
+            ("CPU".to_string(), self.cpu_model.clone()),
+            ("Cores".to_string(), format!("{} physical / {} logical", self.physical_core_count, self.logical_core_count)),
+            ("Motherboard".to_string(), self.motherboard_model.clone()),
+        ];
+        for module in &self.memory_modules {
+            rows.push((
+                format!("RAM ({})", module.device_locator),
+                format!("{} MB @ {} MHz, {}", module.capacity_bytes / 1_048_576, module.speed_mhz, module.manufacturer),
+            ));
+        }
+        for gpu in &self.gpus {
+            rows.push((format!("GPU: {}", gpu.name), format!("driver {}, {} MB VRAM", gpu.driver_version, gpu.adapter_ram_bytes / 1_048_576)));
+        }
+        for disk in &self.storage_devices {
+            rows.push((
+                format!("Storage: {}", disk.model),
+                format!("{} GB, {}", disk.size_bytes / 1_073_741_824, if disk.is_ssd { "SSD" } else { "HDD" }),
+            ));
+        }
+        rows
+    }
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{SystemInfo, SystemInfoError};
+
+    pub(super) fn query() -> Result<SystemInfo, SystemInfoError> {
+        // Connects to the root\cimv2 WMI namespace (via the `wmi`
+        // crate's COM-backed client, the same approach
+        // crate::system::defender uses for root\Microsoft\Windows\Defender)
+        // and queries Win32_OperatingSystem (Caption, BuildNumber),
+        // Win32_Processor (Name, NumberOfCores, NumberOfLogicalProcessors),
+        // Win32_PhysicalMemory (DeviceLocator, Capacity, Speed,
+        // Manufacturer), Win32_VideoController (Name, DriverVersion,
+        // AdapterRAM), Win32_BaseBoard (Product) and Win32_DiskDrive
+        // (Model, Size, MediaType) in turn, collecting the
+        // one-row-per-device classes into their Vecs.
+        todo!("query Win32_OperatingSystem/Win32_Processor/Win32_PhysicalMemory/Win32_VideoController/Win32_BaseBoard/Win32_DiskDrive via WMI")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{SystemInfo, SystemInfoError};
+
+    pub(super) fn query() -> Result<SystemInfo, SystemInfoError> {
+        Err(SystemInfoError::Unsupported("WMI hardware inventory doesn't exist on this platform"))
+    }
+}
@@ -0,0 +1,190 @@
+//! Persisted visual theme configuration: dark/light/auto mode, accent
+//! color, background tint, corner rounding and font size, saved to the
+//! same per-install state directory as every other setting this app
+//! persists and restored at startup.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::system_theme;
+
+#[derive(Debug)]
+pub enum ThemeConfigError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl std::fmt::Display for ThemeConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeConfigError::Io(err) => write!(f, "{err}"),
+            ThemeConfigError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeConfigError {}
+
+impl From<io::Error> for ThemeConfigError {
+    fn from(err: io::Error) -> Self {
+        ThemeConfigError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ThemeConfigError {
+    fn from(err: serde_json::Error) -> Self {
+        ThemeConfigError::Parse(err)
+    }
+}
+
+/// Which palette the UI draws with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeMode {
+    Light,
+    #[default]
+    Dark,
+    /// Follows Windows' own "Choose your mode" setting, resolved fresh
+    /// every time [`ThemeConfig::effective_mode`] is called — so
+    /// flipping it in Windows Settings takes effect without restarting
+    /// this app.
+    Auto,
+    /// Maximum-contrast palette — pure black background, pure yellow
+    /// accent, overriding the user's own accent and tint — for users who
+    /// need it regardless of what Windows' own high-contrast setting is
+    /// doing.
+    HighContrast,
+}
+
+/// A plain sRGB color, kept independent of any particular UI
+/// framework's color type so `theme` doesn't need an `egui` dependency
+/// just to describe a config value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+const DEFAULT_ACCENT: Rgb = Rgb::new(66, 133, 244);
+const DEFAULT_BACKGROUND_TINT: Rgb = Rgb::new(30, 30, 30);
+const DEFAULT_CORNER_RADIUS: f32 = 4.0;
+const DEFAULT_FONT_SIZE: f32 = 14.0;
+
+/// Pure black background and pure yellow accent — WCAG's highest-contrast
+/// pairing short of pure black-on-white text — used for every control
+/// while [`ThemeMode::HighContrast`] is active, overriding whatever
+/// accent/tint the user previously picked.
+const HIGH_CONTRAST_BACKGROUND: Rgb = Rgb::new(0, 0, 0);
+const HIGH_CONTRAST_ACCENT: Rgb = Rgb::new(255, 255, 0);
+
+fn default_accent() -> Rgb {
+    DEFAULT_ACCENT
+}
+
+fn default_background_tint() -> Rgb {
+    DEFAULT_BACKGROUND_TINT
+}
+
+fn default_corner_radius() -> f32 {
+    DEFAULT_CORNER_RADIUS
+}
+
+fn default_font_size() -> f32 {
+    DEFAULT_FONT_SIZE
+}
+
+/// The full set of visual knobs the Settings tab's theme editor
+/// exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub mode: ThemeMode,
+    #[serde(default = "default_accent")]
+    pub accent: Rgb,
+    #[serde(default = "default_background_tint")]
+    pub background_tint: Rgb,
+    #[serde(default = "default_corner_radius")]
+    pub corner_radius: f32,
+    #[serde(default = "default_font_size")]
+    pub font_size: f32,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            mode: ThemeMode::default(),
+            accent: DEFAULT_ACCENT,
+            background_tint: DEFAULT_BACKGROUND_TINT,
+            corner_radius: DEFAULT_CORNER_RADIUS,
+            font_size: DEFAULT_FONT_SIZE,
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Loads `path`, or starts with every default if it doesn't exist
+    /// yet.
+    pub fn load_from_file(path: impl Into<PathBuf>) -> Result<Self, ThemeConfigError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Saves this config to `path` immediately, so an edit in the
+    /// theme editor survives an unexpected exit.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), ThemeConfigError> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Resolves [`ThemeMode::Auto`] against the live Windows light/dark
+    /// setting; `Light`/`Dark` pass through unchanged. Defaults to dark
+    /// if the system setting can't be read, matching this app's own
+    /// default.
+    pub fn effective_mode(&self) -> ThemeMode {
+        match self.mode {
+            ThemeMode::Auto => {
+                if system_theme::system_prefers_dark().unwrap_or(true) {
+                    ThemeMode::Dark
+                } else {
+                    ThemeMode::Light
+                }
+            }
+            other => other,
+        }
+    }
+
+    /// The background tint actually drawn: [`HIGH_CONTRAST_BACKGROUND`]
+    /// while [`ThemeMode::HighContrast`] is active, `background_tint`
+    /// otherwise.
+    pub fn effective_background_tint(&self) -> Rgb {
+        match self.effective_mode() {
+            ThemeMode::HighContrast => HIGH_CONTRAST_BACKGROUND,
+            _ => self.background_tint,
+        }
+    }
+
+    /// The accent color actually drawn: [`HIGH_CONTRAST_ACCENT`] while
+    /// [`ThemeMode::HighContrast`] is active, `accent` otherwise.
+    pub fn effective_accent(&self) -> Rgb {
+        match self.effective_mode() {
+            ThemeMode::HighContrast => HIGH_CONTRAST_ACCENT,
+            _ => self.accent,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Visual theme: dark/light/auto mode plus accent color, background
+//! tint, corner rounding, and font size, persisted across restarts. See
+//! [`ThemeConfig`] for the persisted settings and [`system_theme`] for
+//! how `Auto` mode reads Windows' own preference.
+
+mod config;
+mod system_theme;
+
+pub use config::{Rgb, ThemeConfig, ThemeConfigError, ThemeMode};
+pub use system_theme::{system_prefers_dark, SystemThemeError};
@@ -0,0 +1,48 @@
+//! Reads Windows' own "Choose your mode" light/dark app-theme
+//! preference, for [`super::ThemeMode::Auto`].
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum SystemThemeError {
+    Unsupported(&'static str),
+    Os(std::io::Error),
+}
+
+impl fmt::Display for SystemThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemThemeError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+            SystemThemeError::Os(err) => write!(f, "os error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SystemThemeError {}
+
+/// Returns `true` if Windows is currently set to dark mode for apps.
+pub fn system_prefers_dark() -> Result<bool, SystemThemeError> {
+    imp::system_prefers_dark()
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::SystemThemeError;
+
+    pub(super) fn system_prefers_dark() -> Result<bool, SystemThemeError> {
+        // Reads the AppsUseLightTheme DWORD under
+        // HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize:
+        // 0 means dark, 1 (or the key missing entirely, pre-Windows 10
+        // 1607) means light.
+        todo!("read AppsUseLightTheme via the windows crate's registry bindings")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::SystemThemeError;
+
+    pub(super) fn system_prefers_dark() -> Result<bool, SystemThemeError> {
+        Err(SystemThemeError::Unsupported("this platform has no light/dark app-theme setting to read"))
+    }
+}
@@ -0,0 +1,13 @@
+//! Helpers for making icon-only widgets usable with a screen reader:
+//! egui's own accesskit integration reads a widget's name off its
+//! visible text, which is exactly what an icon glyph doesn't have.
+
+/// Draws a button whose visible label is `icon` (e.g. an emoji glyph)
+/// but whose accessible name — and hover tooltip — is `label`, so
+/// accesskit announces "Remove" instead of reading the glyph aloud or
+/// staying silent.
+pub fn icon_button(ui: &mut egui::Ui, icon: &str, label: &str) -> egui::Response {
+    let response = ui.button(icon).on_hover_text(label);
+    response.widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, label));
+    response
+}
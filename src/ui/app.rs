@@ -0,0 +1,329 @@
+//! The app shell: owns every long-lived piece of state the tabs render
+//! from and mutate, most importantly the gaming profiles that outlive a
+//! single session, the [`AppSettings`](crate::settings::AppSettings)
+//! that remember theme, disk options, the speed limit field, the
+//! selected tab and the window's geometry across restarts, which
+//! destructive-action confirmation dialogs the user has opted out of,
+//! the [`ActionHistoryLog`](crate::history::ActionHistoryLog) of
+//! everything the app has done on its own, and which long-running
+//! [`Job`]s are in flight right now.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::confirm::{ConfirmPromptSettings, ConfirmPromptSettingsError, DestructiveAction};
+use crate::history::{ActionHistoryError, ActionHistoryLog, ActionRecord, UndoAction};
+use crate::profiles::hotkey_listener::{HotkeyError, HotkeyListener};
+use crate::profiles::manager::ProfileManagerError;
+use crate::profiles::profile::DiskCleanOptions;
+use crate::profiles::{GamingProfile, ProfileManager};
+use crate::settings::{AppSettings, AppSettingsError, MonitorGeometry, WindowState};
+use crate::shortcuts::{GlobalAction, ShortcutsConfig, ShortcutsConfigError};
+use crate::theme::ThemeConfig;
+use crate::ui::jobs::{AppJobs, Job};
+
+/// Name `profiles.json` is saved under, inside whatever state directory
+/// the app was constructed with.
+const PROFILES_FILE_NAME: &str = "profiles.json";
+
+/// Name `shortcuts.json` is saved under, inside whatever state
+/// directory the app was constructed with.
+const SHORTCUTS_FILE_NAME: &str = "shortcuts.json";
+
+/// Name `settings.json` is saved under, inside whatever state directory
+/// the app was constructed with.
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// Name `confirm_prompts.json` is saved under, inside whatever state
+/// directory the app was constructed with.
+const CONFIRM_PROMPTS_FILE_NAME: &str = "confirm_prompts.json";
+
+/// Name `history.json` is saved under, inside whatever state directory
+/// the app was constructed with.
+const HISTORY_FILE_NAME: &str = "history.json";
+
+#[derive(Debug)]
+pub enum AppError {
+    ProfileManager(ProfileManagerError),
+    Shortcuts(ShortcutsConfigError),
+    Settings(AppSettingsError),
+    ConfirmPrompts(ConfirmPromptSettingsError),
+    History(ActionHistoryError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::ProfileManager(err) => write!(f, "{err}"),
+            AppError::Shortcuts(err) => write!(f, "{err}"),
+            AppError::Settings(err) => write!(f, "{err}"),
+            AppError::ConfirmPrompts(err) => write!(f, "{err}"),
+            AppError::History(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<ProfileManagerError> for AppError {
+    fn from(err: ProfileManagerError) -> Self {
+        AppError::ProfileManager(err)
+    }
+}
+
+impl From<ShortcutsConfigError> for AppError {
+    fn from(err: ShortcutsConfigError) -> Self {
+        AppError::Shortcuts(err)
+    }
+}
+
+impl From<AppSettingsError> for AppError {
+    fn from(err: AppSettingsError) -> Self {
+        AppError::Settings(err)
+    }
+}
+
+impl From<ConfirmPromptSettingsError> for AppError {
+    fn from(err: ConfirmPromptSettingsError) -> Self {
+        AppError::ConfirmPrompts(err)
+    }
+}
+
+impl From<ActionHistoryError> for AppError {
+    fn from(err: ActionHistoryError) -> Self {
+        AppError::History(err)
+    }
+}
+
+pub struct CleanRamApp {
+    profile_manager: ProfileManager,
+    shortcuts: ShortcutsConfig,
+    hotkeys: HotkeyListener,
+    settings: AppSettings,
+    settings_path: PathBuf,
+    confirm_prompts: ConfirmPromptSettings,
+    confirm_prompts_path: PathBuf,
+    history: ActionHistoryLog,
+    /// Whether the compact always-on-top overlay is currently shown,
+    /// flipped from the tray. Not persisted — the overlay starts closed
+    /// every launch, the same as any other tray-toggled window.
+    overlay_visible: bool,
+    /// Which long-running operations are currently in flight. Not
+    /// persisted — nothing is still "running" across a restart.
+    jobs: AppJobs,
+}
+
+impl CleanRamApp {
+    /// Auto-loads `profiles.json`, `shortcuts.json`, `settings.json`,
+    /// `confirm_prompts.json` and `history.json` out of `state_dir`, or
+    /// starts with no profiles, the default shortcut bindings, every
+    /// setting at its default, every confirmation dialog still enabled
+    /// and an empty history if this is the first run. `state_dir`
+    /// itself is the caller's job to pick — see
+    /// [`crate::system::storage_location::resolve_state_dir`] for the
+    /// installed-vs-portable decision.
+    pub fn new(state_dir: impl Into<PathBuf>) -> Result<Self, AppError> {
+        let state_dir = state_dir.into();
+        let profile_manager = ProfileManager::load_from_file(state_dir.join(PROFILES_FILE_NAME))?;
+        let shortcuts = ShortcutsConfig::load_from_file(state_dir.join(SHORTCUTS_FILE_NAME))?;
+        let settings_path = state_dir.join(SETTINGS_FILE_NAME);
+        let settings = AppSettings::load_from_file(&settings_path)?;
+        let confirm_prompts_path = state_dir.join(CONFIRM_PROMPTS_FILE_NAME);
+        let confirm_prompts = ConfirmPromptSettings::load_from_file(&confirm_prompts_path)?;
+        let history = ActionHistoryLog::new(state_dir.join(HISTORY_FILE_NAME));
+        Ok(Self {
+            profile_manager,
+            shortcuts,
+            hotkeys: HotkeyListener::new(),
+            settings,
+            settings_path,
+            confirm_prompts,
+            confirm_prompts_path,
+            history,
+            overlay_visible: false,
+            jobs: AppJobs::new(),
+        })
+    }
+
+    /// Marks `job` as started, for [`CleanRamApp::is_not_busy`] and the
+    /// relevant tab's spinner.
+    pub fn start_job(&mut self, job: Job) {
+        self.jobs.start(job);
+    }
+
+    /// Marks `job` as finished.
+    pub fn finish_job(&mut self, job: Job) {
+        self.jobs.finish(job);
+    }
+
+    pub fn is_job_running(&self, job: Job) -> bool {
+        self.jobs.is_running(job)
+    }
+
+    /// `true` once every tracked job has finished. The embedder's main
+    /// loop should request a repaint every frame while this is `false`
+    /// (so spinners animate smoothly) and fall back to
+    /// [`crate::ui::jobs::IDLE_REPAINT_INTERVAL`] once it's `true` — this
+    /// crate has no render loop of its own to do that in.
+    pub fn is_not_busy(&self) -> bool {
+        self.jobs.is_not_busy()
+    }
+
+    pub fn overlay_visible(&self) -> bool {
+        self.overlay_visible
+    }
+
+    /// Flips the mini overlay's visibility, for the tray's toggle entry.
+    pub fn toggle_overlay(&mut self) {
+        self.overlay_visible = !self.overlay_visible;
+    }
+
+    /// Whether `action` should still pop the confirmation dialog,
+    /// i.e. the user hasn't ticked "don't ask again" for it.
+    pub fn should_confirm(&self, action: DestructiveAction) -> bool {
+        self.confirm_prompts.should_confirm(action)
+    }
+
+    /// Ticks or clears "don't ask again" for `action` and flushes it to
+    /// disk immediately.
+    pub fn set_skip_confirm(&mut self, action: DestructiveAction, skip: bool) -> Result<(), ConfirmPromptSettingsError> {
+        self.confirm_prompts.set_skip_confirm(action, skip);
+        self.confirm_prompts.save_to_file(&self.confirm_prompts_path)
+    }
+
+    pub fn settings(&self) -> &AppSettings {
+        &self.settings
+    }
+
+    fn save_settings(&self) -> Result<(), AppSettingsError> {
+        self.settings.save_to_file(&self.settings_path)
+    }
+
+    /// Applies a new theme and flushes it to disk immediately, so the
+    /// theme editor's changes survive an unexpected exit.
+    pub fn set_theme(&mut self, theme: ThemeConfig) -> Result<(), AppSettingsError> {
+        self.settings.theme = theme;
+        self.save_settings()
+    }
+
+    /// Remembers which tab was open and flushes it to disk immediately,
+    /// so the app reopens on the same tab it was closed on.
+    pub fn set_selected_tab(&mut self, selected_tab: usize) -> Result<(), AppSettingsError> {
+        self.settings.selected_tab = selected_tab;
+        self.save_settings()
+    }
+
+    /// Remembers the last value typed into the network speed limit
+    /// field and flushes it to disk immediately.
+    pub fn set_speed_limit_kbps(&mut self, speed_limit_kbps: Option<u32>) -> Result<(), AppSettingsError> {
+        self.settings.speed_limit_kbps = speed_limit_kbps;
+        self.save_settings()
+    }
+
+    /// Remembers the main window's size, position and monitor and
+    /// flushes it to disk immediately, so it reopens where it was left.
+    pub fn set_window_state(&mut self, window: WindowState) -> Result<(), AppSettingsError> {
+        self.settings.window = window;
+        self.save_settings()
+    }
+
+    /// Validates the saved window state against the monitors connected
+    /// right now (and the "always open on primary" preference), for the
+    /// embedder to pass to its `ViewportBuilder` at startup instead of
+    /// trusting a saved position blindly — it may point at a monitor
+    /// that's since been unplugged.
+    pub fn validated_window_state(&self, monitors: &[MonitorGeometry], primary: &MonitorGeometry) -> WindowState {
+        self.settings.window.validated(monitors, primary, self.settings.always_open_on_primary_monitor)
+    }
+
+    /// Remembers whether the window should always reopen on the primary
+    /// monitor instead of its last saved position, and flushes it to
+    /// disk immediately.
+    pub fn set_always_open_on_primary_monitor(&mut self, always_open_on_primary_monitor: bool) -> Result<(), AppSettingsError> {
+        self.settings.always_open_on_primary_monitor = always_open_on_primary_monitor;
+        self.save_settings()
+    }
+
+    /// Remembers the default disk-cleanup options and flushes them to
+    /// disk immediately, so the next scan starts with the same
+    /// checkboxes ticked.
+    pub fn set_disk_options(&mut self, disk_options: DiskCleanOptions) -> Result<(), AppSettingsError> {
+        self.settings.disk_options = disk_options;
+        self.save_settings()
+    }
+
+    /// Remembers the UI scale and flushes it to disk immediately; the
+    /// caller still has to call `egui::Context::set_pixels_per_point`
+    /// for the change to show up this frame.
+    pub fn set_ui_scale(&mut self, ui_scale: f32) -> Result<(), AppSettingsError> {
+        self.settings.ui_scale = ui_scale;
+        self.save_settings()
+    }
+
+    /// Opts in or out of the Services tab's periodic Defender status
+    /// poll and flushes it to disk immediately.
+    pub fn set_defender_live_status_enabled(&mut self, enabled: bool) -> Result<(), AppSettingsError> {
+        self.settings.defender_live_status_enabled = enabled;
+        self.save_settings()
+    }
+
+    /// Returns every recorded action, oldest first, for the history
+    /// panel to render.
+    pub fn history(&self) -> Result<Vec<ActionRecord>, ActionHistoryError> {
+        self.history.load_all()
+    }
+
+    /// Appends a new entry to the action history immediately. Called by
+    /// every call site that just did something worth remembering —
+    /// a cleanup, a speed limit change, a service toggle, a profile
+    /// activation — with `undo` set when that action left something to
+    /// undo.
+    pub fn record_action(&self, summary: impl Into<String>, undo: Option<UndoAction>) -> Result<(), ActionHistoryError> {
+        self.history.append(ActionRecord {
+            at: std::time::SystemTime::now(),
+            summary: summary.into(),
+            undo,
+        })
+    }
+
+    pub fn profiles(&self) -> impl Iterator<Item = &GamingProfile> {
+        self.profile_manager.profiles()
+    }
+
+    /// Saves `profile` and flushes it to disk immediately, so edits made
+    /// in the profile editor survive an unexpected exit.
+    pub fn save_profile(&mut self, profile: GamingProfile) -> Result<(), ProfileManagerError> {
+        self.profile_manager.upsert(profile)
+    }
+
+    pub fn delete_profile(&mut self, name: &str) -> Result<(), ProfileManagerError> {
+        self.profile_manager.remove(name)
+    }
+
+    pub fn shortcuts(&self) -> &ShortcutsConfig {
+        &self.shortcuts
+    }
+
+    /// Registers every global shortcut (`Clean RAM now`, `Toggle Focus
+    /// mode`) via `RegisterHotKey`, so they fire no matter which window
+    /// — including a fullscreen game — has focus. Call once at
+    /// startup, after construction.
+    pub fn register_global_shortcuts(&mut self) -> Result<(), HotkeyError> {
+        for (action, hotkey) in self.shortcuts.global_shortcuts().to_vec() {
+            self.hotkeys.register(action.hotkey_id(), &hotkey)?;
+        }
+        Ok(())
+    }
+
+    /// Drains pending global-hotkey triggers, mapped back to the
+    /// [`GlobalAction`] each one is bound to. Call once per frame.
+    pub fn poll_global_shortcuts(&mut self) -> Vec<GlobalAction> {
+        let triggered_ids = self.hotkeys.poll_triggered();
+        self.shortcuts
+            .global_shortcuts()
+            .iter()
+            .filter(|(action, _)| triggered_ids.contains(&action.hotkey_id()))
+            .map(|(action, _)| *action)
+            .collect()
+    }
+}
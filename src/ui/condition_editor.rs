@@ -0,0 +1,114 @@
+//! Condition builder shared by the task dialog's "add" and "edit"
+//! flows: turning a task's optional [`ConditionExpr`] into AND/OR
+//! groups of metric comparisons the user can add to, remove from, or
+//! regroup, without hand-editing JSON.
+
+use std::path::PathBuf;
+
+use crate::scheduler::{Comparison, ConditionExpr, ConditionMetric};
+
+/// Draws the editor for `condition`, replacing it entirely if the user
+/// toggles it on/off or changes its top-level AND/OR grouping.
+pub fn condition_editor(ui: &mut egui::Ui, condition: &mut Option<ConditionExpr>) {
+    let mut enabled = condition.is_some();
+    if ui.checkbox(&mut enabled, "Only run when").changed() {
+        *condition = enabled.then(default_leaf);
+    }
+
+    let Some(expr) = condition else { return };
+    ui.push_id("condition_builder", |ui| expr_editor(ui, expr));
+}
+
+fn expr_editor(ui: &mut egui::Ui, expr: &mut ConditionExpr) {
+    match expr {
+        ConditionExpr::Compare { metric, comparison, threshold } => {
+            leaf_editor(ui, metric, comparison, threshold);
+            ui.horizontal(|ui| {
+                if ui.button("Group with AND").clicked() {
+                    *expr = ConditionExpr::And(vec![expr.clone(), default_leaf()]);
+                }
+                if ui.button("Group with OR").clicked() {
+                    *expr = ConditionExpr::Or(vec![expr.clone(), default_leaf()]);
+                }
+            });
+        }
+        ConditionExpr::And(_) | ConditionExpr::Or(_) => {
+            let label = if matches!(expr, ConditionExpr::And(_)) { "ALL of:" } else { "ANY of:" };
+            ui.label(label);
+            let children = match expr {
+                ConditionExpr::And(children) | ConditionExpr::Or(children) => children,
+                ConditionExpr::Compare { .. } => unreachable!(),
+            };
+            let mut remove_at = None;
+            for (index, child) in children.iter_mut().enumerate() {
+                ui.push_id(index, |ui| {
+                    ui.indent("condition_group_child", |ui| {
+                        expr_editor(ui, child);
+                        if ui.button("Remove").clicked() {
+                            remove_at = Some(index);
+                        }
+                    });
+                });
+            }
+            if let Some(index) = remove_at {
+                children.remove(index);
+            }
+            if ui.button("Add condition").clicked() {
+                children.push(default_leaf());
+            }
+        }
+    }
+}
+
+fn leaf_editor(ui: &mut egui::Ui, metric: &mut ConditionMetric, comparison: &mut Comparison, threshold: &mut u64) {
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source("condition_metric")
+            .selected_text(metric_label(metric))
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(matches!(metric, ConditionMetric::RamUsedPercent), "RAM used %").clicked() {
+                    *metric = ConditionMetric::RamUsedPercent;
+                }
+                if ui
+                    .selectable_label(matches!(metric, ConditionMetric::FreeDiskSpaceBytes { .. }), "Free disk space (bytes)")
+                    .clicked()
+                {
+                    *metric = ConditionMetric::FreeDiskSpaceBytes { path: PathBuf::from("/") };
+                }
+            });
+
+        egui::ComboBox::from_id_source("condition_comparison")
+            .selected_text(if *comparison == Comparison::LessThan { "<" } else { ">" })
+            .show_ui(ui, |ui| {
+                ui.selectable_value(comparison, Comparison::LessThan, "<");
+                ui.selectable_value(comparison, Comparison::GreaterThan, ">");
+            });
+
+        ui.add(egui::DragValue::new(threshold));
+    });
+
+    if let ConditionMetric::FreeDiskSpaceBytes { path } = metric {
+        ui.horizontal(|ui| {
+            ui.label("Volume:");
+            let mut path_text = path.to_string_lossy().into_owned();
+            if ui.text_edit_singleline(&mut path_text).changed() {
+                *path = PathBuf::from(path_text);
+            }
+        });
+    }
+}
+
+fn metric_label(metric: &ConditionMetric) -> &'static str {
+    match metric {
+        ConditionMetric::RamUsedPercent => "RAM used %",
+        ConditionMetric::FreeDiskSpaceBytes { .. } => "Free disk space (bytes)",
+        ConditionMetric::FreeableDiskBytes { .. } => "Freeable disk bytes",
+    }
+}
+
+fn default_leaf() -> ConditionExpr {
+    ConditionExpr::Compare {
+        metric: ConditionMetric::RamUsedPercent,
+        comparison: Comparison::GreaterThan,
+        threshold: 80,
+    }
+}
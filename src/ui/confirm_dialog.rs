@@ -0,0 +1,57 @@
+//! The reusable confirmation modal shown before a
+//! [`DestructiveAction`] runs: recycle-bin emptying, disabling
+//! Defender's real-time protection, deleting a scheduled task, and
+//! applying Realtime CPU priority all share this one dialog instead of
+//! each rolling their own.
+
+use crate::confirm::DestructiveAction;
+
+/// What the user picked this frame. `None` means the dialog is still
+/// open and waiting.
+pub enum ConfirmDialogResponse {
+    Confirmed,
+    Cancelled,
+}
+
+/// Draws the modal for `action`, returning `Some` the frame the user
+/// clicks Confirm or Cancel (or presses Enter or Escape). `dont_ask_again` is
+/// the checkbox's own state, left for the caller to persist into
+/// [`crate::confirm::ConfirmPromptSettings`] only once the user
+/// actually confirms.
+pub fn confirm_dialog(
+    ctx: &egui::Context,
+    action: DestructiveAction,
+    dont_ask_again: &mut bool,
+) -> Option<ConfirmDialogResponse> {
+    let (title, body) = action.prompt();
+    let mut response = None;
+
+    // Enter confirms and Escape cancels even if focus never leaves the
+    // checkbox, so the whole dialog is usable without a mouse.
+    if ctx.input(|input| input.key_pressed(egui::Key::Enter)) {
+        response = Some(ConfirmDialogResponse::Confirmed);
+    } else if ctx.input(|input| input.key_pressed(egui::Key::Escape)) {
+        response = Some(ConfirmDialogResponse::Cancelled);
+    }
+
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.label(body);
+            ui.add_space(8.0);
+            ui.checkbox(dont_ask_again, "Don't ask again");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Confirm").on_hover_text("Confirm (Enter)").clicked() {
+                    response = Some(ConfirmDialogResponse::Confirmed);
+                }
+                if ui.button("Cancel").on_hover_text("Cancel (Esc)").clicked() {
+                    response = Some(ConfirmDialogResponse::Cancelled);
+                }
+            });
+        });
+
+    response
+}
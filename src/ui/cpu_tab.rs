@@ -0,0 +1,43 @@
+//! Rendering for the CPU tab's usage graphs: a small inline sparkline per
+//! monitored process row, plus a comparative chart for processes the user
+//! has selected.
+
+use egui_plot::{Line, Plot, PlotPoints};
+
+use crate::cpu::history::{CpuHistoryStore, ProcessCpuHistory};
+
+/// Fixed size of the inline per-row sparkline, in points.
+const SPARKLINE_SIZE: [f32; 2] = [80.0, 24.0];
+
+/// Draws a minimal, axis-free sparkline of `history`'s last 60s inline in
+/// a process row.
+pub fn sparkline(ui: &mut egui::Ui, history: &ProcessCpuHistory) {
+    let points = PlotPoints::from(history.as_plot_points());
+    Plot::new(ui.next_auto_id())
+        .width(SPARKLINE_SIZE[0])
+        .height(SPARKLINE_SIZE[1])
+        .show_axes([false, false])
+        .show_grid([false, false])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .show(ui, |plot_ui| {
+            plot_ui.line(Line::new(points));
+        });
+}
+
+/// Draws a comparative chart overlaying CPU usage history for multiple
+/// selected processes over the last [`crate::cpu::history::HISTORY_WINDOW_SECS`] seconds.
+pub fn comparative_chart(ui: &mut egui::Ui, store: &CpuHistoryStore, selected_pids: &[u32], label_for: impl Fn(u32) -> String) {
+    Plot::new("cpu_comparative_chart")
+        .height(180.0)
+        .x_axis_label("seconds ago")
+        .y_axis_label("% CPU")
+        .legend(egui_plot::Legend::default())
+        .show(ui, |plot_ui| {
+            for (pid, history) in store.comparative(selected_pids) {
+                let points = PlotPoints::from(history.as_plot_points());
+                plot_ui.line(Line::new(points).name(label_for(pid)));
+            }
+        });
+}
@@ -0,0 +1,201 @@
+//! Rendering for the Dashboard tab: at-a-glance cards for RAM, disk
+//! space, active network limits, the active profile, scheduler status,
+//! and the last cleanup result, each with a quick-action button so the
+//! most common actions don't require tab-hopping. Sampling each
+//! subsystem and laying the cards out is left to the call site — these
+//! are pure view functions, one per card, like the rest of `ui`.
+
+use std::time::Duration;
+
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+use crate::cleaning::{CategoryDelta, CleanupCategory, ScanSnapshot};
+
+/// A quick action the user picked from a Dashboard card this frame, for
+/// the caller to run against the relevant subsystem.
+pub enum DashboardAction {
+    CleanRamNow,
+    RunDiskCleanupNow,
+    DeactivateProfile,
+    /// "Export report" on the RAM card, producing an HTML snapshot via
+    /// [`crate::reporting::Report`].
+    ExportMemoryReport,
+    /// "Export report" on the last-cleanup card.
+    ExportDiskReport,
+}
+
+/// Draws the RAM usage card, with a "Clean RAM now" and an "Export
+/// report" button.
+pub fn ram_card(ui: &mut egui::Ui, used_percent: Option<u8>) -> Option<DashboardAction> {
+    let mut action = None;
+    ui.group(|ui| {
+        ui.strong("RAM");
+        match used_percent {
+            Some(percent) => {
+                ui.add(egui::ProgressBar::new(percent as f32 / 100.0).text(format!("{percent}% used")));
+            }
+            None => {
+                ui.label("(unavailable)");
+            }
+        }
+        ui.horizontal(|ui| {
+            if ui.button("Clean RAM now").clicked() {
+                action = Some(DashboardAction::CleanRamNow);
+            }
+            if ui.button("Export report").clicked() {
+                action = Some(DashboardAction::ExportMemoryReport);
+            }
+        });
+    });
+    action
+}
+
+/// Draws the free-disk-space card, with a "Run disk cleanup now" button.
+pub fn disk_card(ui: &mut egui::Ui, free_bytes: Option<u64>) -> Option<DashboardAction> {
+    let mut action = None;
+    ui.group(|ui| {
+        ui.strong("Disk space");
+        match free_bytes {
+            Some(bytes) => {
+                ui.label(format!("{:.1} GB free", bytes as f64 / 1e9));
+            }
+            None => {
+                ui.label("(unavailable)");
+            }
+        }
+        if ui.button("Run disk cleanup now").clicked() {
+            action = Some(DashboardAction::RunDiskCleanupNow);
+        }
+    });
+    action
+}
+
+fn category_label(category: CleanupCategory) -> &'static str {
+    match category {
+        CleanupCategory::TempFiles => "temp",
+        CleanupCategory::ShaderCache => "shader cache",
+        CleanupCategory::BrowserCache => "browser cache",
+    }
+}
+
+/// Draws the "since last scan" deltas under the disk space card, e.g.
+/// "+320 MB temp since last scan", so the user can tell whether running
+/// a cleanup now is actually worth it. Draws nothing once `deltas` is
+/// empty (the very first scan has no previous one to diff against).
+pub fn scan_diff_panel(ui: &mut egui::Ui, deltas: &[CategoryDelta]) {
+    if deltas.is_empty() {
+        return;
+    }
+    ui.group(|ui| {
+        ui.strong("Since last scan");
+        for delta in deltas {
+            let delta_bytes = delta.delta_bytes();
+            let sign = if delta_bytes >= 0 { "+" } else { "-" };
+            let megabytes = delta_bytes.unsigned_abs() as f64 / 1e6;
+            ui.label(format!("{sign}{megabytes:.0} MB {}", category_label(delta.category)));
+        }
+    });
+}
+
+/// Draws a small chart of freeable bytes per category across every scan
+/// in `snapshots`, oldest first, so a slow creeping build-up is visible
+/// even when each individual delta looks small.
+pub fn scan_growth_chart(ui: &mut egui::Ui, snapshots: &[ScanSnapshot]) {
+    let categories = [CleanupCategory::TempFiles, CleanupCategory::ShaderCache, CleanupCategory::BrowserCache];
+    Plot::new("dashboard_scan_growth_chart")
+        .height(140.0)
+        .x_axis_label("scan #")
+        .y_axis_label("MB")
+        .include_y(0.0)
+        .legend(Legend::default())
+        .show(ui, |plot_ui| {
+            for category in categories {
+                let points: PlotPoints = snapshots
+                    .iter()
+                    .enumerate()
+                    .map(|(index, snapshot)| {
+                        let bytes = snapshot.freeable_bytes_by_category.get(&category).copied().unwrap_or(0);
+                        [index as f64, bytes as f64 / 1e6]
+                    })
+                    .collect();
+                plot_ui.line(Line::new(points).name(category_label(category)));
+            }
+        });
+}
+
+/// Draws the active network limit card. `limit_kbps` is `None` when no
+/// profile is currently throttling background traffic.
+pub fn network_card(ui: &mut egui::Ui, limit_kbps: Option<u32>) {
+    ui.group(|ui| {
+        ui.strong("Network");
+        match limit_kbps {
+            Some(kbps) => {
+                ui.label(format!("Background traffic capped at {kbps} kbps"));
+            }
+            None => {
+                ui.label("No active limit");
+            }
+        }
+    });
+}
+
+/// Draws the active-profile card, with a "Deactivate" button shown only
+/// while a profile is active.
+pub fn active_profile_card(ui: &mut egui::Ui, active_profile_name: Option<&str>) -> Option<DashboardAction> {
+    let mut action = None;
+    ui.group(|ui| {
+        ui.strong("Active profile");
+        match active_profile_name {
+            Some(name) => {
+                ui.label(name);
+                if ui.button("Deactivate").clicked() {
+                    action = Some(DashboardAction::DeactivateProfile);
+                }
+            }
+            None => {
+                ui.label("(none running)");
+            }
+        }
+    });
+    action
+}
+
+/// Draws the scheduler-status card: each task running right now, by
+/// name with a spinner, or "Idle" when none is, mirroring
+/// [`crate::scheduler::RunningTasks::snapshot`].
+pub fn scheduler_card(ui: &mut egui::Ui, running_task_names: &[String]) {
+    ui.group(|ui| {
+        ui.strong("Scheduler");
+        if running_task_names.is_empty() {
+            ui.label("Idle");
+        } else {
+            for task_name in running_task_names {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(task_name);
+                });
+            }
+        }
+    });
+}
+
+/// Draws the last-cleanup-result card. `None` means nothing has run
+/// yet, in which case there's nothing to export either.
+pub fn last_cleanup_card(ui: &mut egui::Ui, bytes_freed: Option<u64>, ran_ago: Option<Duration>) -> Option<DashboardAction> {
+    let mut action = None;
+    ui.group(|ui| {
+        ui.strong("Last cleanup");
+        match (bytes_freed, ran_ago) {
+            (Some(bytes), Some(ago)) => {
+                ui.label(format!("Freed {:.1} GB, {}s ago", bytes as f64 / 1e9, ago.as_secs()));
+                if ui.button("Export report").clicked() {
+                    action = Some(DashboardAction::ExportDiskReport);
+                }
+            }
+            _ => {
+                ui.label("(none yet)");
+            }
+        }
+    });
+    action
+}
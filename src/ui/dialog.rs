@@ -0,0 +1,157 @@
+//! A reusable modal-editor state machine for add/edit dialogs: the
+//! scheduler's add/edit task dialog, and any future add/edit profile
+//! dialog, share this instead of each inferring "am I editing an
+//! existing one" and "has anything changed" from incidental field
+//! state — the old task dialog used to check `edit_task_name.is_empty()`
+//! to tell new from existing, which broke for a blank name and left
+//! stale state behind when reopened for a different task.
+
+/// Which value a [`DialogState`] is currently editing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditTarget<T> {
+    /// A brand new value, not saved anywhere yet.
+    New(T),
+    /// Editing an existing value, keyed by whatever identifies it (a
+    /// task name, a profile name) so Save knows what to overwrite.
+    Existing { key: String, value: T },
+}
+
+impl<T> EditTarget<T> {
+    pub fn value(&self) -> &T {
+        match self {
+            EditTarget::New(value) => value,
+            EditTarget::Existing { value, .. } => value,
+        }
+    }
+
+    pub fn value_mut(&mut self) -> &mut T {
+        match self {
+            EditTarget::New(value) => value,
+            EditTarget::Existing { value, .. } => value,
+        }
+    }
+}
+
+/// A modal add/edit dialog's state, generic over whatever it edits.
+/// `Closed` is the only variant with no in-progress value, so a caller
+/// can always tell "open" from "editing a blank value" apart — the bug
+/// this replaces.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DialogState<T> {
+    Closed,
+    /// Open and tracking whether `target` has diverged from `original`
+    /// since it was opened.
+    Editing { target: EditTarget<T>, original: T },
+    /// The user asked to close a dialog with unsaved changes; waiting
+    /// on the discard-changes prompt's answer instead of closing
+    /// immediately.
+    ConfirmDiscard { target: EditTarget<T>, original: T },
+}
+
+impl<T> Default for DialogState<T> {
+    fn default() -> Self {
+        DialogState::Closed
+    }
+}
+
+impl<T: Clone + PartialEq> DialogState<T> {
+    pub fn closed() -> Self {
+        DialogState::Closed
+    }
+
+    /// Opens the dialog on a fresh, unsaved `value`.
+    pub fn open_new(value: T) -> Self {
+        DialogState::Editing { original: value.clone(), target: EditTarget::New(value) }
+    }
+
+    /// Opens the dialog on an existing value, keyed by `key`.
+    pub fn open_existing(key: impl Into<String>, value: T) -> Self {
+        DialogState::Editing { original: value.clone(), target: EditTarget::Existing { key: key.into(), value } }
+    }
+
+    pub fn is_open(&self) -> bool {
+        !matches!(self, DialogState::Closed)
+    }
+
+    /// Whether the in-progress edit has diverged from what the dialog
+    /// opened with.
+    pub fn is_dirty(&self) -> bool {
+        match self {
+            DialogState::Closed => false,
+            DialogState::Editing { target, original } | DialogState::ConfirmDiscard { target, original } => target.value() != original,
+        }
+    }
+
+    /// Call when the user asks to close the dialog (Cancel, the
+    /// window's own close button). Closes immediately if nothing
+    /// changed, otherwise moves to `ConfirmDiscard` so the caller can
+    /// show a prompt instead of dropping the edit silently.
+    pub fn request_close(&mut self) {
+        if !self.is_dirty() {
+            *self = DialogState::Closed;
+            return;
+        }
+        if let DialogState::Editing { target, original } = std::mem::replace(self, DialogState::Closed) {
+            *self = DialogState::ConfirmDiscard { target, original };
+        }
+    }
+
+    /// Confirms discarding unsaved changes from a `ConfirmDiscard`
+    /// prompt.
+    pub fn confirm_discard(&mut self) {
+        *self = DialogState::Closed;
+    }
+
+    /// Backs out of a `ConfirmDiscard` prompt, returning to editing.
+    pub fn cancel_discard(&mut self) {
+        if let DialogState::ConfirmDiscard { target, original } = std::mem::replace(self, DialogState::Closed) {
+            *self = DialogState::Editing { target, original };
+        }
+    }
+
+    pub fn target(&self) -> Option<&EditTarget<T>> {
+        match self {
+            DialogState::Closed => None,
+            DialogState::Editing { target, .. } | DialogState::ConfirmDiscard { target, .. } => Some(target),
+        }
+    }
+
+    pub fn target_mut(&mut self) -> Option<&mut EditTarget<T>> {
+        match self {
+            DialogState::Closed => None,
+            DialogState::Editing { target, .. } | DialogState::ConfirmDiscard { target, .. } => Some(target),
+        }
+    }
+}
+
+/// What the user picked on the discard-changes prompt.
+pub enum DiscardChoice {
+    Discard,
+    KeepEditing,
+}
+
+/// Draws the "Discard unsaved changes?" prompt shown while a
+/// [`DialogState`] is in `ConfirmDiscard`, mirroring
+/// [`crate::ui::confirm_dialog::confirm_dialog`]'s layout.
+pub fn discard_changes_dialog(ctx: &egui::Context) -> Option<DiscardChoice> {
+    let mut choice = None;
+
+    egui::Window::new("Discard changes?")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.label("You have unsaved changes. Discard them?");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if ui.button("Discard").clicked() {
+                    choice = Some(DiscardChoice::Discard);
+                }
+                if ui.button("Keep editing").clicked() {
+                    choice = Some(DiscardChoice::KeepEditing);
+                }
+            });
+        });
+
+    choice
+}
@@ -0,0 +1,64 @@
+//! Rendering for the Games tab: every installed game
+//! [`discover_installed_games`](crate::profiles::discover_installed_games)
+//! found, with a Play button that launches it and names the profile
+//! that's linked by matching
+//! [`GamingProfile::target_process_name`](crate::profiles::GamingProfile)
+//! against the game's executable — the same matching
+//! [`crate::profiles::watcher::ProfileWatcher`] already does once the
+//! launched game's process actually appears, so Play doesn't need to
+//! apply the profile itself.
+
+use crate::profiles::{DiscoveredGame, GamingProfile};
+
+/// A quick action the user picked from a Games-tab row this frame.
+pub enum GamesAction {
+    /// Launch this game via [`crate::profiles::launcher::launch`].
+    Play(DiscoveredGame),
+}
+
+/// Finds the profile, if any, whose `target_process_name` matches
+/// `game`'s executable — the profile that'll activate once `game` is
+/// running.
+pub fn linked_profile<'a>(game: &DiscoveredGame, profiles: &'a [&'a GamingProfile]) -> Option<&'a GamingProfile> {
+    let executable_name = game.executable_name();
+    profiles.iter().copied().find(|profile| profile.target_process_name == executable_name)
+}
+
+/// Draws one row per discovered game: its name, launcher, linked
+/// profile (or a note that none is linked yet) and a Play button.
+pub fn games_tab(ui: &mut egui::Ui, games: &[DiscoveredGame], profiles: &[&GamingProfile]) -> Option<GamesAction> {
+    let mut action = None;
+
+    if games.is_empty() {
+        ui.label("No installed games found.");
+        return None;
+    }
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for game in games {
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.vertical(|ui| {
+                        ui.strong(&game.name);
+                        ui.weak(format!("{:?}", game.launcher));
+                        match linked_profile(game, profiles) {
+                            Some(profile) => {
+                                ui.label(format!("Profile: {}", profile.name));
+                            }
+                            None => {
+                                ui.weak("No linked profile");
+                            }
+                        }
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("Play").clicked() {
+                            action = Some(GamesAction::Play(game.clone()));
+                        }
+                    });
+                });
+            });
+        }
+    });
+
+    action
+}
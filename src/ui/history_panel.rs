@@ -0,0 +1,44 @@
+//! Rendering for the history panel: a reverse-chronological timeline of
+//! every [`ActionRecord`], each with an "Undo" link when it left
+//! something undoable. A pure view, like the rest of `ui` — applying
+//! the returned [`UndoAction`] is left to the call site.
+
+use std::time::SystemTime;
+
+use crate::history::{ActionRecord, UndoAction};
+
+/// Draws `records` newest first, returning the [`UndoAction`] the user
+/// clicked "Undo" for this frame, if any.
+pub fn history_panel(ui: &mut egui::Ui, records: &[ActionRecord]) -> Option<UndoAction> {
+    let mut undo = None;
+
+    if records.is_empty() {
+        ui.label("(no actions recorded yet)");
+        return None;
+    }
+
+    egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+        for record in records.iter().rev() {
+            ui.horizontal(|ui| {
+                ui.label(format_ago(record.at));
+                ui.label(&record.summary);
+                if let Some(action) = &record.undo {
+                    if ui.button("Undo").clicked() {
+                        undo = Some(action.clone());
+                    }
+                }
+            });
+        }
+    });
+
+    undo
+}
+
+/// Renders `at` as "Ns ago" for the timeline, falling back to "just
+/// now" for a record from the same instant this frame is drawn.
+fn format_ago(at: SystemTime) -> String {
+    match at.elapsed() {
+        Ok(elapsed) if elapsed.as_secs() > 0 => format!("{}s ago", elapsed.as_secs()),
+        _ => "just now".to_string(),
+    }
+}
@@ -0,0 +1,58 @@
+//! Tracks which long-running operations are currently in flight, so the
+//! app shell has a single `is_not_busy()` check instead of every tab
+//! keeping its own "is this still running" flag, and the embedder knows
+//! when it's safe to fall back to an infrequent repaint instead of
+//! requesting one every frame.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+/// How often the embedder should request a repaint while
+/// [`AppJobs::is_not_busy`] is `true` — frequent enough that the
+/// Dashboard's sampled numbers still feel live, far below the framerate
+/// a spinner needs.
+pub const IDLE_REPAINT_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A long-running operation tracked by [`AppJobs`], named for the
+/// action that started it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Job {
+    CleaningRam,
+    RunningDiskCleanup,
+}
+
+/// Centralizes "is anything in flight right now" across every tab, so
+/// [`crate::ui::app::CleanRamApp::is_not_busy`] reflects every
+/// in-progress action rather than each call site guessing from its own
+/// state. Whoever kicks a [`Job`] off is responsible for calling
+/// [`AppJobs::start`] before and [`AppJobs::finish`] after — this crate
+/// has no async runtime of its own, so there's no promise to poll here.
+#[derive(Debug, Default)]
+pub struct AppJobs {
+    running: HashSet<Job>,
+}
+
+impl AppJobs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&mut self, job: Job) {
+        self.running.insert(job);
+    }
+
+    pub fn finish(&mut self, job: Job) {
+        self.running.remove(&job);
+    }
+
+    pub fn is_running(&self, job: Job) -> bool {
+        self.running.contains(&job)
+    }
+
+    /// `true` once every tracked job has finished, for the embedder to
+    /// decide between requesting a repaint next frame or waiting out
+    /// [`IDLE_REPAINT_INTERVAL`].
+    pub fn is_not_busy(&self) -> bool {
+        self.running.is_empty()
+    }
+}
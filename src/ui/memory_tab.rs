@@ -0,0 +1,22 @@
+//! Rendering for the Memory tab: a rolling chart of RAM usage over the
+//! last [`crate::cpu::history::HISTORY_WINDOW_SECS`] seconds, fed by the
+//! shared [`crate::system::sampler::StatsSampler`] instead of a
+//! tab-local poll loop.
+
+use egui_plot::{Line, Plot, PlotPoints};
+
+use crate::system::sampler::StatsSampler;
+
+/// Draws the RAM usage chart.
+pub fn ram_chart(ui: &mut egui::Ui, sampler: &StatsSampler) {
+    let points = PlotPoints::from(sampler.ram_percent_plot_points());
+    Plot::new("memory_tab_ram_chart")
+        .height(180.0)
+        .x_axis_label("seconds ago")
+        .y_axis_label("% RAM used")
+        .include_y(0.0)
+        .include_y(100.0)
+        .show(ui, |plot_ui| {
+            plot_ui.line(Line::new(points).name("RAM used"));
+        });
+}
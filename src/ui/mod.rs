@@ -0,0 +1,28 @@
+//! egui rendering helpers, organized by tab. These are pure view
+//! functions: they take already-computed state and an `egui::Ui` to draw
+//! into, and leave sampling/mutation to the call sites in the app shell.
+
+pub mod accessibility;
+pub mod app;
+pub mod condition_editor;
+pub mod confirm_dialog;
+pub mod cpu_tab;
+pub mod dashboard_tab;
+pub mod dialog;
+pub mod games_tab;
+pub mod history_panel;
+pub mod jobs;
+pub mod memory_tab;
+pub mod network_tab;
+pub mod overlay;
+pub mod profile_editor;
+pub mod report_export;
+pub mod schedule_editor;
+pub mod scheduler_tab;
+pub mod services_tab;
+pub mod shortcuts;
+pub mod settings_tab;
+pub mod theme_tab;
+pub mod title_bar;
+
+pub use app::CleanRamApp;
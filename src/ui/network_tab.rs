@@ -0,0 +1,72 @@
+//! Rendering for the Network tab: a rolling download/upload throughput
+//! chart over the last [`crate::cpu::history::HISTORY_WINDOW_SECS`]
+//! seconds, fed by the shared [`crate::system::sampler::StatsSampler`]
+//! instead of a tab-local poll loop, and the per-process rate limit
+//! table underneath.
+
+use std::collections::HashMap;
+
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+use crate::network::NetworkProcessRow;
+use crate::system::sampler::StatsSampler;
+
+/// Draws the download/upload throughput chart.
+pub fn throughput_chart(ui: &mut egui::Ui, sampler: &StatsSampler) {
+    let down = PlotPoints::from(sampler.network_down_plot_points());
+    let up = PlotPoints::from(sampler.network_up_plot_points());
+    Plot::new("network_tab_throughput_chart")
+        .height(180.0)
+        .x_axis_label("seconds ago")
+        .y_axis_label("kbps")
+        .include_y(0.0)
+        .legend(Legend::default())
+        .show(ui, |plot_ui| {
+            plot_ui.line(Line::new(down).name("Download"));
+            plot_ui.line(Line::new(up).name("Upload"));
+        });
+}
+
+/// What the user did to one process's limit this frame, for the caller
+/// to run against [`crate::network::NetworkLimiter`].
+pub enum NetworkLimitAction {
+    SetLimit { pid: u32, down_kbps: u32, up_kbps: u32 },
+    ClearLimit { pid: u32 },
+}
+
+/// Draws one row per running process with an inline down/up kbps drag
+/// value and an Apply button, and an "active limit" badge on whichever
+/// rows already have one — replacing a single shared speed-limit field
+/// that applied to whatever process was selected. `edits` holds each
+/// row's in-progress (not yet applied) values across frames, keyed by
+/// pid, seeded from the row's active limit the first time it's drawn.
+pub fn process_limit_table(ui: &mut egui::Ui, rows: &[NetworkProcessRow], edits: &mut HashMap<u32, (u32, u32)>) -> Option<NetworkLimitAction> {
+    let mut action = None;
+
+    egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+        for row in rows {
+            let (down_kbps, up_kbps) = edits
+                .entry(row.pid)
+                .or_insert_with(|| row.active_limit.map(|limit| (limit.down_kbps, limit.up_kbps)).unwrap_or((0, 0)));
+
+            ui.horizontal(|ui| {
+                ui.label(&row.name);
+                if row.active_limit.is_some() {
+                    ui.colored_label(egui::Color32::LIGHT_BLUE, "active limit");
+                }
+                ui.label("Down:");
+                ui.add(egui::DragValue::new(down_kbps).suffix(" kbps").range(0..=1_000_000));
+                ui.label("Up:");
+                ui.add(egui::DragValue::new(up_kbps).suffix(" kbps").range(0..=1_000_000));
+                if ui.button("Apply").clicked() {
+                    action = Some(NetworkLimitAction::SetLimit { pid: row.pid, down_kbps: *down_kbps, up_kbps: *up_kbps });
+                }
+                if row.active_limit.is_some() && ui.button("Clear").clicked() {
+                    action = Some(NetworkLimitAction::ClearLimit { pid: row.pid });
+                }
+            });
+        }
+    });
+
+    action
+}
@@ -0,0 +1,54 @@
+//! The compact always-on-top overlay: a borderless egui viewport
+//! showing RAM%, CPU%, ping and download speed, for glancing at while a
+//! game is running borderless-windowed and the main window is hidden
+//! behind it. Toggled from the tray; sampling is shared with the
+//! Memory/CPU/Network tabs through [`crate::system::sampler::StatsSampler`]
+//! so opening it doesn't start a second poll loop.
+
+use egui::{Color32, ViewportBuilder, ViewportId};
+
+/// A snapshot of the numbers the overlay shows, read once per frame
+/// from the shared [`crate::system::sampler::StatsSampler`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OverlayStats {
+    pub ram_percent: Option<f64>,
+    pub cpu_percent: Option<f64>,
+    pub ping_ms: Option<u32>,
+    pub network_down_kbps: Option<f64>,
+}
+
+/// Stable id for the overlay's viewport, so repeated calls update the
+/// same window instead of spawning a new one each frame. Not a `const`
+/// since `ViewportId::from_hash_of` isn't a `const fn`, but it hashes
+/// the same fixed string every call, so every call returns the same id.
+pub fn overlay_viewport_id() -> ViewportId {
+    ViewportId::from_hash_of("clean_ram_overlay")
+}
+
+fn format_row(label: &str, value: Option<String>) -> String {
+    format!("{label}: {}", value.unwrap_or_else(|| "--".to_string()))
+}
+
+/// Builds the borderless, always-on-top viewport and draws the stats
+/// into it. Call every frame the overlay is enabled; the caller decides
+/// when to stop calling it, which closes the viewport.
+pub fn show_overlay(ctx: &egui::Context, stats: OverlayStats) {
+    let viewport = ViewportBuilder::default()
+        .with_title("Clean RAM overlay")
+        .with_decorations(false)
+        .with_always_on_top()
+        .with_transparent(true)
+        .with_inner_size([180.0, 110.0]);
+
+    ctx.show_viewport_immediate(overlay_viewport_id(), viewport, |ctx, _class| {
+        egui::CentralPanel::default().frame(egui::Frame::none().fill(Color32::from_black_alpha(180))).show(ctx, |ui| {
+            ui.colored_label(Color32::WHITE, format_row("RAM", stats.ram_percent.map(|p| format!("{p:.0}%"))));
+            ui.colored_label(Color32::WHITE, format_row("CPU", stats.cpu_percent.map(|p| format!("{p:.0}%"))));
+            ui.colored_label(Color32::WHITE, format_row("Ping", stats.ping_ms.map(|ms| format!("{ms} ms"))));
+            ui.colored_label(
+                Color32::WHITE,
+                format_row("Down", stats.network_down_kbps.map(|kbps| format!("{kbps:.0} kbps"))),
+            );
+        });
+    });
+}
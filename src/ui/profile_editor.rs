@@ -0,0 +1,36 @@
+//! Background app whitelist editor for the profile editor: lets the
+//! user exempt specific processes (Discord, Spotify, capture software)
+//! from background limits and suspension.
+
+use crate::profiles::GamingProfile;
+
+/// Draws the whitelist editor: existing entries with a remove button,
+/// plus a process picker to add a new one from `running_process_names`.
+pub fn background_whitelist_editor(ui: &mut egui::Ui, profile: &mut GamingProfile, running_process_names: &[String]) {
+    ui.label("Exempt from background limits:");
+
+    let mut remove_index = None;
+    for (i, name) in profile.background_app_whitelist.iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(name);
+            if ui.small_button("Remove").clicked() {
+                remove_index = Some(i);
+            }
+        });
+    }
+    if let Some(i) = remove_index {
+        profile.background_app_whitelist.remove(i);
+    }
+
+    ui.menu_button("+ Add process…", |ui| {
+        for candidate in running_process_names {
+            if profile.background_app_whitelist.iter().any(|w| w.eq_ignore_ascii_case(candidate)) {
+                continue;
+            }
+            if ui.button(candidate).clicked() {
+                profile.background_app_whitelist.push(candidate.clone());
+                ui.close_menu();
+            }
+        }
+    });
+}
@@ -0,0 +1,26 @@
+//! Builds the [`Report`]s behind the Memory and Disk cards' "Export
+//! report" buttons, translating whatever state those cards already
+//! render into the generic summary/chart shape
+//! [`crate::reporting::Report`] knows how to write out.
+
+use crate::cleaning::CleanupReport;
+use crate::reporting::Report;
+use crate::system::sampler::StatsSampler;
+
+/// The RAM card's current chart and reading, as a report ready to
+/// export.
+pub fn memory_report(sampler: &StatsSampler, used_percent: Option<u8>) -> Report {
+    let mut report = Report::new("Memory report");
+    if let Some(percent) = used_percent {
+        report = report.with_summary_row("RAM used", format!("{percent}%"));
+    }
+    report.with_chart("RAM usage", "% used", sampler.ram_percent_plot_points())
+}
+
+/// The last-cleanup card's totals, as a report ready to export.
+pub fn disk_report(cleanup: &CleanupReport) -> Report {
+    Report::new("Disk cleanup report")
+        .with_summary_row("Freed", format!("{:.1} GB", cleanup.bytes_freed as f64 / 1e9))
+        .with_summary_row("Files removed", cleanup.files_removed.to_string())
+        .with_summary_row("Skipped (excluded)", cleanup.skipped_excluded.to_string())
+}
@@ -0,0 +1,179 @@
+//! Schedule rule editor shared by the task dialog's "add" and "edit"
+//! flows: picking a rule kind, editing its fields, and previewing the
+//! next time it would fire.
+
+use std::time::SystemTime;
+
+use crate::scheduler::{DateTime, ScheduleRule, TimeOfDay, TimeWindow};
+
+/// Draws the editor for `rule`, replacing it entirely if the user picks
+/// a different rule kind. Shows a validation error or a next-run
+/// preview underneath, depending on whether the current value parses.
+pub fn schedule_rule_editor(ui: &mut egui::Ui, rule: &mut Option<ScheduleRule>) {
+    ui.horizontal(|ui| {
+        ui.label("Repeats:");
+        egui::ComboBox::from_id_source("schedule_rule_kind")
+            .selected_text(kind_label(rule))
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(rule.is_none(), "Never").clicked() {
+                    *rule = None;
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::Interval { .. })), "Interval").clicked() {
+                    *rule = Some(ScheduleRule::Interval { period_secs: 3600, window: None });
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::Daily { .. })), "Daily").clicked() {
+                    *rule = Some(ScheduleRule::Daily { hour: 9, minute: 0, jitter_minutes: None });
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::Monthly { .. })), "Monthly").clicked() {
+                    *rule = Some(ScheduleRule::Monthly { day: 1, time: TimeOfDay { hour: 9, minute: 0 } });
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::Cron(_))), "Cron expression").clicked() {
+                    *rule = Some(ScheduleRule::Cron("0 9 * * *".to_string()));
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::Once { .. })), "Once").clicked() {
+                    *rule = Some(ScheduleRule::Once {
+                        at: DateTime { year: 2026, month: 1, day: 1, hour: 9, minute: 0 },
+                    });
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::OnProcessExit { .. })), "On process exit").clicked() {
+                    *rule = Some(ScheduleRule::OnProcessExit { exe: String::new() });
+                }
+                if ui.selectable_label(matches!(rule, Some(ScheduleRule::OnLogon)), "On logon").clicked() {
+                    *rule = Some(ScheduleRule::OnLogon);
+                }
+            });
+    });
+
+    let Some(rule) = rule else { return };
+
+    match rule {
+        ScheduleRule::Interval { period_secs, window } => {
+            ui.horizontal(|ui| {
+                ui.label("Every");
+                let mut minutes = *period_secs / 60;
+                if ui.add(egui::DragValue::new(&mut minutes).range(1..=1440)).changed() {
+                    *period_secs = minutes * 60;
+                }
+                ui.label("minutes");
+            });
+
+            let mut restricted = window.is_some();
+            if ui.checkbox(&mut restricted, "Only between").changed() {
+                *window = restricted.then(|| TimeWindow {
+                    start: TimeOfDay { hour: 1, minute: 0 },
+                    end: TimeOfDay { hour: 6, minute: 0 },
+                });
+            }
+            if let Some(window) = window {
+                time_of_day_picker(ui, &mut window.start.hour, &mut window.start.minute);
+                ui.label("and");
+                time_of_day_picker(ui, &mut window.end.hour, &mut window.end.minute);
+            }
+        }
+        ScheduleRule::Daily { hour, minute, jitter_minutes } => {
+            time_of_day_picker(ui, hour, minute);
+
+            let mut jittered = jitter_minutes.is_some();
+            if ui.checkbox(&mut jittered, "Add random jitter").changed() {
+                *jitter_minutes = jittered.then_some(15);
+            }
+            if let Some(minutes) = jitter_minutes {
+                ui.horizontal(|ui| {
+                    ui.label("±");
+                    ui.add(egui::DragValue::new(minutes).range(1..=720));
+                    ui.label("minutes");
+                });
+            }
+        }
+        ScheduleRule::Monthly { day, time } => {
+            ui.horizontal(|ui| {
+                ui.label("Day");
+                ui.add(egui::DragValue::new(day).range(1..=31));
+            });
+            time_of_day_picker(ui, &mut time.hour, &mut time.minute);
+        }
+        ScheduleRule::Cron(expr) => {
+            ui.horizontal(|ui| {
+                ui.label("Expression");
+                ui.text_edit_singleline(expr);
+            });
+        }
+        ScheduleRule::Once { at } => {
+            ui.horizontal(|ui| {
+                ui.add(egui::DragValue::new(&mut at.year).range(1970..=9999));
+                ui.add(egui::DragValue::new(&mut at.month).range(1..=12));
+                ui.add(egui::DragValue::new(&mut at.day).range(1..=31));
+            });
+            time_of_day_picker(ui, &mut at.hour, &mut at.minute);
+        }
+        ScheduleRule::OnProcessExit { exe } => {
+            ui.horizontal(|ui| {
+                ui.label("Process name");
+                ui.text_edit_singleline(exe);
+            });
+        }
+        ScheduleRule::OnLogon => {}
+    }
+
+    match rule.validate() {
+        Err(err) => {
+            ui.colored_label(egui::Color32::RED, format!("Invalid schedule: {err}"));
+        }
+        Ok(()) => match rule {
+            ScheduleRule::OnProcessExit { .. } => {
+                ui.label("Fires right after the named process exits.");
+            }
+            ScheduleRule::OnLogon => {
+                ui.label("Fires once at app/service startup.");
+            }
+            _ => {
+                let occurrences = rule.next_n_after(SystemTime::now(), NEXT_OCCURRENCES_PREVIEW_COUNT);
+                if occurrences.is_empty() {
+                    ui.label("This schedule won't fire again.");
+                } else {
+                    ui.label("Next occurrences:");
+                    for occurrence in &occurrences {
+                        ui.label(format_system_time(*occurrence));
+                    }
+                }
+            }
+        },
+    }
+}
+
+/// How many upcoming occurrences the task dialog previews below the
+/// schedule editor.
+const NEXT_OCCURRENCES_PREVIEW_COUNT: usize = 5;
+
+fn kind_label(rule: &Option<ScheduleRule>) -> &'static str {
+    match rule {
+        None => "Never",
+        Some(ScheduleRule::Interval { .. }) => "Interval",
+        Some(ScheduleRule::Daily { .. }) => "Daily",
+        Some(ScheduleRule::Monthly { .. }) => "Monthly",
+        Some(ScheduleRule::Cron(_)) => "Cron expression",
+        Some(ScheduleRule::Once { .. }) => "Once",
+        Some(ScheduleRule::OnProcessExit { .. }) => "On process exit",
+        Some(ScheduleRule::OnLogon) => "On logon",
+    }
+}
+
+fn time_of_day_picker(ui: &mut egui::Ui, hour: &mut u8, minute: &mut u8) {
+    ui.horizontal(|ui| {
+        ui.label("At");
+        ui.add(egui::DragValue::new(hour).range(0..=23));
+        ui.label(":");
+        ui.add(egui::DragValue::new(minute).range(0..=59));
+        ui.label("UTC");
+    });
+}
+
+/// Renders a `SystemTime` as a Unix timestamp for the next-run preview.
+/// Good enough for a preview label; a proper calendar string belongs in
+/// a date/time crate this app doesn't depend on yet.
+fn format_system_time(time: SystemTime) -> String {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(duration) => format!("{} (unix time)", duration.as_secs()),
+        Err(_) => "before 1970".to_string(),
+    }
+}
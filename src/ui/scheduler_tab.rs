@@ -0,0 +1,226 @@
+//! Rendering for the Scheduler tab's task list and execution history
+//! pane: drag-and-drop task ordering and grouping, a filterable table
+//! of past runs with a CSV export button, and the add/edit task dialog
+//! built on [`crate::scheduler::TaskFormModel`].
+
+use std::collections::BTreeMap;
+
+use crate::scheduler::{dry_run_task, to_csv, ExecutionRecord, ScheduledTask, TaskFormModel, TaskOutcome, TaskType};
+use crate::ui::dialog::{DialogState, EditTarget};
+
+/// What the user did to the task list this frame, for the caller to
+/// apply via [`crate::scheduler::reorder_tasks`] (and a plain field
+/// assignment for `SetGroup`) before saving to
+/// [`crate::scheduler::SchedulerStore`].
+pub enum TaskOrganizeAction {
+    /// Dropped `moved_task` onto `before_task`'s row.
+    Reorder { moved_task: String, before_task: String },
+    /// Dropped `task_name` onto a group's collapsible header.
+    SetGroup { task_name: String, group: Option<String> },
+}
+
+/// Draws `tasks` grouped into collapsible sections named by
+/// [`ScheduledTask::group`] (in `group_order`, with ungrouped tasks in
+/// their own trailing section), each row draggable by its "⠿" handle —
+/// dropped on another row to reorder, dropped on a section header to
+/// move into that group.
+pub fn task_organizer_panel(ui: &mut egui::Ui, tasks: &[ScheduledTask], group_order: &[String]) -> Option<TaskOrganizeAction> {
+    let mut action = None;
+
+    let mut by_group: BTreeMap<Option<String>, Vec<&ScheduledTask>> = BTreeMap::new();
+    for task in tasks {
+        by_group.entry(task.group.clone()).or_default().push(task);
+    }
+    for group_tasks in by_group.values_mut() {
+        group_tasks.sort_by_key(|task| task.order);
+    }
+
+    let mut sections: Vec<Option<String>> = group_order.iter().cloned().map(Some).collect();
+    sections.push(None);
+
+    for section in sections {
+        let Some(tasks_in_section) = by_group.get(&section) else { continue };
+        let heading = section.clone().unwrap_or_else(|| "Ungrouped".to_string());
+
+        let header_response = egui::CollapsingHeader::new(&heading).default_open(true).show(ui, |ui| {
+            for task in tasks_in_section {
+                let item_id = egui::Id::new("scheduler_task_row").with(&task.name);
+                let drag_response = ui
+                    .dnd_drag_source(item_id, task.name.clone(), |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("⠿");
+                            ui.label(&task.name);
+                        });
+                    })
+                    .response;
+
+                if let Some(dropped_name) = drag_response.dnd_release_payload::<String>() {
+                    if dropped_name.as_str() != task.name {
+                        action = Some(TaskOrganizeAction::Reorder {
+                            moved_task: (*dropped_name).clone(),
+                            before_task: task.name.clone(),
+                        });
+                    }
+                }
+            }
+        });
+
+        let header_drop = ui.interact(header_response.header_response.rect, ui.id().with(("task_group_drop", &heading)), egui::Sense::hover());
+        if let Some(dropped_name) = header_drop.dnd_release_payload::<String>() {
+            action = Some(TaskOrganizeAction::SetGroup { task_name: (*dropped_name).clone(), group: section.clone() });
+        }
+    }
+
+    action
+}
+
+/// Draws the list of tasks currently executing, so the user can see at
+/// a glance why a task they expected to run hasn't started yet (it's
+/// still overlapping a previous run, or the concurrency cap is full).
+pub fn running_tasks_pane(ui: &mut egui::Ui, running_task_names: &[String]) {
+    ui.label("Currently running:");
+    if running_task_names.is_empty() {
+        ui.label("(none)");
+        return;
+    }
+    for task_name in running_task_names {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label(task_name);
+        });
+    }
+}
+
+/// Draws a "Test run now (dry run)" button for `task_type` and, once
+/// clicked, what it would have done underneath without actually doing
+/// it. `last_dry_run` holds the most recent result across frames so it
+/// stays visible after the click.
+pub fn dry_run_button(ui: &mut egui::Ui, task_type: &TaskType, last_dry_run: &mut Option<TaskOutcome>) {
+    if ui.button("Test run now (dry run)").clicked() {
+        *last_dry_run = Some(dry_run_task(task_type));
+    }
+    if let Some(outcome) = last_dry_run {
+        if let Some(output) = &outcome.output {
+            ui.label(output);
+        }
+    }
+}
+
+/// What the user has typed into the history pane's task-name filter.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub task_name: String,
+}
+
+impl HistoryFilter {
+    fn matches(&self, record: &ExecutionRecord) -> bool {
+        self.task_name.is_empty() || record.task_name.to_lowercase().contains(&self.task_name.to_lowercase())
+    }
+}
+
+/// Draws the history pane: a task-name filter box, the matching records
+/// newest first, and an "Export CSV" button. Returns the CSV text if the
+/// button was clicked this frame, so the caller can write it to a file
+/// the user picked.
+pub fn history_pane(ui: &mut egui::Ui, records: &[ExecutionRecord], filter: &mut HistoryFilter) -> Option<String> {
+    ui.horizontal(|ui| {
+        ui.label("Filter by task:");
+        ui.text_edit_singleline(&mut filter.task_name);
+    });
+
+    let matching: Vec<&ExecutionRecord> = records.iter().filter(|record| filter.matches(record)).collect();
+
+    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+        for record in matching.iter().rev() {
+            ui.horizontal(|ui| {
+                ui.label(&record.task_name);
+                if record.attempt > 0 {
+                    ui.label(format!("retry #{}", record.attempt));
+                }
+                ui.label(format!("{:.1}s", record.duration.as_secs_f64()));
+                if record.bytes_freed > 0 {
+                    ui.label(format!("{} bytes freed", record.bytes_freed));
+                }
+                if record.timed_out {
+                    ui.colored_label(egui::Color32::YELLOW, "timed out");
+                } else if let Some(error) = &record.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                } else {
+                    ui.colored_label(egui::Color32::GREEN, "ok");
+                }
+            });
+        }
+    });
+
+    if ui.button("Export CSV").clicked() {
+        let owned: Vec<ExecutionRecord> = matching.into_iter().cloned().collect();
+        return Some(to_csv(&owned));
+    }
+    None
+}
+
+/// What the user did in the add/edit task dialog this frame.
+pub enum TaskFormAction {
+    /// Save clicked with a valid form. `existing_name` is the task this
+    /// form was opened to edit, if any — the caller looks it up in its
+    /// [`crate::scheduler::SchedulerStore`] and passes it to
+    /// [`TaskFormModel::to_task`] so run counters and history survive
+    /// the edit, then saves the result under `form.name`.
+    Save { form: TaskFormModel, existing_name: Option<String> },
+    /// The dialog was closed (Cancel, or a confirmed discard).
+    Closed,
+}
+
+/// Draws the add/edit task dialog while `dialog` isn't
+/// [`DialogState::Closed`]: a name field, the validation errors
+/// [`TaskFormModel::validate`] currently reports, and Save/Cancel
+/// buttons. Only the name field is drawn here — the task type and rule
+/// pickers are left to the call site to compose from
+/// `crate::ui::condition_editor`/`crate::ui::schedule_editor`-style
+/// widgets once those are wired to [`crate::scheduler::TaskTypeForm`]
+/// and [`crate::scheduler::RuleForm`].
+pub fn task_form_dialog(ctx: &egui::Context, dialog: &mut DialogState<TaskFormModel>) -> Option<TaskFormAction> {
+    if !dialog.is_open() {
+        return None;
+    }
+
+    let mut action = None;
+    let (title, existing_name) = match dialog.target() {
+        Some(EditTarget::Existing { key, .. }) => ("Edit task", Some(key.clone())),
+        _ => ("Add task", None),
+    };
+
+    egui::Window::new(title).collapsible(false).resizable(false).show(ctx, |ui| {
+        let Some(target) = dialog.target_mut() else { return };
+        let form = target.value_mut();
+
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut form.name);
+        });
+
+        let errors = form.validate();
+        for error in &errors {
+            ui.colored_label(egui::Color32::RED, &error.message);
+        }
+
+        let form = form.clone();
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(errors.is_empty(), |ui| {
+                if ui.button("Save").clicked() {
+                    action = Some(TaskFormAction::Save { form: form.clone(), existing_name: existing_name.clone() });
+                }
+            });
+            if ui.button("Cancel").clicked() {
+                dialog.request_close();
+            }
+        });
+    });
+
+    if let DialogState::Closed = dialog {
+        action = Some(TaskFormAction::Closed);
+    }
+
+    action
+}
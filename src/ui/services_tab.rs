@@ -0,0 +1,307 @@
+//! Rendering for the Services tab: a searchable table of every Windows
+//! service, with start/stop/restart/startup-type controls and a filter
+//! for services this app has changed itself.
+
+use crate::services::manager::{DisableCheck, ServiceInfo, ServiceStatus, StartupType};
+use crate::services::telemetry::TelemetryItem;
+use crate::system::defender::{DefenderStatus, DefenderStatusHistory, DefenderStatusSample, ScanDay, ScanSchedule};
+
+/// What the user has typed into the search box, plus whether the
+/// "changed by GameBooster" filter is on.
+#[derive(Debug, Clone, Default)]
+pub struct ServicesFilter {
+    pub query: String,
+    pub changed_by_app_only: bool,
+}
+
+/// An action the user picked for one row this frame, for the caller to
+/// run against [`crate::services::manager::ServiceManager`] — rendering
+/// stays pure, mutation happens at the call site.
+pub enum ServiceAction {
+    Start(String),
+    Stop(String),
+    Restart(String),
+    SetStartupType(String, StartupType),
+    ApplyGamingPreset,
+    RestoreAllServices,
+    /// Resolves what disabling this service would break, via
+    /// [`crate::services::manager::ServiceManager::check_before_disable`],
+    /// before the Stop/startup-type buttons are allowed to act on it.
+    CheckBeforeDisable(String),
+}
+
+/// Draws the "Apply gaming preset" / "Restore all services" buttons
+/// above the service table, swapping which one shows based on whether a
+/// preset is currently applied.
+pub fn gaming_preset_controls(ui: &mut egui::Ui, preset_applied: bool) -> Option<ServiceAction> {
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if preset_applied {
+            if ui.button("Restore all services").clicked() {
+                action = Some(ServiceAction::RestoreAllServices);
+            }
+        } else if ui.button("Apply gaming preset").clicked() {
+            action = Some(ServiceAction::ApplyGamingPreset);
+        }
+    });
+    action
+}
+
+/// Draws the filter bar and the table of `services`, returning at most
+/// one action for the frame the user clicked a button.
+pub fn services_tab(
+    ui: &mut egui::Ui,
+    services: &[ServiceInfo],
+    filter: &mut ServicesFilter,
+    was_changed_by_app: impl Fn(&str) -> bool,
+    last_disable_check: &Option<(String, DisableCheck)>,
+) -> Option<ServiceAction> {
+    ui.horizontal(|ui| {
+        ui.label("Search:");
+        ui.text_edit_singleline(&mut filter.query);
+        ui.checkbox(&mut filter.changed_by_app_only, "Changed by GameBooster only");
+    });
+
+    let query = filter.query.to_lowercase();
+    let matching = services.iter().filter(|service| {
+        let matches_query = query.is_empty()
+            || service.name.to_lowercase().contains(&query)
+            || service.display_name.to_lowercase().contains(&query);
+        let matches_changed = !filter.changed_by_app_only || was_changed_by_app(&service.name);
+        matches_query && matches_changed
+    });
+
+    let mut action = None;
+    egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+        for service in matching {
+            let check = last_disable_check
+                .as_ref()
+                .filter(|(checked_name, _)| checked_name == &service.name)
+                .map(|(_, check)| check);
+            let blocked = matches!(check, Some(DisableCheck::Blocked { .. }));
+
+            ui.horizontal(|ui| {
+                ui.label(&service.display_name);
+                ui.label(status_label(service.status));
+                let mut startup_type = service.startup_type;
+                ui.add_enabled_ui(!blocked, |ui| {
+                    egui::ComboBox::from_id_source(("startup_type", &service.name))
+                        .selected_text(startup_type_label(startup_type))
+                        .show_ui(ui, |ui| {
+                            for option in [StartupType::Automatic, StartupType::AutomaticDelayed, StartupType::Manual, StartupType::Disabled] {
+                                ui.selectable_value(&mut startup_type, option, startup_type_label(option));
+                            }
+                        });
+                });
+                if startup_type != service.startup_type {
+                    action = Some(ServiceAction::SetStartupType(service.name.clone(), startup_type));
+                }
+                if was_changed_by_app(&service.name) {
+                    ui.colored_label(egui::Color32::LIGHT_BLUE, "changed by GameBooster");
+                }
+                if ui.button("Start").clicked() {
+                    action = Some(ServiceAction::Start(service.name.clone()));
+                }
+                if ui.button("Check before stopping").clicked() {
+                    action = Some(ServiceAction::CheckBeforeDisable(service.name.clone()));
+                }
+                if ui.add_enabled(!blocked, egui::Button::new("Stop")).clicked() {
+                    action = Some(ServiceAction::Stop(service.name.clone()));
+                }
+                if ui.add_enabled(!blocked, egui::Button::new("Restart")).clicked() {
+                    action = Some(ServiceAction::Restart(service.name.clone()));
+                }
+                if !service.description.is_empty() {
+                    ui.label(&service.description);
+                }
+            });
+            match check {
+                Some(DisableCheck::Blocked { reason }) => {
+                    ui.colored_label(egui::Color32::RED, format!("Blocked: {reason}"));
+                }
+                Some(DisableCheck::DependentsWarning(dependents)) => {
+                    ui.colored_label(
+                        egui::Color32::YELLOW,
+                        format!("Warning: {} also depend(s) on this service", dependents.join(", ")),
+                    );
+                }
+                Some(DisableCheck::Safe) | None => {}
+            }
+        }
+    });
+    action
+}
+
+/// What the user asked to do with Defender's scheduled scans this
+/// frame, for the caller to apply via [`crate::system::defender`].
+pub enum DefenderScanAction {
+    MoveToOffHours,
+    OnlyWhenIdle,
+}
+
+/// Draws Defender's current scheduled-scan times and two buttons to
+/// move them off mid-game hours, either by picking a fixed off-hours
+/// time or by turning Defender's own schedule off in favor of this
+/// app's idle-gated scheduler.
+pub fn defender_scan_schedule_panel(ui: &mut egui::Ui, schedule: &ScanSchedule) -> Option<DefenderScanAction> {
+    ui.label("Defender scheduled scans:");
+    ui.horizontal(|ui| {
+        ui.label("Quick scan:");
+        ui.label(schedule.quick_scan_time.map_or("unscheduled".to_string(), |t| format!("{:02}:{:02}", t.hour, t.minute)));
+    });
+    ui.horizontal(|ui| {
+        ui.label("Full scan:");
+        ui.label(format!("{} at {:02}:{:02}", scan_day_label(schedule.full_scan_day), schedule.full_scan_time.hour, schedule.full_scan_time.minute));
+    });
+
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if ui.button("Move to off-hours (2 AM)").clicked() {
+            action = Some(DefenderScanAction::MoveToOffHours);
+        }
+        if ui.button("Only scan when idle").clicked() {
+            action = Some(DefenderScanAction::OnlyWhenIdle);
+        }
+    });
+    action
+}
+
+fn scan_day_label(day: ScanDay) -> &'static str {
+    match day {
+        ScanDay::Everyday => "Everyday",
+        ScanDay::Sunday => "Sunday",
+        ScanDay::Monday => "Monday",
+        ScanDay::Tuesday => "Tuesday",
+        ScanDay::Wednesday => "Wednesday",
+        ScanDay::Thursday => "Thursday",
+        ScanDay::Friday => "Friday",
+        ScanDay::Saturday => "Saturday",
+        ScanDay::Never => "Never",
+    }
+}
+
+/// What the user asked to do with telemetry/diagnostics this frame.
+pub enum TelemetryAction {
+    DisableAll,
+    RestoreAll,
+}
+
+/// What the user did with Defender's live-status panel this frame.
+pub enum DefenderStatusAction {
+    /// Ticked or cleared "Poll status every 60s" —
+    /// [`crate::settings::AppSettings::defender_live_status_enabled`].
+    SetLiveStatusEnabled(bool),
+    /// Clicked "Check now" for an on-demand refresh regardless of
+    /// whether live polling is on.
+    RefreshNow,
+}
+
+/// Draws Defender's current real-time-protection status, the opt-in
+/// "poll every 60s" checkbox and an on-demand refresh button, and the
+/// enabled/disabled timeline underneath.
+pub fn defender_status_panel(
+    ui: &mut egui::Ui,
+    status: Option<DefenderStatus>,
+    live_status_enabled: bool,
+    history: &DefenderStatusHistory,
+) -> Option<DefenderStatusAction> {
+    let mut action = None;
+
+    ui.horizontal(|ui| {
+        ui.label("Real-time protection:");
+        match status {
+            Some(status) if status.real_time_protection_enabled => {
+                ui.colored_label(egui::Color32::LIGHT_GREEN, "Enabled");
+            }
+            Some(_) => {
+                ui.colored_label(egui::Color32::LIGHT_RED, "Disabled");
+            }
+            None => {
+                ui.weak("(not checked yet)");
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let mut live_status_enabled = live_status_enabled;
+        if ui.checkbox(&mut live_status_enabled, "Poll status every 60s").changed() {
+            action = Some(DefenderStatusAction::SetLiveStatusEnabled(live_status_enabled));
+        }
+        if ui.button("Check now").clicked() {
+            action = Some(DefenderStatusAction::RefreshNow);
+        }
+    });
+
+    defender_status_timeline(ui, history);
+
+    action
+}
+
+/// Draws a compact enabled/disabled strip of `history`'s samples,
+/// oldest on the left, green for enabled and red for disabled.
+pub fn defender_status_timeline(ui: &mut egui::Ui, history: &DefenderStatusHistory) {
+    let samples: Vec<&DefenderStatusSample> = history.samples().collect();
+    if samples.is_empty() {
+        ui.weak("(no history yet)");
+        return;
+    }
+
+    let height = 16.0;
+    let width = ui.available_width();
+    let (rect, _response) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let segment_width = (width / samples.len() as f32).max(1.0);
+    for (index, sample) in samples.iter().enumerate() {
+        let color = if sample.real_time_protection_enabled {
+            egui::Color32::from_rgb(60, 179, 113)
+        } else {
+            egui::Color32::from_rgb(200, 60, 60)
+        };
+        let x0 = rect.left() + index as f32 * segment_width;
+        let segment_rect = egui::Rect::from_min_size(egui::pos2(x0, rect.top()), egui::vec2(segment_width, height));
+        painter.rect_filled(segment_rect, 0.0, color);
+    }
+}
+
+/// Draws the full list of what a telemetry toggle would change, and a
+/// single "Disable telemetry" / "Restore telemetry" button depending on
+/// `is_disabled`, so the effect is never a black box.
+pub fn telemetry_panel(ui: &mut egui::Ui, items: &[TelemetryItem], is_disabled: bool) -> Option<TelemetryAction> {
+    ui.label("This will change:");
+    for item in items {
+        let label = match item {
+            TelemetryItem::Service(name) => format!("Service: {name}"),
+            TelemetryItem::ScheduledTask(path) => format!("Scheduled task: {path}"),
+        };
+        ui.label(label);
+    }
+
+    let mut action = None;
+    if is_disabled {
+        if ui.button("Restore telemetry").clicked() {
+            action = Some(TelemetryAction::RestoreAll);
+        }
+    } else if ui.button("Disable telemetry").clicked() {
+        action = Some(TelemetryAction::DisableAll);
+    }
+    action
+}
+
+fn status_label(status: ServiceStatus) -> &'static str {
+    match status {
+        ServiceStatus::Running => "Running",
+        ServiceStatus::Stopped => "Stopped",
+        ServiceStatus::StartPending => "Starting...",
+        ServiceStatus::StopPending => "Stopping...",
+        ServiceStatus::Paused => "Paused",
+    }
+}
+
+fn startup_type_label(startup_type: StartupType) -> &'static str {
+    match startup_type {
+        StartupType::Automatic => "Automatic",
+        StartupType::AutomaticDelayed => "Automatic (Delayed Start)",
+        StartupType::Manual => "Manual",
+        StartupType::Disabled => "Disabled",
+    }
+}
@@ -0,0 +1,134 @@
+//! Rendering for the Settings tab's restore-point picker, GPU
+//! scheduling tweaks, notification mute switch, UI scale slider, and
+//! language picker: a restore-point list backed by
+//! [`crate::services::snapshot::SnapshotStore`], independent of Windows
+//! System Restore; HAGS/variable-refresh-rate toggles backed by
+//! [`crate::gpu::GpuController`]; a mute switch backed by
+//! [`crate::notifications::NotificationSettings`]; a UI scale slider
+//! backed by [`crate::settings::AppSettings::ui_scale`]; and a language
+//! picker backed by [`crate::i18n::LocaleSettings`]. Every panel takes
+//! the current [`Locale`] and re-translates its labels each frame, so
+//! switching languages takes effect without a restart.
+
+use crate::i18n::{tr, Locale, TranslationKey};
+use crate::services::snapshot::RestorePoint;
+
+/// An action the user picked for one restore point this frame.
+pub enum RestorePointAction {
+    Restore(usize),
+    Remove(usize),
+}
+
+/// Draws the restore-point list, returning at most one action for the
+/// frame the user clicked a button.
+pub fn restore_points_panel(ui: &mut egui::Ui, locale: Locale, points: &[RestorePoint]) -> Option<RestorePointAction> {
+    ui.label(tr(locale, TranslationKey::RestorePointsLabel));
+    if points.is_empty() {
+        ui.label(tr(locale, TranslationKey::RestorePointsEmpty));
+        return None;
+    }
+
+    let mut action = None;
+    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+        for (index, point) in points.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(&point.label);
+                ui.label(format!("{} (unix time)", point.created_at_unix_secs));
+                ui.label(format!("{} services, {} registry values", point.services.len(), point.registry_values.len()));
+                if ui.button(tr(locale, TranslationKey::RestoreButton)).clicked() {
+                    action = Some(RestorePointAction::Restore(index));
+                }
+                if ui.button(tr(locale, TranslationKey::RemoveButton)).clicked() {
+                    action = Some(RestorePointAction::Remove(index));
+                }
+            });
+        }
+    });
+    action
+}
+
+/// What GPU scheduling toggle the user flipped this frame.
+pub enum GpuSchedulingAction {
+    SetHagsEnabled(bool),
+    SetVrrEnabled(bool),
+}
+
+/// Draws Hardware-accelerated GPU Scheduling and variable-refresh-rate
+/// current state, greying HAGS out when the driver doesn't support it,
+/// and a standing reminder that both need a reboot to take effect.
+pub fn gpu_scheduling_panel(
+    ui: &mut egui::Ui,
+    locale: Locale,
+    hags_supported: bool,
+    hags_enabled: bool,
+    vrr_enabled: bool,
+) -> Option<GpuSchedulingAction> {
+    let mut action = None;
+
+    ui.horizontal(|ui| {
+        let mut enabled = hags_enabled;
+        if ui
+            .add_enabled(hags_supported, egui::Checkbox::new(&mut enabled, tr(locale, TranslationKey::HagsLabel)))
+            .changed()
+        {
+            action = Some(GpuSchedulingAction::SetHagsEnabled(enabled));
+        }
+        if !hags_supported {
+            ui.colored_label(egui::Color32::GRAY, tr(locale, TranslationKey::HagsUnsupported));
+        }
+    });
+
+    ui.horizontal(|ui| {
+        let mut enabled = vrr_enabled;
+        if ui.checkbox(&mut enabled, tr(locale, TranslationKey::VrrLabel)).changed() {
+            action = Some(GpuSchedulingAction::SetVrrEnabled(enabled));
+        }
+    });
+
+    ui.colored_label(egui::Color32::YELLOW, tr(locale, TranslationKey::GpuRebootNotice));
+
+    action
+}
+
+/// Draws the global mute switch, returning the new value the frame the
+/// user flips it. Muting silences every toast and webhook the
+/// scheduler, profile applier, and cleaners would otherwise send.
+pub fn notifications_panel(ui: &mut egui::Ui, locale: Locale, muted: bool) -> Option<bool> {
+    let mut muted = muted;
+    if ui.checkbox(&mut muted, tr(locale, TranslationKey::MuteAllNotifications)).changed() {
+        Some(muted)
+    } else {
+        None
+    }
+}
+
+/// Draws the UI scale slider, returning the new value every frame it
+/// moves (not just on release) so the caller can apply it to
+/// `egui::Context::set_pixels_per_point` immediately for a live preview
+/// instead of waiting for the user to let go of the slider.
+pub fn ui_scale_panel(ui: &mut egui::Ui, locale: Locale, ui_scale: f32) -> Option<f32> {
+    let mut updated = ui_scale;
+    ui.horizontal(|ui| {
+        ui.label(tr(locale, TranslationKey::UiScaleLabel));
+        ui.add(egui::Slider::new(&mut updated, 0.75..=2.0).fixed_decimals(2));
+    });
+    (updated != ui_scale).then_some(updated)
+}
+
+/// Draws the language picker, returning the newly-picked locale the
+/// frame the user selects one. Switching takes effect immediately:
+/// every other panel in this module re-translates on its very next
+/// frame since they all take `locale` as a plain argument rather than
+/// caching translated text.
+pub fn language_picker(ui: &mut egui::Ui, current: Locale) -> Option<Locale> {
+    let mut picked = None;
+    ui.horizontal(|ui| {
+        ui.label(tr(current, TranslationKey::LanguageLabel));
+        for locale in Locale::ALL {
+            if ui.selectable_label(locale == current, locale.native_name()).clicked() {
+                picked = Some(locale);
+            }
+        }
+    });
+    picked
+}
@@ -0,0 +1,74 @@
+//! Bridges [`crate::shortcuts::ShortcutsConfig`]'s in-app bindings to
+//! egui's input handling, and draws a read-only summary of every
+//! binding (in-app and global) for the Settings tab.
+
+use crate::profiles::activation::Hotkey;
+use crate::shortcuts::{GlobalAction, InAppAction, ShortcutsConfig};
+
+/// Maps a [`Hotkey`]'s key name to the `egui::Key` it corresponds to.
+/// Returns `None` for anything the current set of default bindings
+/// doesn't use, rather than guessing.
+fn egui_key(name: &str) -> Option<egui::Key> {
+    match name {
+        "1" => Some(egui::Key::Num1),
+        "2" => Some(egui::Key::Num2),
+        "3" => Some(egui::Key::Num3),
+        "4" => Some(egui::Key::Num4),
+        "5" => Some(egui::Key::Num5),
+        "6" => Some(egui::Key::Num6),
+        "C" | "c" => Some(egui::Key::C),
+        "F" | "f" => Some(egui::Key::F),
+        "R" | "r" => Some(egui::Key::R),
+        "Tab" => Some(egui::Key::Tab),
+        _ => None,
+    }
+}
+
+fn egui_modifiers(hotkey: &Hotkey) -> egui::Modifiers {
+    egui::Modifiers { ctrl: hotkey.ctrl, alt: hotkey.alt, shift: hotkey.shift, ..Default::default() }
+}
+
+/// Returns every in-app action whose shortcut was pressed this frame,
+/// consuming the matching key events so they don't also reach whatever
+/// text field or button happens to be focused.
+pub fn triggered_in_app_actions(ctx: &egui::Context, config: &ShortcutsConfig) -> Vec<InAppAction> {
+    config
+        .in_app_shortcuts()
+        .iter()
+        .filter_map(|(action, hotkey)| {
+            let key = egui_key(&hotkey.key)?;
+            let shortcut = egui::KeyboardShortcut::new(egui_modifiers(hotkey), key);
+            ctx.input_mut(|input| input.consume_shortcut(&shortcut)).then_some(*action)
+        })
+        .collect()
+}
+
+/// Draws a read-only list of every in-app and global binding, for the
+/// Settings tab.
+pub fn bindings_panel(ui: &mut egui::Ui, config: &ShortcutsConfig) {
+    ui.label("In-app shortcuts:");
+    for (action, hotkey) in config.in_app_shortcuts() {
+        let label = match action {
+            InAppAction::SwitchToTab(tab) => format!("Switch to tab {tab}"),
+            InAppAction::CleanRamNow => "Clean RAM now".to_string(),
+            InAppAction::NextTab => "Next tab".to_string(),
+            InAppAction::PreviousTab => "Previous tab".to_string(),
+        };
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.label(hotkey.display());
+        });
+    }
+
+    ui.label("Global shortcuts (work while a game has focus):");
+    for (action, hotkey) in config.global_shortcuts() {
+        let label = match action {
+            GlobalAction::CleanRamNow => "Clean RAM now",
+            GlobalAction::ToggleFocusMode => "Toggle Focus mode",
+        };
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.label(hotkey.display());
+        });
+    }
+}
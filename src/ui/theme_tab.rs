@@ -0,0 +1,62 @@
+//! Rendering for the Settings tab's theme editor: mode switch (light /
+//! dark / auto / high contrast), accent color and background tint
+//! pickers (fixed and disabled in high-contrast mode — see
+//! [`ThemeConfig::effective_accent`](crate::theme::ThemeConfig::effective_accent)
+//! and [`effective_background_tint`](crate::theme::ThemeConfig::effective_background_tint)),
+//! and corner-radius/font-size sliders, backed by
+//! [`crate::theme::ThemeConfig`].
+
+use crate::theme::{Rgb, ThemeConfig, ThemeMode};
+
+/// Draws every theme control, returning an updated [`ThemeConfig`] the
+/// frame the user changes something — the caller is responsible for
+/// saving it and re-applying it to the egui context.
+pub fn theme_editor_panel(ui: &mut egui::Ui, config: &ThemeConfig) -> Option<ThemeConfig> {
+    let mut updated = *config;
+    let mut changed = false;
+
+    ui.horizontal(|ui| {
+        ui.label("Mode:");
+        changed |= ui.selectable_value(&mut updated.mode, ThemeMode::Light, "Light").changed();
+        changed |= ui.selectable_value(&mut updated.mode, ThemeMode::Dark, "Dark").changed();
+        changed |= ui.selectable_value(&mut updated.mode, ThemeMode::Auto, "Auto (follow Windows)").changed();
+        changed |= ui.selectable_value(&mut updated.mode, ThemeMode::HighContrast, "High contrast").changed();
+    });
+
+    let high_contrast = updated.mode == ThemeMode::HighContrast;
+    if high_contrast {
+        ui.label("Accent color and background tint are fixed in high-contrast mode.");
+    }
+
+    ui.add_enabled_ui(!high_contrast, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Accent color:");
+            let mut rgb = [updated.accent.r, updated.accent.g, updated.accent.b];
+            if ui.color_edit_button_srgb(&mut rgb).changed() {
+                updated.accent = Rgb::new(rgb[0], rgb[1], rgb[2]);
+                changed = true;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Background tint:");
+            let mut rgb = [updated.background_tint.r, updated.background_tint.g, updated.background_tint.b];
+            if ui.color_edit_button_srgb(&mut rgb).changed() {
+                updated.background_tint = Rgb::new(rgb[0], rgb[1], rgb[2]);
+                changed = true;
+            }
+        });
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Corner radius:");
+        changed |= ui.add(egui::Slider::new(&mut updated.corner_radius, 0.0..=16.0)).changed();
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Font size:");
+        changed |= ui.add(egui::Slider::new(&mut updated.font_size, 10.0..=24.0)).changed();
+    });
+
+    changed.then_some(updated)
+}
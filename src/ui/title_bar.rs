@@ -0,0 +1,70 @@
+//! A custom, frameless title bar: a drag area, minimize/overlay/close
+//! buttons and the active-profile badge that a native title bar has no
+//! room for. Draws with whatever `egui::Visuals` the rest of the app is
+//! using, so it automatically matches dark mode, high contrast, or
+//! whatever accent the Theme tab has set.
+//!
+//! This only replaces the *drawing* of the title bar — the embedder
+//! still has to construct its `ViewportBuilder` with
+//! `.with_decorations(false)` for the native one to go away, the same
+//! way [`crate::ui::overlay::show_overlay`] does for the overlay
+//! window. There's no `main.rs` in this crate to do that in.
+
+use egui::ViewportCommand;
+
+/// Height of the bar, in points, left room for the branding, the badge
+/// and three roughly touch-sized buttons.
+pub const TITLE_BAR_HEIGHT: f32 = 32.0;
+
+/// A button the user clicked on the title bar this frame, for the
+/// caller to act on. Minimize and close are left to the caller rather
+/// than issued here, since closing may first want to flush settings or
+/// warn about a running cleanup.
+pub enum TitleBarAction {
+    Minimize,
+    Close,
+    ToggleOverlay,
+}
+
+/// Draws the title bar into the top of whatever `ui` it's given —
+/// intended to be the first thing drawn in the main window's
+/// `CentralPanel`. Dragging and double-click-to-maximize are handled
+/// directly via `ViewportCommand`, since both need to happen this frame
+/// regardless of what the caller does with the return value.
+pub fn title_bar(ui: &mut egui::Ui, ctx: &egui::Context, active_profile_name: Option<&str>) -> Option<TitleBarAction> {
+    let mut action = None;
+
+    let bar_rect = egui::Rect::from_min_size(ui.cursor().min, egui::vec2(ui.available_width(), TITLE_BAR_HEIGHT));
+    let drag_response = ui.interact(bar_rect, ui.id().with("title_bar_drag"), egui::Sense::click_and_drag());
+    if drag_response.dragged() {
+        ctx.send_viewport_cmd(ViewportCommand::StartDrag);
+    }
+    if drag_response.double_clicked() {
+        let maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+        ctx.send_viewport_cmd(ViewportCommand::Maximized(!maximized));
+    }
+
+    ui.allocate_ui_at_rect(bar_rect, |ui| {
+        ui.horizontal(|ui| {
+            ui.add_space(8.0);
+            ui.strong("Clean RAM");
+            if let Some(profile_name) = active_profile_name {
+                ui.weak(format!("· {profile_name}"));
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("✕").on_hover_text("Close").clicked() {
+                    action = Some(TitleBarAction::Close);
+                }
+                if ui.button("—").on_hover_text("Minimize").clicked() {
+                    action = Some(TitleBarAction::Minimize);
+                }
+                if ui.button("▣").on_hover_text("Toggle overlay").clicked() {
+                    action = Some(TitleBarAction::ToggleOverlay);
+                }
+            });
+        });
+    });
+
+    ui.advance_cursor_after_rect(bar_rect);
+    action
+}
@@ -0,0 +1,92 @@
+//! Detects whether the current process already holds an elevated token,
+//! and if not, relaunches a specific operation elevated via
+//! `ShellExecute`'s `"runas"` verb rather than letting an admin-only
+//! feature fail silently partway through.
+
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ElevationError {
+    /// The user dismissed the UAC prompt.
+    Declined,
+    Io(std::io::Error),
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for ElevationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElevationError::Declined => write!(f, "the UAC prompt was declined"),
+            ElevationError::Io(err) => write!(f, "io error: {err}"),
+            ElevationError::Unsupported(msg) => write!(f, "unsupported: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ElevationError {}
+
+impl From<std::io::Error> for ElevationError {
+    fn from(err: std::io::Error) -> Self {
+        ElevationError::Io(err)
+    }
+}
+
+/// What an elevated relaunch reported back over its result pipe.
+#[derive(Debug)]
+pub struct ElevatedResult {
+    pub exit_code: i32,
+    pub output: String,
+}
+
+/// Whether the current process already holds an elevated token.
+pub fn is_elevated() -> bool {
+    imp::is_elevated()
+}
+
+/// Ensures `operation_args` runs elevated: if the current process is
+/// already elevated, returns `Ok(None)` so the caller just performs the
+/// operation in-process. Otherwise relaunches this executable with
+/// `operation_args` via `ShellExecute`'s `"runas"` verb, waits for the
+/// elevated child to report back over a named pipe, and returns its
+/// result.
+pub fn ensure_elevated(operation_args: &[&str]) -> Result<Option<ElevatedResult>, ElevationError> {
+    if is_elevated() {
+        return Ok(None);
+    }
+    imp::relaunch_elevated(operation_args).map(Some)
+}
+
+#[cfg(windows)]
+mod imp {
+    use super::{ElevatedResult, ElevationError};
+
+    pub(super) fn is_elevated() -> bool {
+        // OpenProcessToken(GetCurrentProcess(), ...) followed by
+        // GetTokenInformation(TokenElevation) — the same check behind
+        // `[Security.Principal.WindowsPrincipal]::...IsInRole(Administrator)`.
+        todo!("check the current process token via GetTokenInformation(TokenElevation)")
+    }
+
+    pub(super) fn relaunch_elevated(_operation_args: &[&str]) -> Result<ElevatedResult, ElevationError> {
+        // ShellExecuteExW with lpVerb = "runas" and lpParameters built
+        // from operation_args, plus a one-shot named pipe
+        // (`\\.\pipe\gamebooster-elevated-<pid>`) the elevated child
+        // connects to and writes its exit code + captured output to,
+        // since ShellExecuteExW gives no direct handle to the child's
+        // stdout when invoked with "runas".
+        todo!("ShellExecuteExW \"runas\" the current exe with operation_args, then read the result back over a named pipe")
+    }
+}
+
+#[cfg(not(windows))]
+mod imp {
+    use super::{ElevatedResult, ElevationError};
+
+    pub(super) fn is_elevated() -> bool {
+        false
+    }
+
+    pub(super) fn relaunch_elevated(_operation_args: &[&str]) -> Result<ElevatedResult, ElevationError> {
+        Err(ElevationError::Unsupported("UAC elevation is a Windows-only concept"))
+    }
+}
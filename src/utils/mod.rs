@@ -0,0 +1,6 @@
+//! Small cross-cutting helpers that don't belong to any single
+//! subsystem.
+
+pub mod elevation;
+
+pub use elevation::ensure_elevated;